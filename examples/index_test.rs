@@ -1,25 +1,46 @@
-use tantivy::tokenizer::{SimpleTokenizer, TextAnalyzer};
-use tantivy_analysis_contrib::commons::LengthTokenFilter;
+use std::num::NonZeroUsize;
+use tantivy::tokenizer::{RawTokenizer, SimpleTokenizer, TextAnalyzer};
+use tantivy_analysis_contrib::commons::{EdgeNgramTokenFilter, TrimTokenFilter};
 use tantivy_analysis_contrib::icu::{Direction, ICUTransformTokenFilter};
 
 fn main() -> tantivy::Result<()> {
-    let args = std::env::args().skip(1);
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
-    let transform = ICUTransformTokenFilter {
+    let folding = ICUTransformTokenFilter {
         compound_id: "Any-Latin; NFD; [:Nonspacing Mark:] Remove; Lower;  NFC".to_string(),
         rules: None,
         direction: Direction::Forward,
     };
-    let icu_analyzer = TextAnalyzer::from(SimpleTokenizer)
-        .filter(LengthTokenFilter::new(Some(3), None))
-        .filter(transform);
+    let prefixes =
+        EdgeNgramTokenFilter::new(NonZeroUsize::MIN, NonZeroUsize::new(20), true).unwrap();
+    let analyzers = [
+        (
+            "folded",
+            TextAnalyzer::from(SimpleTokenizer).filter(folding.clone()),
+        ),
+        (
+            "keyword",
+            TextAnalyzer::from(RawTokenizer)
+                .filter(TrimTokenFilter)
+                .filter(folding.clone()),
+        ),
+        (
+            "prefix",
+            TextAnalyzer::from(SimpleTokenizer)
+                .filter(folding)
+                .filter(prefixes),
+        ),
+    ];
 
-    for arg in args {
+    for arg in &args {
         println!("{arg}");
 
-        let mut token_stream = icu_analyzer.token_stream(&arg);
-        while token_stream.advance() {
-            println!("{:?}", token_stream.token());
+        for (name, analyzer) in &analyzers {
+            println!("  {name}");
+            let mut token_stream = analyzer.token_stream(arg);
+            while token_stream.advance() {
+                println!("    {:?}", token_stream.token());
+            }
         }
     }
 
@@ -0,0 +1,162 @@
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
+use tantivy::collector::TopDocs;
+use tantivy::query::TermQuery;
+use tantivy::schema::{IndexRecordOption, Schema, TextFieldIndexing, TextOptions};
+use tantivy::tokenizer::{RawTokenizer, TextAnalyzer};
+use tantivy::{doc, Index, Searcher, Term};
+use tantivy_analysis_contrib::commons::TrimTokenFilter;
+use tantivy_analysis_contrib::icu::{Direction, ICUTransformTokenFilter};
+
+/// Words titles are made of.
+const WORDS: [&str; 16] = [
+    "blue",
+    "train",
+    "moonlight",
+    "sonata",
+    "dream",
+    "little",
+    "night",
+    "so",
+    "what",
+    "kind",
+    "love",
+    "supreme",
+    "giant",
+    "steps",
+    "round",
+    "midnight",
+];
+
+/// Time the suggestions of titles, read from the term dictionary of a keyword field like
+/// the server does, for an index of `songs` songs (default to 100000) :
+///
+/// ```shell
+/// cargo r --release --example suggest_time -- 100000
+/// ```
+fn main() -> tantivy::Result<()> {
+    let songs: usize = std::env::args()
+        .nth(1)
+        .and_then(|songs| songs.parse().ok())
+        .unwrap_or(100_000);
+
+    let folding = ICUTransformTokenFilter {
+        compound_id: "Any-Latin; NFD; [:Nonspacing Mark:] Remove; Lower;  NFC".to_string(),
+        rules: None,
+        direction: Direction::Forward,
+    };
+    let keyword = TextAnalyzer::from(RawTokenizer)
+        .filter(TrimTokenFilter)
+        .filter(folding);
+
+    let mut builder = Schema::builder();
+    let title = builder.add_text_field("title", TextOptions::default().set_stored());
+    let title_keyword = builder.add_text_field(
+        "title_keyword",
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer("keyword")
+                .set_index_option(IndexRecordOption::WithFreqs),
+        ),
+    );
+    let index = Index::create_in_ram(builder.build());
+    index.tokenizers().register("keyword", keyword.clone());
+
+    // Titles of 2 to 4 words and a number, from a linear congruential generator
+    let mut seed: u64 = 42;
+    let mut random = move |bound: usize| {
+        seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (seed >> 33) as usize % bound
+    };
+    let mut titles = Vec::with_capacity(songs);
+    let mut writer = index.writer(50_000_000)?;
+    for number in 0..songs {
+        let words: Vec<&str> = (0..2 + random(3))
+            .map(|_| WORDS[random(WORDS.len())])
+            .collect();
+        let text = format!("{} {number}", words.join(" "));
+        writer.add_document(doc!(title => text.as_str(), title_keyword => text.as_str()))?;
+        titles.push(text);
+    }
+    writer.commit()?;
+    let searcher = index.reader()?.searcher();
+
+    // Prefixes of 1 to 12 characters of existing titles
+    let mut times: Vec<Duration> = Vec::new();
+    for _ in 0..1000 {
+        let text = &titles[random(titles.len())];
+        let prefix: String = text.chars().take(1 + random(12)).collect();
+        let start = Instant::now();
+        suggest(&searcher, &keyword, title_keyword, &prefix, 5)?;
+        times.push(start.elapsed());
+    }
+    times.sort();
+
+    let percentile = |percent: usize| times[(times.len() - 1) * percent / 100];
+    println!(
+        "{songs} songs in {} segments, {} suggestions : p50 {:?}, p99 {:?}, max {:?}",
+        searcher.segment_readers().len(),
+        times.len(),
+        percentile(50),
+        percentile(99),
+        times[times.len() - 1]
+    );
+
+    Ok(())
+}
+
+/// Titles starting with `text`, like `TantivyIndex::suggest`.
+fn suggest(
+    searcher: &Searcher,
+    keyword: &TextAnalyzer,
+    field: tantivy::schema::Field,
+    text: &str,
+    limit: usize,
+) -> tantivy::Result<Vec<String>> {
+    let mut prefix = String::new();
+    let mut tokens = keyword.token_stream(text);
+    while tokens.advance() {
+        prefix.push_str(&tokens.token().text);
+    }
+
+    let mut keywords = BTreeSet::new();
+    for segment_reader in searcher.segment_readers() {
+        let inverted_index = segment_reader.inverted_index(field)?;
+        let mut terms = inverted_index
+            .terms()
+            .range()
+            .ge(prefix.as_bytes())
+            .into_stream()?;
+        let mut count = 0;
+        while count < limit * 4 && terms.advance() {
+            let Ok(keyword) = std::str::from_utf8(terms.key()) else {
+                continue;
+            };
+            if !keyword.starts_with(prefix.as_str()) {
+                break;
+            }
+            keywords.insert(keyword.to_string());
+            count += 1;
+        }
+    }
+
+    let mut values = Vec::new();
+    for keyword in keywords.into_iter().take(limit) {
+        let term = Term::from_field_text(field, &keyword);
+        let query = TermQuery::new(term, IndexRecordOption::Basic);
+        if let Some((_, doc_address)) = searcher.search(&query, &TopDocs::with_limit(1))?.pop() {
+            let document = searcher.doc(doc_address)?;
+            values.extend(
+                document
+                    .field_values()
+                    .iter()
+                    .filter_map(|value| value.value().as_text())
+                    .map(str::to_string)
+                    .next(),
+            );
+        }
+    }
+    Ok(values)
+}
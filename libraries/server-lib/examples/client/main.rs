@@ -5,16 +5,29 @@
 use futures::{future, Stream, stream};
 #[allow(unused_imports)]
 use server_lib::{Api, ApiNoContext, Client, ContextWrapperExt, models,
+                      AlbumsGetResponse,
+                      AlbumsIdGetResponse,
+                      AlbumsIndexGetResponse,
+                      ArtistsGetResponse,
+                      ArtistsIdGetResponse,
+                      ArtistsIndexGetResponse,
+                      LibraryScanPostResponse,
                       PlaylistsGetResponse,
                       PlaylistsIdDeleteResponse,
                       PlaylistsIdGetResponse,
                       PlaylistsPostResponse,
                       RootGetResponse,
                       SearchGetResponse,
+                      SongsDuplicatesGetResponse,
                       SongsIdDeleteResponse,
                       SongsIdGetResponse,
                       SongsIdPutResponse,
                       SongsPostResponse,
+                      SuggestGetResponse,
+                      UsersGetResponse,
+                      UsersIdDeleteResponse,
+                      UsersIdPutResponse,
+                      UsersPostResponse,
                      };
 use clap::{App, Arg};
 
@@ -36,14 +49,25 @@ fn main() {
         .arg(Arg::with_name("operation")
             .help("Sets the operation to run")
             .possible_values(&[
+                "AlbumsGet",
+                "AlbumsIdGet",
+                "AlbumsIndexGet",
+                "ArtistsGet",
+                "ArtistsIdGet",
+                "ArtistsIndexGet",
+                "LibraryScanPost",
                 "PlaylistsGet",
                 "PlaylistsIdDelete",
                 "PlaylistsIdGet",
                 "RootGet",
                 "SearchGet",
+                "SongsDuplicatesGet",
                 "SongsIdDelete",
                 "SongsIdGet",
                 "SongsPost",
+                "SuggestGet",
+                "UsersGet",
+                "UsersIdDelete",
             ])
             .required(true)
             .index(1))
@@ -87,6 +111,50 @@ fn main() {
     let mut rt = tokio::runtime::Runtime::new().unwrap();
 
     match matches.value_of("operation") {
+        Some("AlbumsGet") => {
+            let result = rt.block_on(client.albums_get(
+                  Some(56),
+                  Some(56),
+                  Some("sort_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("AlbumsIdGet") => {
+            let result = rt.block_on(client.albums_id_get(
+                  56
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("AlbumsIndexGet") => {
+            let result = rt.block_on(client.albums_index_get(
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("ArtistsGet") => {
+            let result = rt.block_on(client.artists_get(
+                  Some(56),
+                  Some(56),
+                  Some("sort_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("ArtistsIdGet") => {
+            let result = rt.block_on(client.artists_id_get(
+                  56
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("ArtistsIndexGet") => {
+            let result = rt.block_on(client.artists_index_get(
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("LibraryScanPost") => {
+            let result = rt.block_on(client.library_scan_post(
+                  Some("path_example".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
         Some("PlaylistsGet") => {
             let result = rt.block_on(client.playlists_get(
             ));
@@ -121,7 +189,15 @@ fn main() {
             let result = rt.block_on(client.search_get(
                   "q_example".to_string(),
                   Some(56),
-                  Some(56)
+                  Some(56),
+                  Some("sort_example".to_string()),
+                  Some(&Vec::new()),
+                  Some(true)
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("SongsDuplicatesGet") => {
+            let result = rt.block_on(client.songs_duplicates_get(
             ));
             info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
         },
@@ -141,6 +217,7 @@ fn main() {
         Some("SongsIdPut") => {
             let result = rt.block_on(client.songs_id_put(
                   56,
+                  Some(true),
                   ???
             ));
             info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
@@ -149,10 +226,45 @@ fn main() {
         Some("SongsPost") => {
             let result = rt.block_on(client.songs_post(
                   "x_filename_example".to_string(),
-                  "body_example".to_string()
+                  swagger::ByteArray(Vec::from("BYTE_ARRAY_DATA_HERE"))
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("SuggestGet") => {
+            let result = rt.block_on(client.suggest_get(
+                  "q_example".to_string(),
+                  Some(56)
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("UsersGet") => {
+            let result = rt.block_on(client.users_get(
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("UsersIdDelete") => {
+            let result = rt.block_on(client.users_id_delete(
+                  56
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        /* Disabled because there's no example.
+        Some("UsersIdPut") => {
+            let result = rt.block_on(client.users_id_put(
+                  56,
+                  ???
             ));
             info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
         },
+        */
+        /* Disabled because there's no example.
+        Some("UsersPost") => {
+            let result = rt.block_on(client.users_post(
+                  ???
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
         _ => {
             panic!("Invalid operation provided")
         }
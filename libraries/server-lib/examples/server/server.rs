@@ -94,16 +94,29 @@ impl<C> Server<C> {
 
 use server_lib::{
     Api,
+    AlbumsGetResponse,
+    AlbumsIdGetResponse,
+    AlbumsIndexGetResponse,
+    ArtistsGetResponse,
+    ArtistsIdGetResponse,
+    ArtistsIndexGetResponse,
+    LibraryScanPostResponse,
     PlaylistsGetResponse,
     PlaylistsIdDeleteResponse,
     PlaylistsIdGetResponse,
     PlaylistsPostResponse,
     RootGetResponse,
     SearchGetResponse,
+    SongsDuplicatesGetResponse,
     SongsIdDeleteResponse,
     SongsIdGetResponse,
     SongsIdPutResponse,
     SongsPostResponse,
+    SuggestGetResponse,
+    UsersGetResponse,
+    UsersIdDeleteResponse,
+    UsersIdPutResponse,
+    UsersPostResponse,
 };
 use server_lib::server::MakeService;
 use std::error::Error;
@@ -112,6 +125,78 @@ use swagger::ApiError;
 #[async_trait]
 impl<C> Api<C> for Server<C> where C: Has<XSpanIdString> + Send + Sync
 {
+    async fn albums_get(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<String>,
+        context: &C) -> Result<AlbumsGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("albums_get({:?}, {:?}, {:?}) - X-Span-ID: {:?}", limit, offset, sort, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn albums_id_get(
+        &self,
+        id: i32,
+        context: &C) -> Result<AlbumsIdGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("albums_id_get({}) - X-Span-ID: {:?}", id, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn albums_index_get(
+        &self,
+        context: &C) -> Result<AlbumsIndexGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("albums_index_get() - X-Span-ID: {:?}", context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn artists_get(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<String>,
+        context: &C) -> Result<ArtistsGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("artists_get({:?}, {:?}, {:?}) - X-Span-ID: {:?}", limit, offset, sort, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn artists_id_get(
+        &self,
+        id: i32,
+        context: &C) -> Result<ArtistsIdGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("artists_id_get({}) - X-Span-ID: {:?}", id, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn artists_index_get(
+        &self,
+        context: &C) -> Result<ArtistsIndexGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("artists_index_get() - X-Span-ID: {:?}", context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn library_scan_post(
+        &self,
+        path: Option<String>,
+        context: &C) -> Result<LibraryScanPostResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("library_scan_post({:?}) - X-Span-ID: {:?}", path, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
     async fn playlists_get(
         &self,
         context: &C) -> Result<PlaylistsGetResponse, ApiError>
@@ -165,10 +250,22 @@ impl<C> Api<C> for Server<C> where C: Has<XSpanIdString> + Send + Sync
         q: String,
         limit: Option<i32>,
         offset: Option<i32>,
+        sort: Option<String>,
+        facet: Option<&Vec<String>>,
+        facets: Option<bool>,
         context: &C) -> Result<SearchGetResponse, ApiError>
     {
         let context = context.clone();
-        info!("search_get(\"{}\", {:?}, {:?}) - X-Span-ID: {:?}", q, limit, offset, context.get().0.clone());
+        info!("search_get(\"{}\", {:?}, {:?}, {:?}, {:?}, {:?}) - X-Span-ID: {:?}", q, limit, offset, sort, facet, facets, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn songs_duplicates_get(
+        &self,
+        context: &C) -> Result<SongsDuplicatesGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("songs_duplicates_get() - X-Span-ID: {:?}", context.get().0.clone());
         Err(ApiError("Generic failure".into()))
     }
 
@@ -195,22 +292,74 @@ impl<C> Api<C> for Server<C> where C: Has<XSpanIdString> + Send + Sync
     async fn songs_id_put(
         &self,
         id: i32,
-        playlist: models::Playlist,
+        write_tags: Option<bool>,
+        song: models::Song,
         context: &C) -> Result<SongsIdPutResponse, ApiError>
     {
         let context = context.clone();
-        info!("songs_id_put({}, {:?}) - X-Span-ID: {:?}", id, playlist, context.get().0.clone());
+        info!("songs_id_put({}, {:?}, {:?}) - X-Span-ID: {:?}", id, write_tags, song, context.get().0.clone());
         Err(ApiError("Generic failure".into()))
     }
 
     async fn songs_post(
         &self,
         x_filename: String,
-        body: String,
+        body: swagger::ByteArray,
         context: &C) -> Result<SongsPostResponse, ApiError>
     {
         let context = context.clone();
-        info!("songs_post(\"{}\", \"{}\") - X-Span-ID: {:?}", x_filename, body, context.get().0.clone());
+        info!("songs_post(\"{}\", {:?}) - X-Span-ID: {:?}", x_filename, body, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn suggest_get(
+        &self,
+        q: String,
+        limit: Option<i32>,
+        context: &C) -> Result<SuggestGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("suggest_get(\"{}\", {:?}) - X-Span-ID: {:?}", q, limit, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn users_get(
+        &self,
+        context: &C) -> Result<UsersGetResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("users_get() - X-Span-ID: {:?}", context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn users_id_delete(
+        &self,
+        id: i32,
+        context: &C) -> Result<UsersIdDeleteResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("users_id_delete({}) - X-Span-ID: {:?}", id, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn users_id_put(
+        &self,
+        id: i32,
+        user: models::User,
+        context: &C) -> Result<UsersIdPutResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("users_id_put({}, {:?}) - X-Span-ID: {:?}", id, user, context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    async fn users_post(
+        &self,
+        user: models::User,
+        context: &C) -> Result<UsersPostResponse, ApiError>
+    {
+        let context = context.clone();
+        info!("users_post({:?}) - X-Span-ID: {:?}", user, context.get().0.clone());
         Err(ApiError("Generic failure".into()))
     }
 
@@ -36,16 +36,29 @@ const FRAGMENT_ENCODE_SET: &AsciiSet = &percent_encoding::CONTROLS
 const ID_ENCODE_SET: &AsciiSet = &FRAGMENT_ENCODE_SET.add(b'|');
 
 use crate::{Api,
+     AlbumsGetResponse,
+     AlbumsIdGetResponse,
+     AlbumsIndexGetResponse,
+     ArtistsGetResponse,
+     ArtistsIdGetResponse,
+     ArtistsIndexGetResponse,
+     LibraryScanPostResponse,
      PlaylistsGetResponse,
      PlaylistsIdDeleteResponse,
      PlaylistsIdGetResponse,
      PlaylistsPostResponse,
      RootGetResponse,
      SearchGetResponse,
+     SongsDuplicatesGetResponse,
      SongsIdDeleteResponse,
      SongsIdGetResponse,
      SongsIdPutResponse,
-     SongsPostResponse
+     SongsPostResponse,
+     SuggestGetResponse,
+     UsersGetResponse,
+     UsersIdDeleteResponse,
+     UsersIdPutResponse,
+     UsersPostResponse
      };
 
 /// Convert input into a base path, e.g. "http://example:123". Also checks the scheme as it goes.
@@ -389,13 +402,1268 @@ impl<S, C> Api<C> for Client<S, C> where
         }
     }
 
+    async fn albums_get(
+        &self,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+        param_sort: Option<String>,
+        context: &C) -> Result<AlbumsGetResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/albums",
+            self.base_path
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            if let Some(param_limit) = param_limit {
+                query_string.append_pair("limit",
+                    &param_limit.to_string());
+            }
+            if let Some(param_offset) = param_offset {
+                query_string.append_pair("offset",
+                    &param_offset.to_string());
+            }
+            if let Some(param_sort) = param_sort {
+                query_string.append_pair("sort",
+                    &param_sort);
+            }
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let response_x_total_count = match response.headers().get(HeaderName::from_static("x-total-count")) {
+                    Some(response_x_total_count) => {
+                        let response_x_total_count = response_x_total_count.clone();
+                        let response_x_total_count = match TryInto::<header::IntoHeaderValue<i64>>::try_into(response_x_total_count) {
+                            Ok(value) => value,
+                            Err(e) => {
+                                return Err(ApiError(format!("Invalid response header X-Total-Count for response 200 - {}", e)));
+                            },
+                        };
+                        let response_x_total_count = response_x_total_count.0;
+                        Some(response_x_total_count)
+                        },
+                    None => None,
+                };
+
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<Vec<models::Album>>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(AlbumsGetResponse::ListOfAlbums
+                    {
+                        body,
+                        x_total_count: response_x_total_count,
+                    }
+                )
+            }
+            400 => {
+                Ok(
+                    AlbumsGetResponse::WrongData
+                )
+            }
+            0 => {
+                Ok(
+                    AlbumsGetResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn albums_id_get(
+        &self,
+        param_id: i32,
+        context: &C) -> Result<AlbumsIdGetResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/albums/{id}",
+            self.base_path
+            ,id=utf8_percent_encode(&param_id.to_string(), ID_ENCODE_SET)
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::Album>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(AlbumsIdGetResponse::Album
+                    (body)
+                )
+            }
+            404 => {
+                Ok(
+                    AlbumsIdGetResponse::UnknownAlbum
+                )
+            }
+            0 => {
+                Ok(
+                    AlbumsIdGetResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn albums_index_get(
+        &self,
+        context: &C) -> Result<AlbumsIndexGetResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/albums/index",
+            self.base_path
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<Vec<models::IndexBucket>>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(AlbumsIndexGetResponse::AlphabeticalIndex
+                    (body)
+                )
+            }
+            0 => {
+                Ok(
+                    AlbumsIndexGetResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn artists_get(
+        &self,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+        param_sort: Option<String>,
+        context: &C) -> Result<ArtistsGetResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/artists",
+            self.base_path
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            if let Some(param_limit) = param_limit {
+                query_string.append_pair("limit",
+                    &param_limit.to_string());
+            }
+            if let Some(param_offset) = param_offset {
+                query_string.append_pair("offset",
+                    &param_offset.to_string());
+            }
+            if let Some(param_sort) = param_sort {
+                query_string.append_pair("sort",
+                    &param_sort);
+            }
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let response_x_total_count = match response.headers().get(HeaderName::from_static("x-total-count")) {
+                    Some(response_x_total_count) => {
+                        let response_x_total_count = response_x_total_count.clone();
+                        let response_x_total_count = match TryInto::<header::IntoHeaderValue<i64>>::try_into(response_x_total_count) {
+                            Ok(value) => value,
+                            Err(e) => {
+                                return Err(ApiError(format!("Invalid response header X-Total-Count for response 200 - {}", e)));
+                            },
+                        };
+                        let response_x_total_count = response_x_total_count.0;
+                        Some(response_x_total_count)
+                        },
+                    None => None,
+                };
+
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<Vec<models::Artist>>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(ArtistsGetResponse::ListOfArtists
+                    {
+                        body,
+                        x_total_count: response_x_total_count,
+                    }
+                )
+            }
+            400 => {
+                Ok(
+                    ArtistsGetResponse::WrongData
+                )
+            }
+            0 => {
+                Ok(
+                    ArtistsGetResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn artists_id_get(
+        &self,
+        param_id: i32,
+        context: &C) -> Result<ArtistsIdGetResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/artists/{id}",
+            self.base_path
+            ,id=utf8_percent_encode(&param_id.to_string(), ID_ENCODE_SET)
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::Artist>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(ArtistsIdGetResponse::Artist
+                    (body)
+                )
+            }
+            404 => {
+                Ok(
+                    ArtistsIdGetResponse::UnknownArtist
+                )
+            }
+            0 => {
+                Ok(
+                    ArtistsIdGetResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn artists_index_get(
+        &self,
+        context: &C) -> Result<ArtistsIndexGetResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/artists/index",
+            self.base_path
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<Vec<models::IndexBucket>>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(ArtistsIndexGetResponse::AlphabeticalIndex
+                    (body)
+                )
+            }
+            0 => {
+                Ok(
+                    ArtistsIndexGetResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn library_scan_post(
+        &self,
+        param_path: Option<String>,
+        context: &C) -> Result<LibraryScanPostResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/library/scan",
+            self.base_path
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            if let Some(param_path) = param_path {
+                query_string.append_pair("path",
+                    &param_path);
+            }
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("POST")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::ScanReport>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(LibraryScanPostResponse::ScanDone
+                    (body)
+                )
+            }
+            400 => {
+                Ok(
+                    LibraryScanPostResponse::WrongData
+                )
+            }
+            403 => {
+                Ok(
+                    LibraryScanPostResponse::Forbidden
+                )
+            }
+            409 => {
+                Ok(
+                    LibraryScanPostResponse::ScanAlreadyRunning
+                )
+            }
+            0 => {
+                Ok(
+                    LibraryScanPostResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
     async fn playlists_get(
         &self,
-        context: &C) -> Result<PlaylistsGetResponse, ApiError>
+        context: &C) -> Result<PlaylistsGetResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/playlists",
+            self.base_path
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<Vec<models::Playlist>>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(PlaylistsGetResponse::ListAllPlaylists
+                    (body)
+                )
+            }
+            0 => {
+                Ok(
+                    PlaylistsGetResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn playlists_id_delete(
+        &self,
+        param_id: i32,
+        context: &C) -> Result<PlaylistsIdDeleteResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/playlists/{id}",
+            self.base_path
+            ,id=utf8_percent_encode(&param_id.to_string(), ID_ENCODE_SET)
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("DELETE")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                Ok(
+                    PlaylistsIdDeleteResponse::PlaylistDeleted
+                )
+            }
+            403 => {
+                Ok(
+                    PlaylistsIdDeleteResponse::PlaylistSharedByAnotherUser
+                )
+            }
+            404 => {
+                Ok(
+                    PlaylistsIdDeleteResponse::UnknownPlaylist
+                )
+            }
+            0 => {
+                Ok(
+                    PlaylistsIdDeleteResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn playlists_id_get(
+        &self,
+        param_id: i32,
+        context: &C) -> Result<PlaylistsIdGetResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/playlists/{id}",
+            self.base_path
+            ,id=utf8_percent_encode(&param_id.to_string(), ID_ENCODE_SET)
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::Playlist>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(PlaylistsIdGetResponse::Playlist
+                    (body)
+                )
+            }
+            404 => {
+                Ok(
+                    PlaylistsIdGetResponse::UnknownPlaylist
+                )
+            }
+            0 => {
+                Ok(
+                    PlaylistsIdGetResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn playlists_post(
+        &self,
+        param_playlist: models::Playlist,
+        context: &C) -> Result<PlaylistsPostResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/playlists",
+            self.base_path
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("POST")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let body = serde_json::to_string(&param_playlist).expect("impossible to fail to serialize");
+                *request.body_mut() = Body::from(body);
+
+        let header = "application/json";
+        request.headers_mut().insert(CONTENT_TYPE, match HeaderValue::from_str(header) {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create header: {} - {}", header, e)))
+        });
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            201 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::Playlist>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(PlaylistsPostResponse::PlaylistSuccessfullyCreated
+                    (body)
+                )
+            }
+            400 => {
+                Ok(
+                    PlaylistsPostResponse::WrongData
+                )
+            }
+            0 => {
+                Ok(
+                    PlaylistsPostResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn root_get(
+        &self,
+        context: &C) -> Result<RootGetResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/",
+            self.base_path
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::Informations>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(RootGetResponse::Ok
+                    (body)
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn search_get(
+        &self,
+        param_q: String,
+        param_limit: Option<i32>,
+        param_offset: Option<i32>,
+        param_sort: Option<String>,
+        param_facet: Option<&Vec<String>>,
+        param_facets: Option<bool>,
+        context: &C) -> Result<SearchGetResponse, ApiError>
+    {
+        let mut client_service = self.client_service.clone();
+        let mut uri = format!(
+            "{}/api/v1/search",
+            self.base_path
+        );
+
+        // Query parameters
+        let query_string = {
+            let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+                query_string.append_pair("q",
+                    &param_q);
+            if let Some(param_limit) = param_limit {
+                query_string.append_pair("limit",
+                    &param_limit.to_string());
+            }
+            if let Some(param_offset) = param_offset {
+                query_string.append_pair("offset",
+                    &param_offset.to_string());
+            }
+            if let Some(param_sort) = param_sort {
+                query_string.append_pair("sort",
+                    &param_sort);
+            }
+            if let Some(param_facet) = param_facet {
+                query_string.append_pair("facet",
+                    &param_facet.iter().map(ToString::to_string).collect::<Vec<String>>().join(","));
+            }
+            if let Some(param_facets) = param_facets {
+                query_string.append_pair("facets",
+                    &param_facets.to_string());
+            }
+            query_string.finish()
+        };
+        if !query_string.is_empty() {
+            uri += "?";
+            uri += &query_string;
+        }
+
+        let uri = match Uri::from_str(&uri) {
+            Ok(uri) => uri,
+            Err(err) => return Err(ApiError(format!("Unable to build URI: {}", err))),
+        };
+
+        let mut request = match Request::builder()
+            .method("GET")
+            .uri(uri)
+            .body(Body::empty()) {
+                Ok(req) => req,
+                Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
+        };
+
+        let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
+        request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
+        });
+
+        let response = client_service.call((request, context.clone()))
+            .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let response_x_total_count = match response.headers().get(HeaderName::from_static("x-total-count")) {
+                    Some(response_x_total_count) => {
+                        let response_x_total_count = response_x_total_count.clone();
+                        let response_x_total_count = match TryInto::<header::IntoHeaderValue<i64>>::try_into(response_x_total_count) {
+                            Ok(value) => value,
+                            Err(e) => {
+                                return Err(ApiError(format!("Invalid response header X-Total-Count for response 200 - {}", e)));
+                            },
+                        };
+                        let response_x_total_count = response_x_total_count.0;
+                        Some(response_x_total_count)
+                        },
+                    None => None,
+                };
+
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::SearchResult>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(SearchGetResponse::SongsMatchingQuery
+                    {
+                        body,
+                        x_total_count: response_x_total_count,
+                    }
+                )
+            }
+            400 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::QueryError>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(SearchGetResponse::InvalidQuery
+                    (body)
+                )
+            }
+            0 => {
+                Ok(
+                    SearchGetResponse::UnexpectedError
+                )
+            }
+            code => {
+                let headers = response.headers().clone();
+                let body = response.into_body()
+                       .take(100)
+                       .into_raw().await;
+                Err(ApiError(format!("Unexpected response code {}:\n{:?}\n\n{}",
+                    code,
+                    headers,
+                    match body {
+                        Ok(body) => match String::from_utf8(body) {
+                            Ok(body) => body,
+                            Err(e) => format!("<Body was not UTF8: {:?}>", e),
+                        },
+                        Err(e) => format!("<Failed to read body: {}>", e),
+                    }
+                )))
+            }
+        }
+    }
+
+    async fn songs_duplicates_get(
+        &self,
+        context: &C) -> Result<SongsDuplicatesGetResponse, ApiError>
     {
         let mut client_service = self.client_service.clone();
         let mut uri = format!(
-            "{}/api/v1/playlists",
+            "{}/api/v1/songs/duplicates",
             self.base_path
         );
 
@@ -433,24 +1701,24 @@ impl<S, C> Api<C> for Client<S, C> where
 
         match response.status().as_u16() {
             200 => {
-                Ok(
-                    PlaylistsGetResponse::ListAllPlayslists
-                )
-            }
-            0 => {
                 let body = response.into_body();
                 let body = body
                         .into_raw()
                         .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
                 let body = str::from_utf8(&body)
                     .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
-                let body = serde_json::from_str::<Vec<models::Playlist>>(body).map_err(|e| {
+                let body = serde_json::from_str::<Vec<models::NearDuplicates>>(body).map_err(|e| {
                     ApiError(format!("Response body did not match the schema: {}", e))
                 })?;
-                Ok(PlaylistsGetResponse::UnexpectedError
+                Ok(SongsDuplicatesGetResponse::NearDuplicates
                     (body)
                 )
             }
+            0 => {
+                Ok(
+                    SongsDuplicatesGetResponse::UnexpectedError
+                )
+            }
             code => {
                 let headers = response.headers().clone();
                 let body = response.into_body()
@@ -471,14 +1739,14 @@ impl<S, C> Api<C> for Client<S, C> where
         }
     }
 
-    async fn playlists_id_delete(
+    async fn songs_id_delete(
         &self,
         param_id: i32,
-        context: &C) -> Result<PlaylistsIdDeleteResponse, ApiError>
+        context: &C) -> Result<SongsIdDeleteResponse, ApiError>
     {
         let mut client_service = self.client_service.clone();
         let mut uri = format!(
-            "{}/api/v1/playlists/{id}",
+            "{}/api/v1/songs/{id}",
             self.base_path
             ,id=utf8_percent_encode(&param_id.to_string(), ID_ENCODE_SET)
         );
@@ -518,17 +1786,17 @@ impl<S, C> Api<C> for Client<S, C> where
         match response.status().as_u16() {
             200 => {
                 Ok(
-                    PlaylistsIdDeleteResponse::PlaylistDeleted
+                    SongsIdDeleteResponse::SongDeleted
                 )
             }
             404 => {
                 Ok(
-                    PlaylistsIdDeleteResponse::UnknownPlaylist
+                    SongsIdDeleteResponse::UnknownSong
                 )
             }
             0 => {
                 Ok(
-                    PlaylistsIdDeleteResponse::UnexpectedError
+                    SongsIdDeleteResponse::UnexpectedError
                 )
             }
             code => {
@@ -551,14 +1819,14 @@ impl<S, C> Api<C> for Client<S, C> where
         }
     }
 
-    async fn playlists_id_get(
+    async fn songs_id_get(
         &self,
         param_id: i32,
-        context: &C) -> Result<PlaylistsIdGetResponse, ApiError>
+        context: &C) -> Result<SongsIdGetResponse, ApiError>
     {
         let mut client_service = self.client_service.clone();
         let mut uri = format!(
-            "{}/api/v1/playlists/{id}",
+            "{}/api/v1/songs/{id}",
             self.base_path
             ,id=utf8_percent_encode(&param_id.to_string(), ID_ENCODE_SET)
         );
@@ -603,21 +1871,21 @@ impl<S, C> Api<C> for Client<S, C> where
                         .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
                 let body = str::from_utf8(&body)
                     .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
-                let body = serde_json::from_str::<models::Playlist>(body).map_err(|e| {
+                let body = serde_json::from_str::<models::Song>(body).map_err(|e| {
                     ApiError(format!("Response body did not match the schema: {}", e))
                 })?;
-                Ok(PlaylistsIdGetResponse::Playlist
+                Ok(SongsIdGetResponse::SongMetadata
                     (body)
                 )
             }
             404 => {
                 Ok(
-                    PlaylistsIdGetResponse::UnknownPlaylist
+                    SongsIdGetResponse::UnknownSong
                 )
             }
             0 => {
                 Ok(
-                    PlaylistsIdGetResponse::UnexpectedError
+                    SongsIdGetResponse::UnexpectedError
                 )
             }
             code => {
@@ -640,20 +1908,27 @@ impl<S, C> Api<C> for Client<S, C> where
         }
     }
 
-    async fn playlists_post(
+    async fn songs_id_put(
         &self,
-        param_playlist: models::Playlist,
-        context: &C) -> Result<PlaylistsPostResponse, ApiError>
+        param_id: i32,
+        param_write_tags: Option<bool>,
+        param_song: models::Song,
+        context: &C) -> Result<SongsIdPutResponse, ApiError>
     {
         let mut client_service = self.client_service.clone();
         let mut uri = format!(
-            "{}/api/v1/playlists",
+            "{}/api/v1/songs/{id}",
             self.base_path
+            ,id=utf8_percent_encode(&param_id.to_string(), ID_ENCODE_SET)
         );
 
         // Query parameters
         let query_string = {
             let mut query_string = form_urlencoded::Serializer::new("".to_owned());
+            if let Some(param_write_tags) = param_write_tags {
+                query_string.append_pair("write_tags",
+                    &param_write_tags.to_string());
+            }
             query_string.finish()
         };
         if !query_string.is_empty() {
@@ -667,14 +1942,14 @@ impl<S, C> Api<C> for Client<S, C> where
         };
 
         let mut request = match Request::builder()
-            .method("POST")
+            .method("PUT")
             .uri(uri)
             .body(Body::empty()) {
                 Ok(req) => req,
                 Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
         };
 
-        let body = serde_json::to_string(&param_playlist).expect("impossible to fail to serialize");
+        let body = serde_json::to_string(&param_song).expect("impossible to fail to serialize");
                 *request.body_mut() = Body::from(body);
 
         let header = "application/json";
@@ -692,14 +1967,33 @@ impl<S, C> Api<C> for Client<S, C> where
             .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
 
         match response.status().as_u16() {
-            201 => {
+            200 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::Song>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(SongsIdPutResponse::SongUpdated
+                    (body)
+                )
+            }
+            404 => {
+                Ok(
+                    SongsIdPutResponse::UnknownSong
+                )
+            }
+            400 => {
                 Ok(
-                    PlaylistsPostResponse::PlaylistSuccessfullyCreated
+                    SongsIdPutResponse::WrongData
                 )
             }
             0 => {
                 Ok(
-                    PlaylistsPostResponse::UnexpectedError
+                    SongsIdPutResponse::UnexpectedError
                 )
             }
             code => {
@@ -722,13 +2016,15 @@ impl<S, C> Api<C> for Client<S, C> where
         }
     }
 
-    async fn root_get(
+    async fn songs_post(
         &self,
-        context: &C) -> Result<RootGetResponse, ApiError>
+        param_x_filename: String,
+        param_body: swagger::ByteArray,
+        context: &C) -> Result<SongsPostResponse, ApiError>
     {
         let mut client_service = self.client_service.clone();
         let mut uri = format!(
-            "{}/api/v1/",
+            "{}/api/v1/songs",
             self.base_path
         );
 
@@ -748,37 +2044,83 @@ impl<S, C> Api<C> for Client<S, C> where
         };
 
         let mut request = match Request::builder()
-            .method("GET")
+            .method("POST")
             .uri(uri)
             .body(Body::empty()) {
                 Ok(req) => req,
                 Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
         };
 
+        let body = param_body.0;
+                *request.body_mut() = Body::from(body);
+
+        let header = "audio/*";
+        request.headers_mut().insert(CONTENT_TYPE, match HeaderValue::from_str(header) {
+            Ok(h) => h,
+            Err(e) => return Err(ApiError(format!("Unable to create header: {} - {}", header, e)))
+        });
+
         let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
         request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
             Ok(h) => h,
             Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
         });
 
+        // Header parameters
+        request.headers_mut().append(
+            HeaderName::from_static("x-filename"),
+            #[allow(clippy::redundant_clone)]
+            match header::IntoHeaderValue(param_x_filename.clone()).try_into() {
+                Ok(header) => header,
+                Err(e) => {
+                    return Err(ApiError(format!(
+                        "Invalid header x_filename - {}", e)));
+                },
+            });
+
         let response = client_service.call((request, context.clone()))
             .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
 
         match response.status().as_u16() {
-            200 => {
+            201 => {
+                Ok(
+                    SongsPostResponse::SuccessfulOperation
+                )
+            }
+            400 => {
+                Ok(
+                    SongsPostResponse::WrongData
+                )
+            }
+            413 => {
+                Ok(
+                    SongsPostResponse::FileTooLarge
+                )
+            }
+            409 => {
                 let body = response.into_body();
                 let body = body
                         .into_raw()
                         .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
                 let body = str::from_utf8(&body)
                     .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
-                let body = serde_json::from_str::<models::Informations>(body).map_err(|e| {
+                let body = serde_json::from_str::<models::Song>(body).map_err(|e| {
                     ApiError(format!("Response body did not match the schema: {}", e))
                 })?;
-                Ok(RootGetResponse::Ok
+                Ok(SongsPostResponse::SongAlreadyExists
                     (body)
                 )
             }
+            415 => {
+                Ok(
+                    SongsPostResponse::FileFormatNotSupported
+                )
+            }
+            0 => {
+                Ok(
+                    SongsPostResponse::UnexpectedError
+                )
+            }
             code => {
                 let headers = response.headers().clone();
                 let body = response.into_body()
@@ -799,16 +2141,15 @@ impl<S, C> Api<C> for Client<S, C> where
         }
     }
 
-    async fn search_get(
+    async fn suggest_get(
         &self,
         param_q: String,
         param_limit: Option<i32>,
-        param_offset: Option<i32>,
-        context: &C) -> Result<SearchGetResponse, ApiError>
+        context: &C) -> Result<SuggestGetResponse, ApiError>
     {
         let mut client_service = self.client_service.clone();
         let mut uri = format!(
-            "{}/api/v1/search",
+            "{}/api/v1/suggest",
             self.base_path
         );
 
@@ -821,10 +2162,6 @@ impl<S, C> Api<C> for Client<S, C> where
                 query_string.append_pair("limit",
                     &param_limit.to_string());
             }
-            if let Some(param_offset) = param_offset {
-                query_string.append_pair("offset",
-                    &param_offset.to_string());
-            }
             query_string.finish()
         };
         if !query_string.is_empty() {
@@ -862,13 +2199,18 @@ impl<S, C> Api<C> for Client<S, C> where
                         .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
                 let body = str::from_utf8(&body)
                     .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
-                let body = serde_json::from_str::<Vec<models::Song>>(body).map_err(|e| {
+                let body = serde_json::from_str::<models::Suggestions>(body).map_err(|e| {
                     ApiError(format!("Response body did not match the schema: {}", e))
                 })?;
-                Ok(SearchGetResponse::ListOfSongMatchingQuery
+                Ok(SuggestGetResponse::Suggestions
                     (body)
                 )
             }
+            0 => {
+                Ok(
+                    SuggestGetResponse::UnexpectedError
+                )
+            }
             code => {
                 let headers = response.headers().clone();
                 let body = response.into_body()
@@ -889,16 +2231,14 @@ impl<S, C> Api<C> for Client<S, C> where
         }
     }
 
-    async fn songs_id_delete(
+    async fn users_get(
         &self,
-        param_id: i32,
-        context: &C) -> Result<SongsIdDeleteResponse, ApiError>
+        context: &C) -> Result<UsersGetResponse, ApiError>
     {
         let mut client_service = self.client_service.clone();
         let mut uri = format!(
-            "{}/api/v1/songs/{id}",
+            "{}/api/v1/users",
             self.base_path
-            ,id=utf8_percent_encode(&param_id.to_string(), ID_ENCODE_SET)
         );
 
         // Query parameters
@@ -917,7 +2257,7 @@ impl<S, C> Api<C> for Client<S, C> where
         };
 
         let mut request = match Request::builder()
-            .method("DELETE")
+            .method("GET")
             .uri(uri)
             .body(Body::empty()) {
                 Ok(req) => req,
@@ -935,18 +2275,27 @@ impl<S, C> Api<C> for Client<S, C> where
 
         match response.status().as_u16() {
             200 => {
-                Ok(
-                    SongsIdDeleteResponse::SongDeleted
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<Vec<models::User>>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(UsersGetResponse::ListOfUsers
+                    (body)
                 )
             }
-            404 => {
+            403 => {
                 Ok(
-                    SongsIdDeleteResponse::UnknownSong
+                    UsersGetResponse::Forbidden
                 )
             }
             0 => {
                 Ok(
-                    SongsIdDeleteResponse::UnexpectedError
+                    UsersGetResponse::UnexpectedError
                 )
             }
             code => {
@@ -969,14 +2318,14 @@ impl<S, C> Api<C> for Client<S, C> where
         }
     }
 
-    async fn songs_id_get(
+    async fn users_id_delete(
         &self,
         param_id: i32,
-        context: &C) -> Result<SongsIdGetResponse, ApiError>
+        context: &C) -> Result<UsersIdDeleteResponse, ApiError>
     {
         let mut client_service = self.client_service.clone();
         let mut uri = format!(
-            "{}/api/v1/songs/{id}",
+            "{}/api/v1/users/{id}",
             self.base_path
             ,id=utf8_percent_encode(&param_id.to_string(), ID_ENCODE_SET)
         );
@@ -997,7 +2346,7 @@ impl<S, C> Api<C> for Client<S, C> where
         };
 
         let mut request = match Request::builder()
-            .method("GET")
+            .method("DELETE")
             .uri(uri)
             .body(Body::empty()) {
                 Ok(req) => req,
@@ -1015,27 +2364,28 @@ impl<S, C> Api<C> for Client<S, C> where
 
         match response.status().as_u16() {
             200 => {
-                let body = response.into_body();
-                let body = body
-                        .into_raw()
-                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
-                let body = str::from_utf8(&body)
-                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
-                let body = serde_json::from_str::<models::Song>(body).map_err(|e| {
-                    ApiError(format!("Response body did not match the schema: {}", e))
-                })?;
-                Ok(SongsIdGetResponse::SongMetadata
-                    (body)
+                Ok(
+                    UsersIdDeleteResponse::UserDeleted
+                )
+            }
+            403 => {
+                Ok(
+                    UsersIdDeleteResponse::Forbidden
                 )
             }
             404 => {
                 Ok(
-                    SongsIdGetResponse::UnknownSong
+                    UsersIdDeleteResponse::UnknownUser
+                )
+            }
+            422 => {
+                Ok(
+                    UsersIdDeleteResponse::LastAdministrator
                 )
             }
             0 => {
                 Ok(
-                    SongsIdGetResponse::UnexpectedError
+                    UsersIdDeleteResponse::UnexpectedError
                 )
             }
             code => {
@@ -1058,15 +2408,15 @@ impl<S, C> Api<C> for Client<S, C> where
         }
     }
 
-    async fn songs_id_put(
+    async fn users_id_put(
         &self,
         param_id: i32,
-        param_playlist: models::Playlist,
-        context: &C) -> Result<SongsIdPutResponse, ApiError>
+        param_user: models::User,
+        context: &C) -> Result<UsersIdPutResponse, ApiError>
     {
         let mut client_service = self.client_service.clone();
         let mut uri = format!(
-            "{}/api/v1/songs/{id}",
+            "{}/api/v1/users/{id}",
             self.base_path
             ,id=utf8_percent_encode(&param_id.to_string(), ID_ENCODE_SET)
         );
@@ -1094,7 +2444,7 @@ impl<S, C> Api<C> for Client<S, C> where
                 Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
         };
 
-        let body = serde_json::to_string(&param_playlist).expect("impossible to fail to serialize");
+        let body = serde_json::to_string(&param_user).expect("impossible to fail to serialize");
                 *request.body_mut() = Body::from(body);
 
         let header = "application/json";
@@ -1113,23 +2463,47 @@ impl<S, C> Api<C> for Client<S, C> where
 
         match response.status().as_u16() {
             200 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::User>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(UsersIdPutResponse::UserUpdated
+                    (body)
+                )
+            }
+            400 => {
                 Ok(
-                    SongsIdPutResponse::PlaylistUpdated
+                    UsersIdPutResponse::WrongData
+                )
+            }
+            403 => {
+                Ok(
+                    UsersIdPutResponse::Forbidden
                 )
             }
             404 => {
                 Ok(
-                    SongsIdPutResponse::UnknownPlaylist
+                    UsersIdPutResponse::UnknownUser
                 )
             }
-            400 => {
+            409 => {
                 Ok(
-                    SongsIdPutResponse::WrongData
+                    UsersIdPutResponse::UserNameAlreadyUsed
+                )
+            }
+            422 => {
+                Ok(
+                    UsersIdPutResponse::LastAdministrator
                 )
             }
             0 => {
                 Ok(
-                    SongsIdPutResponse::UnexpectedError
+                    UsersIdPutResponse::UnexpectedError
                 )
             }
             code => {
@@ -1152,15 +2526,14 @@ impl<S, C> Api<C> for Client<S, C> where
         }
     }
 
-    async fn songs_post(
+    async fn users_post(
         &self,
-        param_x_filename: String,
-        param_body: String,
-        context: &C) -> Result<SongsPostResponse, ApiError>
+        param_user: models::User,
+        context: &C) -> Result<UsersPostResponse, ApiError>
     {
         let mut client_service = self.client_service.clone();
         let mut uri = format!(
-            "{}/api/v1/songs",
+            "{}/api/v1/users",
             self.base_path
         );
 
@@ -1187,51 +2560,56 @@ impl<S, C> Api<C> for Client<S, C> where
                 Err(e) => return Err(ApiError(format!("Unable to create request: {}", e)))
         };
 
-        let body = param_body;
-
+        let body = serde_json::to_string(&param_user).expect("impossible to fail to serialize");
                 *request.body_mut() = Body::from(body);
 
-        let header = "audio/*";
+        let header = "application/json";
         request.headers_mut().insert(CONTENT_TYPE, match HeaderValue::from_str(header) {
             Ok(h) => h,
             Err(e) => return Err(ApiError(format!("Unable to create header: {} - {}", header, e)))
         });
-
         let header = HeaderValue::from_str(Has::<XSpanIdString>::get(context).0.as_str());
         request.headers_mut().insert(HeaderName::from_static("x-span-id"), match header {
             Ok(h) => h,
             Err(e) => return Err(ApiError(format!("Unable to create X-Span ID header value: {}", e)))
         });
 
-        // Header parameters
-        request.headers_mut().append(
-            HeaderName::from_static("x-filename"),
-            #[allow(clippy::redundant_clone)]
-            match header::IntoHeaderValue(param_x_filename.clone()).try_into() {
-                Ok(header) => header,
-                Err(e) => {
-                    return Err(ApiError(format!(
-                        "Invalid header x_filename - {}", e)));
-                },
-            });
-
         let response = client_service.call((request, context.clone()))
             .map_err(|e| ApiError(format!("No response received: {}", e))).await?;
 
         match response.status().as_u16() {
             201 => {
+                let body = response.into_body();
+                let body = body
+                        .into_raw()
+                        .map_err(|e| ApiError(format!("Failed to read response: {}", e))).await?;
+                let body = str::from_utf8(&body)
+                    .map_err(|e| ApiError(format!("Response was not valid UTF8: {}", e)))?;
+                let body = serde_json::from_str::<models::User>(body).map_err(|e| {
+                    ApiError(format!("Response body did not match the schema: {}", e))
+                })?;
+                Ok(UsersPostResponse::UserCreated
+                    (body)
+                )
+            }
+            400 => {
                 Ok(
-                    SongsPostResponse::SuccessfulOperation
+                    UsersPostResponse::WrongData
                 )
             }
-            415 => {
+            403 => {
                 Ok(
-                    SongsPostResponse::FileFormatNotSupported
+                    UsersPostResponse::Forbidden
+                )
+            }
+            409 => {
+                Ok(
+                    UsersPostResponse::UserAlreadyExists
                 )
             }
             0 => {
                 Ok(
-                    SongsPostResponse::UnexpectedError
+                    UsersPostResponse::UnexpectedError
                 )
             }
             code => {
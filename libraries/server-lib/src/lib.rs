@@ -1,6 +1,6 @@
 #![allow(missing_docs, trivial_casts, unused_variables, unused_mut, unused_imports, unused_extern_crates, non_camel_case_types)]
 #![allow(unused_imports, unused_attributes)]
-#![allow(clippy::derive_partial_eq_without_eq, clippy::disallowed_names)]
+#![allow(clippy::derive_partial_eq_without_eq, clippy::disallowed_names, clippy::large_enum_variant)]
 
 use async_trait::async_trait;
 use futures::Stream;
@@ -16,13 +16,123 @@ pub const API_VERSION: &str = "0.1.0";
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
-pub enum PlaylistsGetResponse {
-    /// List all playslists
-    ListAllPlayslists
+pub enum AlbumsGetResponse {
+    /// List of albums
+    ListOfAlbums
+    {
+        body: Vec<models::Album>,
+        x_total_count:
+        Option<
+        i64
+        >
+    }
+    ,
+    /// Wrong data
+    WrongData
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum AlbumsIdGetResponse {
+    /// Album
+    Album
+    (models::Album)
+    ,
+    /// Unknown album
+    UnknownAlbum
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum AlbumsIndexGetResponse {
+    /// Alphabetical index
+    AlphabeticalIndex
+    (Vec<models::IndexBucket>)
     ,
     /// Unexpected error
     UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ArtistsGetResponse {
+    /// List of artists
+    ListOfArtists
+    {
+        body: Vec<models::Artist>,
+        x_total_count:
+        Option<
+        i64
+        >
+    }
+    ,
+    /// Wrong data
+    WrongData
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ArtistsIdGetResponse {
+    /// Artist
+    Artist
+    (models::Artist)
+    ,
+    /// Unknown artist
+    UnknownArtist
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ArtistsIndexGetResponse {
+    /// Alphabetical index
+    AlphabeticalIndex
+    (Vec<models::IndexBucket>)
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum LibraryScanPostResponse {
+    /// Scan done
+    ScanDone
+    (models::ScanReport)
+    ,
+    /// Wrong data
+    WrongData
+    ,
+    /// Forbidden
+    Forbidden
+    ,
+    /// Scan already running
+    ScanAlreadyRunning
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum PlaylistsGetResponse {
+    /// List all playlists
+    ListAllPlaylists
     (Vec<models::Playlist>)
+    ,
+    /// Unexpected error
+    UnexpectedError
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -31,6 +141,9 @@ pub enum PlaylistsIdDeleteResponse {
     /// Playlist deleted
     PlaylistDeleted
     ,
+    /// Playlist shared by another user
+    PlaylistSharedByAnotherUser
+    ,
     /// Unknown playlist
     UnknownPlaylist
     ,
@@ -57,6 +170,10 @@ pub enum PlaylistsIdGetResponse {
 pub enum PlaylistsPostResponse {
     /// Playlist successfully created
     PlaylistSuccessfullyCreated
+    (models::Playlist)
+    ,
+    /// Wrong data
+    WrongData
     ,
     /// Unexpected error
     UnexpectedError
@@ -70,10 +187,35 @@ pub enum RootGetResponse {
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
 pub enum SearchGetResponse {
-    /// List of song matching query
-    ListOfSongMatchingQuery
-    (Vec<models::Song>)
+    /// Songs matching query
+    SongsMatchingQuery
+    {
+        body: models::SearchResult,
+        x_total_count:
+        Option<
+        i64
+        >
+    }
+    ,
+    /// Invalid query
+    InvalidQuery
+    (models::QueryError)
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum SongsDuplicatesGetResponse {
+    /// Groups of songs sharing artist and title, with close durations
+    NearDuplicates
+    (Vec<models::NearDuplicates>)
+    ,
+    /// Unexpected error
+    UnexpectedError
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -106,11 +248,12 @@ pub enum SongsIdGetResponse {
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 pub enum SongsIdPutResponse {
-    /// Playlist updated
-    PlaylistUpdated
+    /// Song updated
+    SongUpdated
+    (models::Song)
     ,
-    /// Unknown playlist
-    UnknownPlaylist
+    /// Unknown song
+    UnknownSong
     ,
     /// Wrong data
     WrongData
@@ -125,6 +268,16 @@ pub enum SongsPostResponse {
     /// Successful operation
     SuccessfulOperation
     ,
+    /// Wrong data
+    WrongData
+    ,
+    /// File too large
+    FileTooLarge
+    ,
+    /// Song already exists, the existing song is returned
+    SongAlreadyExists
+    (models::Song)
+    ,
     /// File format not supported
     FileFormatNotSupported
     ,
@@ -132,6 +285,96 @@ pub enum SongsPostResponse {
     UnexpectedError
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum SuggestGetResponse {
+    /// Suggestions
+    Suggestions
+    (models::Suggestions)
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum UsersGetResponse {
+    /// List of users
+    ListOfUsers
+    (Vec<models::User>)
+    ,
+    /// Forbidden
+    Forbidden
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum UsersIdDeleteResponse {
+    /// User deleted
+    UserDeleted
+    ,
+    /// Forbidden
+    Forbidden
+    ,
+    /// Unknown user
+    UnknownUser
+    ,
+    /// Last administrator
+    LastAdministrator
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum UsersIdPutResponse {
+    /// User updated
+    UserUpdated
+    (models::User)
+    ,
+    /// Wrong data
+    WrongData
+    ,
+    /// Forbidden
+    Forbidden
+    ,
+    /// Unknown user
+    UnknownUser
+    ,
+    /// User name already used
+    UserNameAlreadyUsed
+    ,
+    /// Last administrator
+    LastAdministrator
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum UsersPostResponse {
+    /// User created
+    UserCreated
+    (models::User)
+    ,
+    /// Wrong data
+    WrongData
+    ,
+    /// Forbidden
+    Forbidden
+    ,
+    /// User already exists
+    UserAlreadyExists
+    ,
+    /// Unexpected error
+    UnexpectedError
+}
+
 /// API
 #[async_trait]
 #[allow(clippy::too_many_arguments, clippy::ptr_arg)]
@@ -140,6 +383,43 @@ pub trait Api<C: Send + Sync> {
         Poll::Ready(Ok(()))
     }
 
+    async fn albums_get(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<String>,
+        context: &C) -> Result<AlbumsGetResponse, ApiError>;
+
+    async fn albums_id_get(
+        &self,
+        id: i32,
+        context: &C) -> Result<AlbumsIdGetResponse, ApiError>;
+
+    async fn albums_index_get(
+        &self,
+        context: &C) -> Result<AlbumsIndexGetResponse, ApiError>;
+
+    async fn artists_get(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<String>,
+        context: &C) -> Result<ArtistsGetResponse, ApiError>;
+
+    async fn artists_id_get(
+        &self,
+        id: i32,
+        context: &C) -> Result<ArtistsIdGetResponse, ApiError>;
+
+    async fn artists_index_get(
+        &self,
+        context: &C) -> Result<ArtistsIndexGetResponse, ApiError>;
+
+    async fn library_scan_post(
+        &self,
+        path: Option<String>,
+        context: &C) -> Result<LibraryScanPostResponse, ApiError>;
+
     async fn playlists_get(
         &self,
         context: &C) -> Result<PlaylistsGetResponse, ApiError>;
@@ -168,8 +448,15 @@ pub trait Api<C: Send + Sync> {
         q: String,
         limit: Option<i32>,
         offset: Option<i32>,
+        sort: Option<String>,
+        facet: Option<&Vec<String>>,
+        facets: Option<bool>,
         context: &C) -> Result<SearchGetResponse, ApiError>;
 
+    async fn songs_duplicates_get(
+        &self,
+        context: &C) -> Result<SongsDuplicatesGetResponse, ApiError>;
+
     async fn songs_id_delete(
         &self,
         id: i32,
@@ -183,15 +470,42 @@ pub trait Api<C: Send + Sync> {
     async fn songs_id_put(
         &self,
         id: i32,
-        playlist: models::Playlist,
+        write_tags: Option<bool>,
+        song: models::Song,
         context: &C) -> Result<SongsIdPutResponse, ApiError>;
 
     async fn songs_post(
         &self,
         x_filename: String,
-        body: String,
+        body: swagger::ByteArray,
         context: &C) -> Result<SongsPostResponse, ApiError>;
 
+    async fn suggest_get(
+        &self,
+        q: String,
+        limit: Option<i32>,
+        context: &C) -> Result<SuggestGetResponse, ApiError>;
+
+    async fn users_get(
+        &self,
+        context: &C) -> Result<UsersGetResponse, ApiError>;
+
+    async fn users_id_delete(
+        &self,
+        id: i32,
+        context: &C) -> Result<UsersIdDeleteResponse, ApiError>;
+
+    async fn users_id_put(
+        &self,
+        id: i32,
+        user: models::User,
+        context: &C) -> Result<UsersIdPutResponse, ApiError>;
+
+    async fn users_post(
+        &self,
+        user: models::User,
+        context: &C) -> Result<UsersPostResponse, ApiError>;
+
 }
 
 /// API where `Context` isn't passed on every API call
@@ -203,6 +517,43 @@ pub trait ApiNoContext<C: Send + Sync> {
 
     fn context(&self) -> &C;
 
+    async fn albums_get(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<String>,
+        ) -> Result<AlbumsGetResponse, ApiError>;
+
+    async fn albums_id_get(
+        &self,
+        id: i32,
+        ) -> Result<AlbumsIdGetResponse, ApiError>;
+
+    async fn albums_index_get(
+        &self,
+        ) -> Result<AlbumsIndexGetResponse, ApiError>;
+
+    async fn artists_get(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<String>,
+        ) -> Result<ArtistsGetResponse, ApiError>;
+
+    async fn artists_id_get(
+        &self,
+        id: i32,
+        ) -> Result<ArtistsIdGetResponse, ApiError>;
+
+    async fn artists_index_get(
+        &self,
+        ) -> Result<ArtistsIndexGetResponse, ApiError>;
+
+    async fn library_scan_post(
+        &self,
+        path: Option<String>,
+        ) -> Result<LibraryScanPostResponse, ApiError>;
+
     async fn playlists_get(
         &self,
         ) -> Result<PlaylistsGetResponse, ApiError>;
@@ -231,8 +582,15 @@ pub trait ApiNoContext<C: Send + Sync> {
         q: String,
         limit: Option<i32>,
         offset: Option<i32>,
+        sort: Option<String>,
+        facet: Option<&Vec<String>>,
+        facets: Option<bool>,
         ) -> Result<SearchGetResponse, ApiError>;
 
+    async fn songs_duplicates_get(
+        &self,
+        ) -> Result<SongsDuplicatesGetResponse, ApiError>;
+
     async fn songs_id_delete(
         &self,
         id: i32,
@@ -246,15 +604,42 @@ pub trait ApiNoContext<C: Send + Sync> {
     async fn songs_id_put(
         &self,
         id: i32,
-        playlist: models::Playlist,
+        write_tags: Option<bool>,
+        song: models::Song,
         ) -> Result<SongsIdPutResponse, ApiError>;
 
     async fn songs_post(
         &self,
         x_filename: String,
-        body: String,
+        body: swagger::ByteArray,
         ) -> Result<SongsPostResponse, ApiError>;
 
+    async fn suggest_get(
+        &self,
+        q: String,
+        limit: Option<i32>,
+        ) -> Result<SuggestGetResponse, ApiError>;
+
+    async fn users_get(
+        &self,
+        ) -> Result<UsersGetResponse, ApiError>;
+
+    async fn users_id_delete(
+        &self,
+        id: i32,
+        ) -> Result<UsersIdDeleteResponse, ApiError>;
+
+    async fn users_id_put(
+        &self,
+        id: i32,
+        user: models::User,
+        ) -> Result<UsersIdPutResponse, ApiError>;
+
+    async fn users_post(
+        &self,
+        user: models::User,
+        ) -> Result<UsersPostResponse, ApiError>;
+
 }
 
 /// Trait to extend an API to make it easy to bind it to a context.
@@ -280,6 +665,71 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
         ContextWrapper::context(self)
     }
 
+    async fn albums_get(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<String>,
+        ) -> Result<AlbumsGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().albums_get(limit, offset, sort, &context).await
+    }
+
+    async fn albums_id_get(
+        &self,
+        id: i32,
+        ) -> Result<AlbumsIdGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().albums_id_get(id, &context).await
+    }
+
+    async fn albums_index_get(
+        &self,
+        ) -> Result<AlbumsIndexGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().albums_index_get(&context).await
+    }
+
+    async fn artists_get(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<String>,
+        ) -> Result<ArtistsGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().artists_get(limit, offset, sort, &context).await
+    }
+
+    async fn artists_id_get(
+        &self,
+        id: i32,
+        ) -> Result<ArtistsIdGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().artists_id_get(id, &context).await
+    }
+
+    async fn artists_index_get(
+        &self,
+        ) -> Result<ArtistsIndexGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().artists_index_get(&context).await
+    }
+
+    async fn library_scan_post(
+        &self,
+        path: Option<String>,
+        ) -> Result<LibraryScanPostResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().library_scan_post(path, &context).await
+    }
+
     async fn playlists_get(
         &self,
         ) -> Result<PlaylistsGetResponse, ApiError>
@@ -328,10 +778,21 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
         q: String,
         limit: Option<i32>,
         offset: Option<i32>,
+        sort: Option<String>,
+        facet: Option<&Vec<String>>,
+        facets: Option<bool>,
         ) -> Result<SearchGetResponse, ApiError>
     {
         let context = self.context().clone();
-        self.api().search_get(q, limit, offset, &context).await
+        self.api().search_get(q, limit, offset, sort, facet, facets, &context).await
+    }
+
+    async fn songs_duplicates_get(
+        &self,
+        ) -> Result<SongsDuplicatesGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().songs_duplicates_get(&context).await
     }
 
     async fn songs_id_delete(
@@ -355,23 +816,70 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
     async fn songs_id_put(
         &self,
         id: i32,
-        playlist: models::Playlist,
+        write_tags: Option<bool>,
+        song: models::Song,
         ) -> Result<SongsIdPutResponse, ApiError>
     {
         let context = self.context().clone();
-        self.api().songs_id_put(id, playlist, &context).await
+        self.api().songs_id_put(id, write_tags, song, &context).await
     }
 
     async fn songs_post(
         &self,
         x_filename: String,
-        body: String,
+        body: swagger::ByteArray,
         ) -> Result<SongsPostResponse, ApiError>
     {
         let context = self.context().clone();
         self.api().songs_post(x_filename, body, &context).await
     }
 
+    async fn suggest_get(
+        &self,
+        q: String,
+        limit: Option<i32>,
+        ) -> Result<SuggestGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().suggest_get(q, limit, &context).await
+    }
+
+    async fn users_get(
+        &self,
+        ) -> Result<UsersGetResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().users_get(&context).await
+    }
+
+    async fn users_id_delete(
+        &self,
+        id: i32,
+        ) -> Result<UsersIdDeleteResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().users_id_delete(id, &context).await
+    }
+
+    async fn users_id_put(
+        &self,
+        id: i32,
+        user: models::User,
+        ) -> Result<UsersIdPutResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().users_id_put(id, user, &context).await
+    }
+
+    async fn users_post(
+        &self,
+        user: models::User,
+        ) -> Result<UsersPostResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().users_post(user, &context).await
+    }
+
 }
 
 
@@ -4,48 +4,2113 @@ use crate::models;
 #[cfg(any(feature = "client", feature = "server"))]
 use crate::header;
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Album {
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<i32>,
+
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+
+    /// Artist of the whole album
+    #[serde(rename = "artist")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub artist: Option<String>,
+
+    #[serde(rename = "artist_id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub artist_id: Option<i32>,
+
+    #[serde(rename = "year")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub year: Option<i32>,
+
+    /// Total number of tracks
+    #[serde(rename = "total_tracks")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub total_tracks: Option<i32>,
+
+    /// Number of discs of the album
+    #[serde(rename = "total_discs")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub total_discs: Option<i32>,
+
+    /// Whether the album has a cover, served by /albums/{id}/cover
+    #[serde(rename = "cover")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub cover: Option<bool>,
+
+    /// Tracks of the album in order, only returned by /albums/{id}
+    #[serde(rename = "songs")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub songs: Option<Vec<models::Song>>,
+
+}
+
+impl Album {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Album {
+        Album {
+            id: None,
+            name: None,
+            artist: None,
+            artist_id: None,
+            year: None,
+            total_tracks: None,
+            total_discs: None,
+            cover: None,
+            songs: None,
+        }
+    }
+}
+
+/// Converts the Album value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Album {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            self.id.as_ref().map(|id| {
+                vec![
+                    "id".to_string(),
+                    id.to_string(),
+                ].join(",")
+            }),
+
+
+            self.name.as_ref().map(|name| {
+                vec![
+                    "name".to_string(),
+                    name.to_string(),
+                ].join(",")
+            }),
+
+
+            self.artist.as_ref().map(|artist| {
+                vec![
+                    "artist".to_string(),
+                    artist.to_string(),
+                ].join(",")
+            }),
+
+
+            self.artist_id.as_ref().map(|artist_id| {
+                vec![
+                    "artist_id".to_string(),
+                    artist_id.to_string(),
+                ].join(",")
+            }),
+
+
+            self.year.as_ref().map(|year| {
+                vec![
+                    "year".to_string(),
+                    year.to_string(),
+                ].join(",")
+            }),
+
+
+            self.total_tracks.as_ref().map(|total_tracks| {
+                vec![
+                    "total_tracks".to_string(),
+                    total_tracks.to_string(),
+                ].join(",")
+            }),
+
+
+            self.total_discs.as_ref().map(|total_discs| {
+                vec![
+                    "total_discs".to_string(),
+                    total_discs.to_string(),
+                ].join(",")
+            }),
+
+
+            self.cover.as_ref().map(|cover| {
+                vec![
+                    "cover".to_string(),
+                    cover.to_string(),
+                ].join(",")
+            }),
+
+
+            // Skipping songs in query parameter serialization
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Album value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Album {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<i32>,
+            pub name: Vec<String>,
+            pub artist: Vec<String>,
+            pub artist_id: Vec<i32>,
+            pub year: Vec<i32>,
+            pub total_tracks: Vec<i32>,
+            pub total_discs: Vec<i32>,
+            pub cover: Vec<bool>,
+            pub songs: Vec<Vec<models::Song>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Album".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "artist" => intermediate_rep.artist.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "artist_id" => intermediate_rep.artist_id.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "year" => intermediate_rep.year.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "total_tracks" => intermediate_rep.total_tracks.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "total_discs" => intermediate_rep.total_discs.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "cover" => intermediate_rep.cover.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "songs" => return std::result::Result::Err("Parsing a container in this style is not supported in Album".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Album".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Album {
+            id: intermediate_rep.id.into_iter().next(),
+            name: intermediate_rep.name.into_iter().next(),
+            artist: intermediate_rep.artist.into_iter().next(),
+            artist_id: intermediate_rep.artist_id.into_iter().next(),
+            year: intermediate_rep.year.into_iter().next(),
+            total_tracks: intermediate_rep.total_tracks.into_iter().next(),
+            total_discs: intermediate_rep.total_discs.into_iter().next(),
+            cover: intermediate_rep.cover.into_iter().next(),
+            songs: intermediate_rep.songs.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Album> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Album>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Album>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Album - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Album> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Album as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Album - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Artist {
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<i32>,
+
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+
+    /// Year of the newest album of the artist
+    #[serde(rename = "year")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub year: Option<i32>,
+
+    /// Albums of the artist and albums it appears on, only returned by /artists/{id}
+    #[serde(rename = "albums")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub albums: Option<Vec<models::Album>>,
+
+}
+
+impl Artist {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Artist {
+        Artist {
+            id: None,
+            name: None,
+            year: None,
+            albums: None,
+        }
+    }
+}
+
+/// Converts the Artist value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Artist {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            self.id.as_ref().map(|id| {
+                vec![
+                    "id".to_string(),
+                    id.to_string(),
+                ].join(",")
+            }),
+
+
+            self.name.as_ref().map(|name| {
+                vec![
+                    "name".to_string(),
+                    name.to_string(),
+                ].join(",")
+            }),
+
+
+            self.year.as_ref().map(|year| {
+                vec![
+                    "year".to_string(),
+                    year.to_string(),
+                ].join(",")
+            }),
+
+
+            // Skipping albums in query parameter serialization
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Artist value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Artist {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<i32>,
+            pub name: Vec<String>,
+            pub year: Vec<i32>,
+            pub albums: Vec<Vec<models::Album>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Artist".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "year" => intermediate_rep.year.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "albums" => return std::result::Result::Err("Parsing a container in this style is not supported in Artist".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Artist".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Artist {
+            id: intermediate_rep.id.into_iter().next(),
+            name: intermediate_rep.name.into_iter().next(),
+            year: intermediate_rep.year.into_iter().next(),
+            albums: intermediate_rep.albums.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Artist> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Artist>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Artist>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Artist - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Artist> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Artist as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Artist - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct FacetCount {
+    /// Value of the facet, like a genre or the first year of a decade
+    #[serde(rename = "value")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub value: Option<String>,
+
+    /// Number of songs having this value
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub count: Option<i64>,
+
+}
+
+impl FacetCount {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> FacetCount {
+        FacetCount {
+            value: None,
+            count: None,
+        }
+    }
+}
+
+/// Converts the FacetCount value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for FacetCount {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            self.value.as_ref().map(|value| {
+                vec![
+                    "value".to_string(),
+                    value.to_string(),
+                ].join(",")
+            }),
+
+
+            self.count.as_ref().map(|count| {
+                vec![
+                    "count".to_string(),
+                    count.to_string(),
+                ].join(",")
+            }),
+
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a FacetCount value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for FacetCount {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub value: Vec<String>,
+            pub count: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing FacetCount".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "value" => intermediate_rep.value.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "count" => intermediate_rep.count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing FacetCount".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(FacetCount {
+            value: intermediate_rep.value.into_iter().next(),
+            count: intermediate_rep.count.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<FacetCount> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<FacetCount>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<FacetCount>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for FacetCount - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<FacetCount> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <FacetCount as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into FacetCount - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Facets {
+    /// Most frequent genres
+    #[serde(rename = "genres")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub genres: Option<Vec<models::FacetCount>>,
+
+    /// Most frequent decades, by their first year
+    #[serde(rename = "decades")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub decades: Option<Vec<models::FacetCount>>,
+
+    /// Most frequent artists
+    #[serde(rename = "artists")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub artists: Option<Vec<models::FacetCount>>,
+
+    /// Most frequent albums
+    #[serde(rename = "albums")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub albums: Option<Vec<models::FacetCount>>,
+
+}
+
+impl Facets {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Facets {
+        Facets {
+            genres: None,
+            decades: None,
+            artists: None,
+            albums: None,
+        }
+    }
+}
+
+/// Converts the Facets value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Facets {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            // Skipping genres in query parameter serialization
+
+            // Skipping decades in query parameter serialization
+
+            // Skipping artists in query parameter serialization
+
+            // Skipping albums in query parameter serialization
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Facets value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Facets {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub genres: Vec<Vec<models::FacetCount>>,
+            pub decades: Vec<Vec<models::FacetCount>>,
+            pub artists: Vec<Vec<models::FacetCount>>,
+            pub albums: Vec<Vec<models::FacetCount>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Facets".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "genres" => return std::result::Result::Err("Parsing a container in this style is not supported in Facets".to_string()),
+                    "decades" => return std::result::Result::Err("Parsing a container in this style is not supported in Facets".to_string()),
+                    "artists" => return std::result::Result::Err("Parsing a container in this style is not supported in Facets".to_string()),
+                    "albums" => return std::result::Result::Err("Parsing a container in this style is not supported in Facets".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Facets".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Facets {
+            genres: intermediate_rep.genres.into_iter().next(),
+            decades: intermediate_rep.decades.into_iter().next(),
+            artists: intermediate_rep.artists.into_iter().next(),
+            albums: intermediate_rep.albums.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Facets> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Facets>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Facets>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Facets - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Facets> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Facets as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Facets - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct IndexBucket {
+    /// First letter of the names, # for names not starting with a letter
+    #[serde(rename = "letter")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub letter: Option<String>,
+
+    /// Number of names starting with the letter
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub count: Option<i32>,
+
+    /// Position of the first of them in the list sorted by name
+    #[serde(rename = "offset")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub offset: Option<i32>,
+
+}
+
+impl IndexBucket {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> IndexBucket {
+        IndexBucket {
+            letter: None,
+            count: None,
+            offset: None,
+        }
+    }
+}
+
+/// Converts the IndexBucket value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for IndexBucket {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            self.letter.as_ref().map(|letter| {
+                vec![
+                    "letter".to_string(),
+                    letter.to_string(),
+                ].join(",")
+            }),
+
+
+            self.count.as_ref().map(|count| {
+                vec![
+                    "count".to_string(),
+                    count.to_string(),
+                ].join(",")
+            }),
+
+
+            self.offset.as_ref().map(|offset| {
+                vec![
+                    "offset".to_string(),
+                    offset.to_string(),
+                ].join(",")
+            }),
+
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a IndexBucket value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for IndexBucket {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub letter: Vec<String>,
+            pub count: Vec<i32>,
+            pub offset: Vec<i32>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing IndexBucket".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "letter" => intermediate_rep.letter.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "count" => intermediate_rep.count.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "offset" => intermediate_rep.offset.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing IndexBucket".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(IndexBucket {
+            letter: intermediate_rep.letter.into_iter().next(),
+            count: intermediate_rep.count.into_iter().next(),
+            offset: intermediate_rep.offset.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<IndexBucket> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<IndexBucket>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<IndexBucket>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for IndexBucket - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<IndexBucket> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <IndexBucket as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into IndexBucket - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct Informations {
     #[serde(rename = "version")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub version: Option<String>,
+    pub version: Option<String>,
+
+}
+
+impl Informations {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Informations {
+        Informations {
+            version: None,
+        }
+    }
+}
+
+/// Converts the Informations value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Informations {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            self.version.as_ref().map(|version| {
+                vec![
+                    "version".to_string(),
+                    version.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Informations value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Informations {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub version: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Informations".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Informations".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Informations {
+            version: intermediate_rep.version.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Informations> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Informations>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Informations>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Informations - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Informations> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Informations as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Informations - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NearDuplicates {
+    #[serde(rename = "artist")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub artist: Option<String>,
+
+    #[serde(rename = "title")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+
+    /// Songs of close durations
+    #[serde(rename = "songs")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub songs: Option<Vec<models::Song>>,
+
+}
+
+impl NearDuplicates {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> NearDuplicates {
+        NearDuplicates {
+            artist: None,
+            title: None,
+            songs: None,
+        }
+    }
+}
+
+/// Converts the NearDuplicates value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for NearDuplicates {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            self.artist.as_ref().map(|artist| {
+                vec![
+                    "artist".to_string(),
+                    artist.to_string(),
+                ].join(",")
+            }),
+
+
+            self.title.as_ref().map(|title| {
+                vec![
+                    "title".to_string(),
+                    title.to_string(),
+                ].join(",")
+            }),
+
+
+            // Skipping songs in query parameter serialization
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NearDuplicates value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NearDuplicates {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub artist: Vec<String>,
+            pub title: Vec<String>,
+            pub songs: Vec<Vec<models::Song>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing NearDuplicates".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "artist" => intermediate_rep.artist.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "songs" => return std::result::Result::Err("Parsing a container in this style is not supported in NearDuplicates".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing NearDuplicates".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NearDuplicates {
+            artist: intermediate_rep.artist.into_iter().next(),
+            title: intermediate_rep.title.into_iter().next(),
+            songs: intermediate_rep.songs.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NearDuplicates> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<NearDuplicates>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<NearDuplicates>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for NearDuplicates - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<NearDuplicates> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <NearDuplicates as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into NearDuplicates - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Playlist {
+    #[serde(rename = "id")]
+    #[serde(deserialize_with = "swagger::nullable_format::deserialize_optional_nullable")]
+    #[serde(default = "swagger::nullable_format::default_optional_nullable")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<swagger::Nullable<i32>>,
+
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+
+    /// Name of the user owning the playlist
+    #[serde(rename = "owner")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub owner: Option<String>,
+
+    /// Visible by other users
+    #[serde(rename = "shared")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub shared: Option<bool>,
+
+    /// Search query of a dynamic playlist, songs added explicitly are appended to its result
+    #[serde(rename = "query")]
+    #[serde(deserialize_with = "swagger::nullable_format::deserialize_optional_nullable")]
+    #[serde(default = "swagger::nullable_format::default_optional_nullable")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub query: Option<swagger::Nullable<String>>,
+
+    #[serde(rename = "songs")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub songs: Option<Vec<models::Song>>,
+
+}
+
+impl Playlist {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Playlist {
+        Playlist {
+            id: None,
+            name: None,
+            owner: None,
+            shared: None,
+            query: None,
+            songs: None,
+        }
+    }
+}
+
+/// Converts the Playlist value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Playlist {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            self.id.as_ref().map(|id| {
+                vec![
+                    "id".to_string(),
+                    id.as_ref().map_or("null".to_string(), |x| x.to_string()),
+                ].join(",")
+            }),
+
+
+            self.name.as_ref().map(|name| {
+                vec![
+                    "name".to_string(),
+                    name.to_string(),
+                ].join(",")
+            }),
+
+
+            self.owner.as_ref().map(|owner| {
+                vec![
+                    "owner".to_string(),
+                    owner.to_string(),
+                ].join(",")
+            }),
+
+
+            self.shared.as_ref().map(|shared| {
+                vec![
+                    "shared".to_string(),
+                    shared.to_string(),
+                ].join(",")
+            }),
+
+
+            self.query.as_ref().map(|query| {
+                vec![
+                    "query".to_string(),
+                    query.as_ref().map_or("null".to_string(), |x| x.to_string()),
+                ].join(",")
+            }),
+
+            // Skipping songs in query parameter serialization
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Playlist value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Playlist {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<i32>,
+            pub name: Vec<String>,
+            pub owner: Vec<String>,
+            pub shared: Vec<bool>,
+            pub query: Vec<String>,
+            pub songs: Vec<Vec<models::Song>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Playlist".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "id" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in Playlist".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "owner" => intermediate_rep.owner.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "shared" => intermediate_rep.shared.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "query" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in Playlist".to_string()),
+                    "songs" => return std::result::Result::Err("Parsing a container in this style is not supported in Playlist".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Playlist".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Playlist {
+            id: std::result::Result::Err("Nullable types not supported in Playlist".to_string())?,
+            name: intermediate_rep.name.into_iter().next(),
+            owner: intermediate_rep.owner.into_iter().next(),
+            shared: intermediate_rep.shared.into_iter().next(),
+            query: std::result::Result::Err("Nullable types not supported in Playlist".to_string())?,
+            songs: intermediate_rep.songs.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Playlist> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Playlist>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Playlist>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Playlist - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Playlist> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Playlist as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Playlist - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct QueryError {
+    /// Kind of error : syntax, unknown_field, invalid_value, unsupported, only_exclusions, unknown_sort, unknown_facet or invalid_page
+    #[serde(rename = "code")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub code: Option<String>,
+
+    /// Description of the error
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub message: Option<String>,
+
+}
+
+impl QueryError {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> QueryError {
+        QueryError {
+            code: None,
+            message: None,
+        }
+    }
+}
+
+/// Converts the QueryError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for QueryError {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            self.code.as_ref().map(|code| {
+                vec![
+                    "code".to_string(),
+                    code.to_string(),
+                ].join(",")
+            }),
+
+
+            self.message.as_ref().map(|message| {
+                vec![
+                    "message".to_string(),
+                    message.to_string(),
+                ].join(",")
+            }),
+
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a QueryError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for QueryError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub code: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing QueryError".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing QueryError".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(QueryError {
+            code: intermediate_rep.code.into_iter().next(),
+            message: intermediate_rep.message.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<QueryError> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<QueryError>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<QueryError>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for QueryError - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<QueryError> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <QueryError as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into QueryError - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ScanReport {
+    /// New songs
+    #[serde(rename = "added")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub added: Option<i32>,
+
+    /// Songs whose file was modified or moved
+    #[serde(rename = "updated")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated: Option<i32>,
+
+    /// Files that couldn't be read or saved
+    #[serde(rename = "failed")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub failed: Option<i32>,
+
+    /// Songs whose file no longer exists
+    #[serde(rename = "removed")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub removed: Option<i32>,
+
+}
+
+impl ScanReport {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> ScanReport {
+        ScanReport {
+            added: None,
+            updated: None,
+            failed: None,
+            removed: None,
+        }
+    }
+}
+
+/// Converts the ScanReport value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ScanReport {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            self.added.as_ref().map(|added| {
+                vec![
+                    "added".to_string(),
+                    added.to_string(),
+                ].join(",")
+            }),
+
+
+            self.updated.as_ref().map(|updated| {
+                vec![
+                    "updated".to_string(),
+                    updated.to_string(),
+                ].join(",")
+            }),
+
+
+            self.failed.as_ref().map(|failed| {
+                vec![
+                    "failed".to_string(),
+                    failed.to_string(),
+                ].join(",")
+            }),
+
+
+            self.removed.as_ref().map(|removed| {
+                vec![
+                    "removed".to_string(),
+                    removed.to_string(),
+                ].join(",")
+            }),
+
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ScanReport value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ScanReport {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub added: Vec<i32>,
+            pub updated: Vec<i32>,
+            pub failed: Vec<i32>,
+            pub removed: Vec<i32>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ScanReport".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "added" => intermediate_rep.added.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "updated" => intermediate_rep.updated.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "failed" => intermediate_rep.failed.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "removed" => intermediate_rep.removed.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ScanReport".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ScanReport {
+            added: intermediate_rep.added.into_iter().next(),
+            updated: intermediate_rep.updated.into_iter().next(),
+            failed: intermediate_rep.failed.into_iter().next(),
+            removed: intermediate_rep.removed.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ScanReport> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ScanReport>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ScanReport>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ScanReport - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ScanReport> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ScanReport as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ScanReport - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SearchResult {
+    /// Songs of the requested page
+    #[serde(rename = "songs")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub songs: Option<Vec<models::Song>>,
+
+    /// Facet counts of all the songs found, only when facets is true
+    #[serde(rename = "facets")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub facets: Option<models::Facets>,
+
+}
+
+impl SearchResult {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> SearchResult {
+        SearchResult {
+            songs: None,
+            facets: None,
+        }
+    }
+}
+
+/// Converts the SearchResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for SearchResult {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            // Skipping songs in query parameter serialization
+
+            self.facets.as_ref().map(|facets| {
+                vec![
+                    "facets".to_string(),
+                    facets.to_string(),
+                ].join(",")
+            }),
+
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a SearchResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for SearchResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub songs: Vec<Vec<models::Song>>,
+            pub facets: Vec<models::Facets>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing SearchResult".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "songs" => return std::result::Result::Err("Parsing a container in this style is not supported in SearchResult".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "facets" => intermediate_rep.facets.push(<models::Facets as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing SearchResult".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(SearchResult {
+            songs: intermediate_rep.songs.into_iter().next(),
+            facets: intermediate_rep.facets.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<SearchResult> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<SearchResult>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<SearchResult>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for SearchResult - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SearchResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <SearchResult as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into SearchResult - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Song {
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<i32>,
+
+    #[serde(rename = "title")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(rename = "album")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub album: Option<String>,
+
+    /// Id of the album, its cover is served by /albums/{id}/cover
+    #[serde(rename = "album_id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub album_id: Option<i32>,
+
+    /// Artist of the whole album
+    #[serde(rename = "album_artist")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub album_artist: Option<String>,
+
+    #[serde(rename = "track")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub track: Option<i32>,
+
+    /// Total number of tracks
+    #[serde(rename = "total_tracks")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub total_tracks: Option<i32>,
+
+    /// Disc number
+    #[serde(rename = "disc")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub disc: Option<i32>,
+
+    /// Number of discs of the album
+    #[serde(rename = "total_discs")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub total_discs: Option<i32>,
+
+    /// Artists of the song, as a single text
+    #[serde(rename = "artist")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub artist: Option<String>,
+
+    /// Artists of the song, the artist tag split on separators
+    #[serde(rename = "artists")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub artists: Option<Vec<String>>,
+
+    #[serde(rename = "composer")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub composer: Option<String>,
+
+    #[serde(rename = "genre")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub genre: Option<String>,
+
+    #[serde(rename = "year")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub year: Option<i32>,
+
+    #[serde(rename = "duration")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub duration: Option<i32>,
+
+    #[serde(rename = "comment")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub comment: Option<String>,
+
+    #[serde(rename = "lyrics")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub lyrics: Option<String>,
+
+    /// Name of the file when it was uploaded or found in the library
+    #[serde(rename = "original_name")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub original_name: Option<String>,
+
+    /// Relevance of the song when returned by a search
+    #[serde(rename = "score")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub score: Option<f32>,
+
+}
+
+impl Song {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Song {
+        Song {
+            id: None,
+            title: None,
+            album: None,
+            album_id: None,
+            album_artist: None,
+            track: None,
+            total_tracks: None,
+            disc: None,
+            total_discs: None,
+            artist: None,
+            artists: None,
+            composer: None,
+            genre: None,
+            year: None,
+            duration: None,
+            comment: None,
+            lyrics: None,
+            original_name: None,
+            score: None,
+        }
+    }
+}
+
+/// Converts the Song value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Song {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+
+            self.id.as_ref().map(|id| {
+                vec![
+                    "id".to_string(),
+                    id.to_string(),
+                ].join(",")
+            }),
+
+
+            self.title.as_ref().map(|title| {
+                vec![
+                    "title".to_string(),
+                    title.to_string(),
+                ].join(",")
+            }),
+
+
+            self.album.as_ref().map(|album| {
+                vec![
+                    "album".to_string(),
+                    album.to_string(),
+                ].join(",")
+            }),
+
+
+            self.album_id.as_ref().map(|album_id| {
+                vec![
+                    "album_id".to_string(),
+                    album_id.to_string(),
+                ].join(",")
+            }),
+
+
+            self.album_artist.as_ref().map(|album_artist| {
+                vec![
+                    "album_artist".to_string(),
+                    album_artist.to_string(),
+                ].join(",")
+            }),
+
+
+            self.track.as_ref().map(|track| {
+                vec![
+                    "track".to_string(),
+                    track.to_string(),
+                ].join(",")
+            }),
+
+
+            self.total_tracks.as_ref().map(|total_tracks| {
+                vec![
+                    "total_tracks".to_string(),
+                    total_tracks.to_string(),
+                ].join(",")
+            }),
+
+
+            self.disc.as_ref().map(|disc| {
+                vec![
+                    "disc".to_string(),
+                    disc.to_string(),
+                ].join(",")
+            }),
+
+
+            self.total_discs.as_ref().map(|total_discs| {
+                vec![
+                    "total_discs".to_string(),
+                    total_discs.to_string(),
+                ].join(",")
+            }),
+
+
+            self.artist.as_ref().map(|artist| {
+                vec![
+                    "artist".to_string(),
+                    artist.to_string(),
+                ].join(",")
+            }),
 
-}
 
-impl Informations {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Informations {
-        Informations {
-            version: None,
-        }
-    }
-}
+            self.artists.as_ref().map(|artists| {
+                vec![
+                    "artists".to_string(),
+                    artists.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+                ].join(",")
+            }),
 
-/// Converts the Informations value to the Query Parameters representation (style=form, explode=false)
-/// specified in https://swagger.io/docs/specification/serialization/
-/// Should be implemented in a serde serializer
-impl std::string::ToString for Informations {
-    fn to_string(&self) -> String {
-        let params: Vec<Option<String>> = vec![
+            self.composer.as_ref().map(|composer| {
+                vec![
+                    "composer".to_string(),
+                    composer.to_string(),
+                ].join(",")
+            }),
 
-            self.version.as_ref().map(|version| {
+
+            self.genre.as_ref().map(|genre| {
                 vec![
-                    "version".to_string(),
-                    version.to_string(),
+                    "genre".to_string(),
+                    genre.to_string(),
+                ].join(",")
+            }),
+
+
+            self.year.as_ref().map(|year| {
+                vec![
+                    "year".to_string(),
+                    year.to_string(),
+                ].join(",")
+            }),
+
+
+            self.duration.as_ref().map(|duration| {
+                vec![
+                    "duration".to_string(),
+                    duration.to_string(),
+                ].join(",")
+            }),
+
+
+            self.comment.as_ref().map(|comment| {
+                vec![
+                    "comment".to_string(),
+                    comment.to_string(),
+                ].join(",")
+            }),
+
+
+            self.lyrics.as_ref().map(|lyrics| {
+                vec![
+                    "lyrics".to_string(),
+                    lyrics.to_string(),
+                ].join(",")
+            }),
+
+
+            self.original_name.as_ref().map(|original_name| {
+                vec![
+                    "original_name".to_string(),
+                    original_name.to_string(),
                 ].join(",")
             }),
 
+
+            self.score.as_ref().map(|score| {
+                vec![
+                    "score".to_string(),
+                    score.to_string(),
+                ].join(",")
+            }),
+
+
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Informations value
+/// Converts Query Parameters representation (style=form, explode=false) to a Song value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Informations {
+impl std::str::FromStr for Song {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -53,7 +2118,25 @@ impl std::str::FromStr for Informations {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub version: Vec<String>,
+            pub id: Vec<i32>,
+            pub title: Vec<String>,
+            pub album: Vec<String>,
+            pub album_id: Vec<i32>,
+            pub album_artist: Vec<String>,
+            pub track: Vec<i32>,
+            pub total_tracks: Vec<i32>,
+            pub disc: Vec<i32>,
+            pub total_discs: Vec<i32>,
+            pub artist: Vec<String>,
+            pub artists: Vec<Vec<String>>,
+            pub composer: Vec<String>,
+            pub genre: Vec<String>,
+            pub year: Vec<i32>,
+            pub duration: Vec<i32>,
+            pub comment: Vec<String>,
+            pub lyrics: Vec<String>,
+            pub original_name: Vec<String>,
+            pub score: Vec<f32>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -65,15 +2148,50 @@ impl std::str::FromStr for Informations {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing Informations".to_string())
+                None => return std::result::Result::Err("Missing value while parsing Song".to_string())
             };
 
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "version" => intermediate_rep.version.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing Informations".to_string())
+                    "id" => intermediate_rep.id.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "album" => intermediate_rep.album.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "album_id" => intermediate_rep.album_id.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "album_artist" => intermediate_rep.album_artist.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "track" => intermediate_rep.track.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "total_tracks" => intermediate_rep.total_tracks.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "disc" => intermediate_rep.disc.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "total_discs" => intermediate_rep.total_discs.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "artist" => intermediate_rep.artist.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "artists" => return std::result::Result::Err("Parsing a container in this style is not supported in Song".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "composer" => intermediate_rep.composer.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "genre" => intermediate_rep.genre.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "year" => intermediate_rep.year.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "duration" => intermediate_rep.duration.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "comment" => intermediate_rep.comment.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "lyrics" => intermediate_rep.lyrics.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "original_name" => intermediate_rep.original_name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "score" => intermediate_rep.score.push(<f32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Song".to_string())
                 }
             }
 
@@ -82,40 +2200,58 @@ impl std::str::FromStr for Informations {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Informations {
-            version: intermediate_rep.version.into_iter().next(),
+        std::result::Result::Ok(Song {
+            id: intermediate_rep.id.into_iter().next(),
+            title: intermediate_rep.title.into_iter().next(),
+            album: intermediate_rep.album.into_iter().next(),
+            album_id: intermediate_rep.album_id.into_iter().next(),
+            album_artist: intermediate_rep.album_artist.into_iter().next(),
+            track: intermediate_rep.track.into_iter().next(),
+            total_tracks: intermediate_rep.total_tracks.into_iter().next(),
+            disc: intermediate_rep.disc.into_iter().next(),
+            total_discs: intermediate_rep.total_discs.into_iter().next(),
+            artist: intermediate_rep.artist.into_iter().next(),
+            artists: intermediate_rep.artists.into_iter().next(),
+            composer: intermediate_rep.composer.into_iter().next(),
+            genre: intermediate_rep.genre.into_iter().next(),
+            year: intermediate_rep.year.into_iter().next(),
+            duration: intermediate_rep.duration.into_iter().next(),
+            comment: intermediate_rep.comment.into_iter().next(),
+            lyrics: intermediate_rep.lyrics.into_iter().next(),
+            original_name: intermediate_rep.original_name.into_iter().next(),
+            score: intermediate_rep.score.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Informations> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<Song> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<Informations>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<Song>> for hyper::header::HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<Informations>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<Song>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for Informations - value: {} is invalid {}",
+                 format!("Invalid header value for Song - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Informations> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Song> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <Informations as std::str::FromStr>::from_str(value) {
+                    match <Song as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into Informations - {}",
+                            format!("Unable to convert header value '{}' into Song - {}",
                                 value, err))
                     }
              },
@@ -129,59 +2265,47 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Playlist {
-    #[serde(rename = "id")]
-    #[serde(deserialize_with = "swagger::nullable_format::deserialize_optional_nullable")]
-    #[serde(default = "swagger::nullable_format::default_optional_nullable")]
+pub struct Suggestions {
+    /// Artists starting with the words typed
+    #[serde(rename = "artists")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub id: Option<swagger::Nullable<i32>>,
+    pub artists: Option<Vec<String>>,
 
-    #[serde(rename = "query")]
-    #[serde(deserialize_with = "swagger::nullable_format::deserialize_optional_nullable")]
-    #[serde(default = "swagger::nullable_format::default_optional_nullable")]
+    /// Albums starting with the words typed
+    #[serde(rename = "albums")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub query: Option<swagger::Nullable<String>>,
+    pub albums: Option<Vec<String>>,
 
-    #[serde(rename = "songs")]
+    /// Titles starting with the words typed
+    #[serde(rename = "titles")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub songs: Option<Vec<models::Song>>,
+    pub titles: Option<Vec<String>>,
 
 }
 
-impl Playlist {
+impl Suggestions {
     #[allow(clippy::new_without_default)]
-    pub fn new() -> Playlist {
-        Playlist {
-            id: None,
-            query: None,
-            songs: None,
+    pub fn new() -> Suggestions {
+        Suggestions {
+            artists: None,
+            albums: None,
+            titles: None,
         }
     }
 }
 
-/// Converts the Playlist value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Suggestions value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for Playlist {
+impl std::string::ToString for Suggestions {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
 
-            self.id.as_ref().map(|id| {
-                vec![
-                    "id".to_string(),
-                    id.as_ref().map_or("null".to_string(), |x| x.to_string()),
-                ].join(",")
-            }),
-
+            // Skipping artists in query parameter serialization
 
-            self.query.as_ref().map(|query| {
-                vec![
-                    "query".to_string(),
-                    query.as_ref().map_or("null".to_string(), |x| x.to_string()),
-                ].join(",")
-            }),
+            // Skipping albums in query parameter serialization
 
-            // Skipping songs in query parameter serialization
+            // Skipping titles in query parameter serialization
 
         ];
 
@@ -189,10 +2313,10 @@ impl std::string::ToString for Playlist {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Playlist value
+/// Converts Query Parameters representation (style=form, explode=false) to a Suggestions value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Playlist {
+impl std::str::FromStr for Suggestions {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -200,9 +2324,9 @@ impl std::str::FromStr for Playlist {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub id: Vec<i32>,
-            pub query: Vec<String>,
-            pub songs: Vec<Vec<models::Song>>,
+            pub artists: Vec<Vec<String>>,
+            pub albums: Vec<Vec<String>>,
+            pub titles: Vec<Vec<String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -214,16 +2338,16 @@ impl std::str::FromStr for Playlist {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing Playlist".to_string())
+                None => return std::result::Result::Err("Missing value while parsing Suggestions".to_string())
             };
 
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "id" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in Playlist".to_string()),
-                    "query" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in Playlist".to_string()),
-                    "songs" => return std::result::Result::Err("Parsing a container in this style is not supported in Playlist".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing Playlist".to_string())
+                    "artists" => return std::result::Result::Err("Parsing a container in this style is not supported in Suggestions".to_string()),
+                    "albums" => return std::result::Result::Err("Parsing a container in this style is not supported in Suggestions".to_string()),
+                    "titles" => return std::result::Result::Err("Parsing a container in this style is not supported in Suggestions".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Suggestions".to_string())
                 }
             }
 
@@ -232,42 +2356,42 @@ impl std::str::FromStr for Playlist {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Playlist {
-            id: std::result::Result::Err("Nullable types not supported in Playlist".to_string())?,
-            query: std::result::Result::Err("Nullable types not supported in Playlist".to_string())?,
-            songs: intermediate_rep.songs.into_iter().next(),
+        std::result::Result::Ok(Suggestions {
+            artists: intermediate_rep.artists.into_iter().next(),
+            albums: intermediate_rep.albums.into_iter().next(),
+            titles: intermediate_rep.titles.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Playlist> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<Suggestions> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<Playlist>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<Suggestions>> for hyper::header::HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<Playlist>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<Suggestions>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for Playlist - value: {} is invalid {}",
+                 format!("Invalid header value for Suggestions - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Playlist> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Suggestions> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <Playlist as std::str::FromStr>::from_str(value) {
+                    match <Suggestions as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into Playlist - {}",
+                            format!("Unable to convert header value '{}' into Suggestions - {}",
                                 value, err))
                     }
              },
@@ -281,51 +2405,42 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Song {
+pub struct User {
     #[serde(rename = "id")]
     #[serde(skip_serializing_if="Option::is_none")]
     pub id: Option<i32>,
 
-    #[serde(rename = "title")]
-    #[serde(skip_serializing_if="Option::is_none")]
-    pub title: Option<String>,
-
-    #[serde(rename = "album")]
-    #[serde(skip_serializing_if="Option::is_none")]
-    pub album: Option<String>,
-
-    #[serde(rename = "track")]
+    #[serde(rename = "name")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub track: Option<i32>,
+    pub name: Option<String>,
 
-    #[serde(rename = "artist")]
+    /// Password, only used when creating or updating a user
+    #[serde(rename = "password")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub artist: Option<String>,
+    pub password: Option<String>,
 
-    #[serde(rename = "duration")]
+    #[serde(rename = "admin")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub duration: Option<i32>,
+    pub admin: Option<bool>,
 
 }
 
-impl Song {
+impl User {
     #[allow(clippy::new_without_default)]
-    pub fn new() -> Song {
-        Song {
+    pub fn new() -> User {
+        User {
             id: None,
-            title: None,
-            album: None,
-            track: None,
-            artist: None,
-            duration: None,
+            name: None,
+            password: None,
+            admin: None,
         }
     }
 }
 
-/// Converts the Song value to the Query Parameters representation (style=form, explode=false)
+/// Converts the User value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for Song {
+impl std::string::ToString for User {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
 
@@ -337,55 +2452,40 @@ impl std::string::ToString for Song {
             }),
 
 
-            self.title.as_ref().map(|title| {
-                vec![
-                    "title".to_string(),
-                    title.to_string(),
-                ].join(",")
-            }),
-
-
-            self.album.as_ref().map(|album| {
+            self.name.as_ref().map(|name| {
                 vec![
-                    "album".to_string(),
-                    album.to_string(),
+                    "name".to_string(),
+                    name.to_string(),
                 ].join(",")
             }),
 
 
-            self.track.as_ref().map(|track| {
+            self.password.as_ref().map(|password| {
                 vec![
-                    "track".to_string(),
-                    track.to_string(),
+                    "password".to_string(),
+                    password.to_string(),
                 ].join(",")
             }),
 
 
-            self.artist.as_ref().map(|artist| {
+            self.admin.as_ref().map(|admin| {
                 vec![
-                    "artist".to_string(),
-                    artist.to_string(),
+                    "admin".to_string(),
+                    admin.to_string(),
                 ].join(",")
             }),
 
 
-            self.duration.as_ref().map(|duration| {
-                vec![
-                    "duration".to_string(),
-                    duration.to_string(),
-                ].join(",")
-            }),
-
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Song value
+/// Converts Query Parameters representation (style=form, explode=false) to a User value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Song {
+impl std::str::FromStr for User {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -394,11 +2494,9 @@ impl std::str::FromStr for Song {
         #[allow(dead_code)]
         struct IntermediateRep {
             pub id: Vec<i32>,
-            pub title: Vec<String>,
-            pub album: Vec<String>,
-            pub track: Vec<i32>,
-            pub artist: Vec<String>,
-            pub duration: Vec<i32>,
+            pub name: Vec<String>,
+            pub password: Vec<String>,
+            pub admin: Vec<bool>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -410,7 +2508,7 @@ impl std::str::FromStr for Song {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing Song".to_string())
+                None => return std::result::Result::Err("Missing value while parsing User".to_string())
             };
 
             if let Some(key) = key_result {
@@ -419,16 +2517,12 @@ impl std::str::FromStr for Song {
                     #[allow(clippy::redundant_clone)]
                     "id" => intermediate_rep.id.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "title" => intermediate_rep.title.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "album" => intermediate_rep.album.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "track" => intermediate_rep.track.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "artist" => intermediate_rep.artist.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "password" => intermediate_rep.password.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "duration" => intermediate_rep.duration.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing Song".to_string())
+                    "admin" => intermediate_rep.admin.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing User".to_string())
                 }
             }
 
@@ -437,45 +2531,43 @@ impl std::str::FromStr for Song {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Song {
+        std::result::Result::Ok(User {
             id: intermediate_rep.id.into_iter().next(),
-            title: intermediate_rep.title.into_iter().next(),
-            album: intermediate_rep.album.into_iter().next(),
-            track: intermediate_rep.track.into_iter().next(),
-            artist: intermediate_rep.artist.into_iter().next(),
-            duration: intermediate_rep.duration.into_iter().next(),
+            name: intermediate_rep.name.into_iter().next(),
+            password: intermediate_rep.password.into_iter().next(),
+            admin: intermediate_rep.admin.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Song> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<User> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<Song>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<User>> for hyper::header::HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<Song>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<User>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for Song - value: {} is invalid {}",
+                 format!("Invalid header value for User - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Song> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<User> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <Song as std::str::FromStr>::from_str(value) {
+                    match <User as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into Song - {}",
+                            format!("Unable to convert header value '{}' into User - {}",
                                 value, err))
                     }
              },
@@ -22,16 +22,29 @@ pub use crate::context;
 type ServiceFuture = BoxFuture<'static, Result<Response<Body>, crate::ServiceError>>;
 
 use crate::{Api,
+     AlbumsGetResponse,
+     AlbumsIdGetResponse,
+     AlbumsIndexGetResponse,
+     ArtistsGetResponse,
+     ArtistsIdGetResponse,
+     ArtistsIndexGetResponse,
+     LibraryScanPostResponse,
      PlaylistsGetResponse,
      PlaylistsIdDeleteResponse,
      PlaylistsIdGetResponse,
      PlaylistsPostResponse,
      RootGetResponse,
      SearchGetResponse,
+     SongsDuplicatesGetResponse,
      SongsIdDeleteResponse,
      SongsIdGetResponse,
      SongsIdPutResponse,
-     SongsPostResponse
+     SongsPostResponse,
+     SuggestGetResponse,
+     UsersGetResponse,
+     UsersIdDeleteResponse,
+     UsersIdPutResponse,
+     UsersPostResponse
 };
 
 mod paths {
@@ -40,32 +53,72 @@ mod paths {
     lazy_static! {
         pub static ref GLOBAL_REGEX_SET: regex::RegexSet = regex::RegexSet::new(vec![
             r"^/api/v1/$",
+            r"^/api/v1/albums$",
+            r"^/api/v1/albums/index$",
+            r"^/api/v1/albums/(?P<id>[^/?#]*)$",
+            r"^/api/v1/artists$",
+            r"^/api/v1/artists/index$",
+            r"^/api/v1/artists/(?P<id>[^/?#]*)$",
+            r"^/api/v1/library/scan$",
             r"^/api/v1/playlists$",
             r"^/api/v1/playlists/(?P<id>[^/?#]*)$",
             r"^/api/v1/search$",
             r"^/api/v1/songs$",
-            r"^/api/v1/songs/(?P<id>[^/?#]*)$"
+            r"^/api/v1/songs/duplicates$",
+            r"^/api/v1/songs/(?P<id>[^/?#]*)$",
+            r"^/api/v1/suggest$",
+            r"^/api/v1/users$",
+            r"^/api/v1/users/(?P<id>[^/?#]*)$"
         ])
         .expect("Unable to create global regex set");
     }
     pub(crate) static ID_: usize = 0;
-    pub(crate) static ID_PLAYLISTS: usize = 1;
-    pub(crate) static ID_PLAYLISTS_ID: usize = 2;
+    pub(crate) static ID_ALBUMS: usize = 1;
+    pub(crate) static ID_ALBUMS_INDEX: usize = 2;
+    pub(crate) static ID_ALBUMS_ID: usize = 3;
+    lazy_static! {
+        pub static ref REGEX_ALBUMS_ID: regex::Regex =
+            #[allow(clippy::invalid_regex)]
+            regex::Regex::new(r"^/api/v1/albums/(?P<id>[^/?#]*)$")
+                .expect("Unable to create regex for ALBUMS_ID");
+    }
+    pub(crate) static ID_ARTISTS: usize = 4;
+    pub(crate) static ID_ARTISTS_INDEX: usize = 5;
+    pub(crate) static ID_ARTISTS_ID: usize = 6;
+    lazy_static! {
+        pub static ref REGEX_ARTISTS_ID: regex::Regex =
+            #[allow(clippy::invalid_regex)]
+            regex::Regex::new(r"^/api/v1/artists/(?P<id>[^/?#]*)$")
+                .expect("Unable to create regex for ARTISTS_ID");
+    }
+    pub(crate) static ID_LIBRARY_SCAN: usize = 7;
+    pub(crate) static ID_PLAYLISTS: usize = 8;
+    pub(crate) static ID_PLAYLISTS_ID: usize = 9;
     lazy_static! {
         pub static ref REGEX_PLAYLISTS_ID: regex::Regex =
             #[allow(clippy::invalid_regex)]
             regex::Regex::new(r"^/api/v1/playlists/(?P<id>[^/?#]*)$")
                 .expect("Unable to create regex for PLAYLISTS_ID");
     }
-    pub(crate) static ID_SEARCH: usize = 3;
-    pub(crate) static ID_SONGS: usize = 4;
-    pub(crate) static ID_SONGS_ID: usize = 5;
+    pub(crate) static ID_SEARCH: usize = 10;
+    pub(crate) static ID_SONGS: usize = 11;
+    pub(crate) static ID_SONGS_DUPLICATES: usize = 12;
+    pub(crate) static ID_SONGS_ID: usize = 13;
     lazy_static! {
         pub static ref REGEX_SONGS_ID: regex::Regex =
             #[allow(clippy::invalid_regex)]
             regex::Regex::new(r"^/api/v1/songs/(?P<id>[^/?#]*)$")
                 .expect("Unable to create regex for SONGS_ID");
     }
+    pub(crate) static ID_SUGGEST: usize = 14;
+    pub(crate) static ID_USERS: usize = 15;
+    pub(crate) static ID_USERS_ID: usize = 16;
+    lazy_static! {
+        pub static ref REGEX_USERS_ID: regex::Regex =
+            #[allow(clippy::invalid_regex)]
+            regex::Regex::new(r"^/api/v1/users/(?P<id>[^/?#]*)$")
+                .expect("Unable to create regex for USERS_ID");
+    }
 }
 
 pub struct MakeService<T, C> where
@@ -170,9 +223,66 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
         match method {
 
-            // PlaylistsGet - GET /playlists
-            hyper::Method::GET if path.matched(paths::ID_PLAYLISTS) => {
-                                let result = api_impl.playlists_get(
+            // AlbumsGet - GET /albums
+            hyper::Method::GET if path.matched(paths::ID_ALBUMS) => {
+                // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
+                let param_limit = query_params.iter().filter(|e| e.0 == "limit").map(|e| e.1.clone())
+                    .next();
+                let param_limit = match param_limit {
+                    Some(param_limit) => {
+                        let param_limit =
+                            <i32 as std::str::FromStr>::from_str
+                                (&param_limit);
+                        match param_limit {
+                            Ok(param_limit) => Some(param_limit),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter limit - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter limit")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_offset = query_params.iter().filter(|e| e.0 == "offset").map(|e| e.1.clone())
+                    .next();
+                let param_offset = match param_offset {
+                    Some(param_offset) => {
+                        let param_offset =
+                            <i32 as std::str::FromStr>::from_str
+                                (&param_offset);
+                        match param_offset {
+                            Ok(param_offset) => Some(param_offset),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter offset - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter offset")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_sort = query_params.iter().filter(|e| e.0 == "sort").map(|e| e.1.clone())
+                    .next();
+                let param_sort = match param_sort {
+                    Some(param_sort) => {
+                        let param_sort =
+                            <String as std::str::FromStr>::from_str
+                                (&param_sort);
+                        match param_sort {
+                            Ok(param_sort) => Some(param_sort),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter sort - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter sort")),
+                        }
+                    },
+                    None => None,
+                };
+
+                                let result = api_impl.albums_get(
+                                            param_limit,
+                                            param_offset,
+                                            param_sort,
                                         &context
                                     ).await;
                                 let mut response = Response::new(Body::empty());
@@ -183,21 +293,84 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                PlaylistsGetResponse::ListAllPlayslists
+                                                AlbumsGetResponse::ListOfAlbums
+                                                    {
+                                                        body,
+                                                        x_total_count
+                                                    }
                                                 => {
+                                                    if let Some(x_total_count) = x_total_count {
+                                                    let x_total_count = match header::IntoHeaderValue(x_total_count).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling x_total_count header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("x-total-count"),
+                                                        x_total_count
+                                                    );
+                                                    }
                                                     *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ALBUMS_GET_LIST_OF_ALBUMS"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
                                                 },
-                                                PlaylistsGetResponse::UnexpectedError
-                                                    (body)
+                                                AlbumsGetResponse::WrongData
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                },
+                                                AlbumsGetResponse::UnexpectedError
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // AlbumsIndexGet - GET /albums/index
+            hyper::Method::GET if path.matched(paths::ID_ALBUMS_INDEX) => {
+                                let result = api_impl.albums_index_get(
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                AlbumsIndexGetResponse::AlphabeticalIndex
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for PLAYLISTS_GET_UNEXPECTED_ERROR"));
+                                                            .expect("Unable to create Content-Type header for ALBUMS_INDEX_GET_ALPHABETICAL_INDEX"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
+                                                AlbumsIndexGetResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
                                             },
                                             Err(_) => {
                                                 // Application code returned an error. This should not happen, as the implementation should
@@ -210,15 +383,15 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         Ok(response)
             },
 
-            // PlaylistsIdDelete - DELETE /playlists/{id}
-            hyper::Method::DELETE if path.matched(paths::ID_PLAYLISTS_ID) => {
+            // AlbumsIdGet - GET /albums/{id}
+            hyper::Method::GET if path.matched(paths::ID_ALBUMS_ID) => {
                 // Path parameters
                 let path: &str = uri.path();
                 let path_params =
-                    paths::REGEX_PLAYLISTS_ID
+                    paths::REGEX_ALBUMS_ID
                     .captures(path)
                     .unwrap_or_else(||
-                        panic!("Path {} matched RE PLAYLISTS_ID in set but failed match against \"{}\"", path, paths::REGEX_PLAYLISTS_ID.as_str())
+                        panic!("Path {} matched RE ALBUMS_ID in set but failed match against \"{}\"", path, paths::REGEX_ALBUMS_ID.as_str())
                     );
 
                 let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
@@ -235,7 +408,7 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         .expect("Unable to create Bad Request response for invalid percent decode"))
                 };
 
-                                let result = api_impl.playlists_id_delete(
+                                let result = api_impl.albums_id_get(
                                             param_id,
                                         &context
                                     ).await;
@@ -247,15 +420,22 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                PlaylistsIdDeleteResponse::PlaylistDeleted
+                                                AlbumsIdGetResponse::Album
+                                                    (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ALBUMS_ID_GET_ALBUM"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
                                                 },
-                                                PlaylistsIdDeleteResponse::UnknownPlaylist
+                                                AlbumsIdGetResponse::UnknownAlbum
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
                                                 },
-                                                PlaylistsIdDeleteResponse::UnexpectedError
+                                                AlbumsIdGetResponse::UnexpectedError
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                 },
@@ -271,15 +451,175 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         Ok(response)
             },
 
-            // PlaylistsIdGet - GET /playlists/{id}
-            hyper::Method::GET if path.matched(paths::ID_PLAYLISTS_ID) => {
+            // ArtistsGet - GET /artists
+            hyper::Method::GET if path.matched(paths::ID_ARTISTS) => {
+                // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
+                let param_limit = query_params.iter().filter(|e| e.0 == "limit").map(|e| e.1.clone())
+                    .next();
+                let param_limit = match param_limit {
+                    Some(param_limit) => {
+                        let param_limit =
+                            <i32 as std::str::FromStr>::from_str
+                                (&param_limit);
+                        match param_limit {
+                            Ok(param_limit) => Some(param_limit),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter limit - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter limit")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_offset = query_params.iter().filter(|e| e.0 == "offset").map(|e| e.1.clone())
+                    .next();
+                let param_offset = match param_offset {
+                    Some(param_offset) => {
+                        let param_offset =
+                            <i32 as std::str::FromStr>::from_str
+                                (&param_offset);
+                        match param_offset {
+                            Ok(param_offset) => Some(param_offset),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter offset - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter offset")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_sort = query_params.iter().filter(|e| e.0 == "sort").map(|e| e.1.clone())
+                    .next();
+                let param_sort = match param_sort {
+                    Some(param_sort) => {
+                        let param_sort =
+                            <String as std::str::FromStr>::from_str
+                                (&param_sort);
+                        match param_sort {
+                            Ok(param_sort) => Some(param_sort),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter sort - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter sort")),
+                        }
+                    },
+                    None => None,
+                };
+
+                                let result = api_impl.artists_get(
+                                            param_limit,
+                                            param_offset,
+                                            param_sort,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                ArtistsGetResponse::ListOfArtists
+                                                    {
+                                                        body,
+                                                        x_total_count
+                                                    }
+                                                => {
+                                                    if let Some(x_total_count) = x_total_count {
+                                                    let x_total_count = match header::IntoHeaderValue(x_total_count).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling x_total_count header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("x-total-count"),
+                                                        x_total_count
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ARTISTS_GET_LIST_OF_ARTISTS"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ArtistsGetResponse::WrongData
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                },
+                                                ArtistsGetResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // ArtistsIndexGet - GET /artists/index
+            hyper::Method::GET if path.matched(paths::ID_ARTISTS_INDEX) => {
+                                let result = api_impl.artists_index_get(
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                ArtistsIndexGetResponse::AlphabeticalIndex
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ARTISTS_INDEX_GET_ALPHABETICAL_INDEX"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                ArtistsIndexGetResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // ArtistsIdGet - GET /artists/{id}
+            hyper::Method::GET if path.matched(paths::ID_ARTISTS_ID) => {
                 // Path parameters
                 let path: &str = uri.path();
                 let path_params =
-                    paths::REGEX_PLAYLISTS_ID
+                    paths::REGEX_ARTISTS_ID
                     .captures(path)
                     .unwrap_or_else(||
-                        panic!("Path {} matched RE PLAYLISTS_ID in set but failed match against \"{}\"", path, paths::REGEX_PLAYLISTS_ID.as_str())
+                        panic!("Path {} matched RE ARTISTS_ID in set but failed match against \"{}\"", path, paths::REGEX_ARTISTS_ID.as_str())
                     );
 
                 let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
@@ -296,7 +636,7 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         .expect("Unable to create Bad Request response for invalid percent decode"))
                 };
 
-                                let result = api_impl.playlists_id_get(
+                                let result = api_impl.artists_id_get(
                                             param_id,
                                         &context
                                     ).await;
@@ -308,22 +648,22 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                PlaylistsIdGetResponse::Playlist
+                                                ArtistsIdGetResponse::Artist
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for PLAYLISTS_ID_GET_PLAYLIST"));
+                                                            .expect("Unable to create Content-Type header for ARTISTS_ID_GET_ARTIST"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                PlaylistsIdGetResponse::UnknownPlaylist
+                                                ArtistsIdGetResponse::UnknownArtist
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
                                                 },
-                                                PlaylistsIdGetResponse::UnexpectedError
+                                                ArtistsIdGetResponse::UnexpectedError
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                 },
@@ -339,20 +679,266 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         Ok(response)
             },
 
-            // PlaylistsPost - POST /playlists
-            hyper::Method::POST if path.matched(paths::ID_PLAYLISTS) => {
-                // Body parameters (note that non-required body parameters will ignore garbage
-                // values, rather than causing a 400 response). Produce warning header and logs for
-                // any unused fields.
-                let result = body.into_raw().await;
-                match result {
-                            Ok(body) => {
-                                let mut unused_elements = Vec::new();
-                                let param_playlist: Option<models::Playlist> = if !body.is_empty() {
-                                    let deserializer = &mut serde_json::Deserializer::from_slice(&body);
-                                    match serde_ignored::deserialize(deserializer, |path| {
-                                            warn!("Ignoring unknown field in body: {}", path);
-                                            unused_elements.push(path.to_string());
+            // LibraryScanPost - POST /library/scan
+            hyper::Method::POST if path.matched(paths::ID_LIBRARY_SCAN) => {
+                // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
+                let param_path = query_params.iter().filter(|e| e.0 == "path").map(|e| e.1.clone())
+                    .next();
+                let param_path = match param_path {
+                    Some(param_path) => {
+                        let param_path =
+                            <String as std::str::FromStr>::from_str
+                                (&param_path);
+                        match param_path {
+                            Ok(param_path) => Some(param_path),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter path - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter path")),
+                        }
+                    },
+                    None => None,
+                };
+
+                                let result = api_impl.library_scan_post(
+                                            param_path,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                LibraryScanPostResponse::ScanDone
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIBRARY_SCAN_POST_SCAN_DONE"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                LibraryScanPostResponse::WrongData
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                },
+                                                LibraryScanPostResponse::Forbidden
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
+                                                },
+                                                LibraryScanPostResponse::ScanAlreadyRunning
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(409).expect("Unable to turn 409 into a StatusCode");
+                                                },
+                                                LibraryScanPostResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // PlaylistsGet - GET /playlists
+            hyper::Method::GET if path.matched(paths::ID_PLAYLISTS) => {
+                                let result = api_impl.playlists_get(
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                PlaylistsGetResponse::ListAllPlaylists
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for PLAYLISTS_GET_LIST_ALL_PLAYLISTS"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                PlaylistsGetResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // PlaylistsIdDelete - DELETE /playlists/{id}
+            hyper::Method::DELETE if path.matched(paths::ID_PLAYLISTS_ID) => {
+                // Path parameters
+                let path: &str = uri.path();
+                let path_params =
+                    paths::REGEX_PLAYLISTS_ID
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE PLAYLISTS_ID in set but failed match against \"{}\"", path, paths::REGEX_PLAYLISTS_ID.as_str())
+                    );
+
+                let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                    Ok(param_id) => match param_id.parse::<i32>() {
+                        Ok(param_id) => param_id,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                                let result = api_impl.playlists_id_delete(
+                                            param_id,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                PlaylistsIdDeleteResponse::PlaylistDeleted
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                },
+                                                PlaylistsIdDeleteResponse::PlaylistSharedByAnotherUser
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
+                                                },
+                                                PlaylistsIdDeleteResponse::UnknownPlaylist
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                },
+                                                PlaylistsIdDeleteResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // PlaylistsIdGet - GET /playlists/{id}
+            hyper::Method::GET if path.matched(paths::ID_PLAYLISTS_ID) => {
+                // Path parameters
+                let path: &str = uri.path();
+                let path_params =
+                    paths::REGEX_PLAYLISTS_ID
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE PLAYLISTS_ID in set but failed match against \"{}\"", path, paths::REGEX_PLAYLISTS_ID.as_str())
+                    );
+
+                let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                    Ok(param_id) => match param_id.parse::<i32>() {
+                        Ok(param_id) => param_id,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                                let result = api_impl.playlists_id_get(
+                                            param_id,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                PlaylistsIdGetResponse::Playlist
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for PLAYLISTS_ID_GET_PLAYLIST"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                PlaylistsIdGetResponse::UnknownPlaylist
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                },
+                                                PlaylistsIdGetResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // PlaylistsPost - POST /playlists
+            hyper::Method::POST if path.matched(paths::ID_PLAYLISTS) => {
+                // Body parameters (note that non-required body parameters will ignore garbage
+                // values, rather than causing a 400 response). Produce warning header and logs for
+                // any unused fields.
+                let result = body.into_raw().await;
+                match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_playlist: Option<models::Playlist> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
                                     }) {
                                         Ok(param_playlist) => param_playlist,
                                         Err(e) => return Ok(Response::builder()
@@ -391,10 +977,542 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         match result {
                                             Ok(rsp) => match rsp {
                                                 PlaylistsPostResponse::PlaylistSuccessfullyCreated
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(201).expect("Unable to turn 201 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for PLAYLISTS_POST_PLAYLIST_SUCCESSFULLY_CREATED"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                PlaylistsPostResponse::WrongData
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                },
+                                                PlaylistsPostResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+                            },
+                            Err(e) => Ok(Response::builder()
+                                                .status(StatusCode::BAD_REQUEST)
+                                                .body(Body::from(format!("Couldn't read body parameter Playlist: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter Playlist")),
+                        }
+            },
+
+            // RootGet - GET /
+            hyper::Method::GET if path.matched(paths::ID_) => {
+                                let result = api_impl.root_get(
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                RootGetResponse::Ok
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for ROOT_GET_OK"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // SearchGet - GET /search
+            hyper::Method::GET if path.matched(paths::ID_SEARCH) => {
+                // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
+                let param_q = query_params.iter().filter(|e| e.0 == "q").map(|e| e.1.clone())
+                    .next();
+                let param_q = match param_q {
+                    Some(param_q) => {
+                        let param_q =
+                            <String as std::str::FromStr>::from_str
+                                (&param_q);
+                        match param_q {
+                            Ok(param_q) => Some(param_q),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter q - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter q")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_q = match param_q {
+                    Some(param_q) => param_q,
+                    None => return Ok(Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from("Missing required query parameter q"))
+                        .expect("Unable to create Bad Request response for missing query parameter q")),
+                };
+                let param_limit = query_params.iter().filter(|e| e.0 == "limit").map(|e| e.1.clone())
+                    .next();
+                let param_limit = match param_limit {
+                    Some(param_limit) => {
+                        let param_limit =
+                            <i32 as std::str::FromStr>::from_str
+                                (&param_limit);
+                        match param_limit {
+                            Ok(param_limit) => Some(param_limit),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter limit - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter limit")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_offset = query_params.iter().filter(|e| e.0 == "offset").map(|e| e.1.clone())
+                    .next();
+                let param_offset = match param_offset {
+                    Some(param_offset) => {
+                        let param_offset =
+                            <i32 as std::str::FromStr>::from_str
+                                (&param_offset);
+                        match param_offset {
+                            Ok(param_offset) => Some(param_offset),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter offset - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter offset")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_sort = query_params.iter().filter(|e| e.0 == "sort").map(|e| e.1.clone())
+                    .next();
+                let param_sort = match param_sort {
+                    Some(param_sort) => {
+                        let param_sort =
+                            <String as std::str::FromStr>::from_str
+                                (&param_sort);
+                        match param_sort {
+                            Ok(param_sort) => Some(param_sort),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter sort - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter sort")),
+                        }
+                    },
+                    None => None,
+                };
+                let param_facet = query_params.iter().filter(|e| e.0 == "facet").map(|e| e.1.clone())
+                    .filter_map(|param_facet| param_facet.parse().ok())
+                    .collect::<Vec<_>>();
+                let param_facet = if !param_facet.is_empty() {
+                    Some(param_facet)
+                } else {
+                    None
+                };
+                let param_facets = query_params.iter().filter(|e| e.0 == "facets").map(|e| e.1.clone())
+                    .next();
+                let param_facets = match param_facets {
+                    Some(param_facets) => {
+                        let param_facets =
+                            <bool as std::str::FromStr>::from_str
+                                (&param_facets);
+                        match param_facets {
+                            Ok(param_facets) => Some(param_facets),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter facets - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter facets")),
+                        }
+                    },
+                    None => None,
+                };
+
+                                let result = api_impl.search_get(
+                                            param_q,
+                                            param_limit,
+                                            param_offset,
+                                            param_sort,
+                                            param_facet.as_ref(),
+                                            param_facets,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                SearchGetResponse::SongsMatchingQuery
+                                                    {
+                                                        body,
+                                                        x_total_count
+                                                    }
+                                                => {
+                                                    if let Some(x_total_count) = x_total_count {
+                                                    let x_total_count = match header::IntoHeaderValue(x_total_count).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Ok(Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling x_total_count header - {}", e)))
+                                                                    .expect("Unable to create Internal Server Error for invalid response header"))
+                                                        }
+                                                    };
+
+                                                    response.headers_mut().insert(
+                                                        HeaderName::from_static("x-total-count"),
+                                                        x_total_count
+                                                    );
+                                                    }
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for SEARCH_GET_SONGS_MATCHING_QUERY"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                SearchGetResponse::InvalidQuery
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for SEARCH_GET_INVALID_QUERY"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                SearchGetResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // SongsDuplicatesGet - GET /songs/duplicates
+            hyper::Method::GET if path.matched(paths::ID_SONGS_DUPLICATES) => {
+                                let result = api_impl.songs_duplicates_get(
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                SongsDuplicatesGetResponse::NearDuplicates
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for SONGS_DUPLICATES_GET_NEAR_DUPLICATES"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                SongsDuplicatesGetResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // SongsIdDelete - DELETE /songs/{id}
+            hyper::Method::DELETE if path.matched(paths::ID_SONGS_ID) => {
+                // Path parameters
+                let path: &str = uri.path();
+                let path_params =
+                    paths::REGEX_SONGS_ID
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE SONGS_ID in set but failed match against \"{}\"", path, paths::REGEX_SONGS_ID.as_str())
+                    );
+
+                let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                    Ok(param_id) => match param_id.parse::<i32>() {
+                        Ok(param_id) => param_id,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                                let result = api_impl.songs_id_delete(
+                                            param_id,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                SongsIdDeleteResponse::SongDeleted
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                },
+                                                SongsIdDeleteResponse::UnknownSong
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                },
+                                                SongsIdDeleteResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // SongsIdGet - GET /songs/{id}
+            hyper::Method::GET if path.matched(paths::ID_SONGS_ID) => {
+                // Path parameters
+                let path: &str = uri.path();
+                let path_params =
+                    paths::REGEX_SONGS_ID
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE SONGS_ID in set but failed match against \"{}\"", path, paths::REGEX_SONGS_ID.as_str())
+                    );
+
+                let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                    Ok(param_id) => match param_id.parse::<i32>() {
+                        Ok(param_id) => param_id,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                                let result = api_impl.songs_id_get(
+                                            param_id,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                SongsIdGetResponse::SongMetadata
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for SONGS_ID_GET_SONG_METADATA"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                SongsIdGetResponse::UnknownSong
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                },
+                                                SongsIdGetResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+            },
+
+            // SongsIdPut - PUT /songs/{id}
+            hyper::Method::PUT if path.matched(paths::ID_SONGS_ID) => {
+                // Path parameters
+                let path: &str = uri.path();
+                let path_params =
+                    paths::REGEX_SONGS_ID
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE SONGS_ID in set but failed match against \"{}\"", path, paths::REGEX_SONGS_ID.as_str())
+                    );
+
+                let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
+                    Ok(param_id) => match param_id.parse::<i32>() {
+                        Ok(param_id) => param_id,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
+                let param_write_tags = query_params.iter().filter(|e| e.0 == "write_tags").map(|e| e.1.clone())
+                    .next();
+                let param_write_tags = match param_write_tags {
+                    Some(param_write_tags) => {
+                        let param_write_tags =
+                            <bool as std::str::FromStr>::from_str
+                                (&param_write_tags);
+                        match param_write_tags {
+                            Ok(param_write_tags) => Some(param_write_tags),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter write_tags - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter write_tags")),
+                        }
+                    },
+                    None => None,
+                };
+
+                // Body parameters (note that non-required body parameters will ignore garbage
+                // values, rather than causing a 400 response). Produce warning header and logs for
+                // any unused fields.
+                let result = body.into_raw().await;
+                match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_song: Option<models::Song> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_song) => param_song,
+                                        Err(e) => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from(format!("Couldn't parse body parameter Song - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter Song due to schema")),
+                                    }
+                                } else {
+                                    None
+                                };
+                                let param_song = match param_song {
+                                    Some(param_song) => param_song,
+                                    None => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from("Missing required body parameter Song"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter Song")),
+                                };
+
+                                let result = api_impl.songs_id_put(
+                                            param_id,
+                                            param_write_tags,
+                                            param_song,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                SongsIdPutResponse::SongUpdated
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for SONGS_ID_PUT_SONG_UPDATED"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                SongsIdPutResponse::UnknownSong
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                },
+                                                SongsIdPutResponse::WrongData
                                                 => {
-                                                    *response.status_mut() = StatusCode::from_u16(201).expect("Unable to turn 201 into a StatusCode");
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
                                                 },
-                                                PlaylistsPostResponse::UnexpectedError
+                                                SongsIdPutResponse::UnexpectedError
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                 },
@@ -411,14 +1529,58 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                             },
                             Err(e) => Ok(Response::builder()
                                                 .status(StatusCode::BAD_REQUEST)
-                                                .body(Body::from(format!("Couldn't read body parameter Playlist: {}", e)))
-                                                .expect("Unable to create Bad Request response due to unable to read body parameter Playlist")),
+                                                .body(Body::from(format!("Couldn't read body parameter Song: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter Song")),
                         }
             },
 
-            // RootGet - GET /
-            hyper::Method::GET if path.matched(paths::ID_) => {
-                                let result = api_impl.root_get(
+            // SongsPost - POST /songs
+            hyper::Method::POST if path.matched(paths::ID_SONGS) => {
+                // Header parameters
+                let param_x_filename = headers.get(HeaderName::from_static("x-filename"));
+
+                let param_x_filename = match param_x_filename {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            result.0,
+                        Err(err) => {
+                            return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Invalid header X-Filename - {}", err)))
+                                        .expect("Unable to create Bad Request response for invalid header X-Filename"));
+
+                        },
+                    },
+                    None => {
+                        return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from("Missing required header X-Filename"))
+                                        .expect("Unable to create Bad Request response for missing required header X-Filename"));
+                    }
+                };
+
+                // Body parameters (note that non-required body parameters will ignore garbage
+                // values, rather than causing a 400 response). Produce warning header and logs for
+                // any unused fields.
+                let result = body.into_raw().await;
+                match result {
+                            Ok(body) => {
+                                let param_body: Option<swagger::ByteArray> = if !body.is_empty() {
+                                    Some(swagger::ByteArray(body.to_vec()))
+                                } else {
+                                    None
+                                };
+                                let param_body = match param_body {
+                                    Some(param_body) => param_body,
+                                    None => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from("Missing required body parameter body"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter body")),
+                                };
+
+                                let result = api_impl.songs_post(
+                                            param_x_filename,
+                                            param_body,
                                         &context
                                     ).await;
                                 let mut response = Response::new(Body::empty());
@@ -429,17 +1591,37 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                RootGetResponse::Ok
+                                                SongsPostResponse::SuccessfulOperation
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(201).expect("Unable to turn 201 into a StatusCode");
+                                                },
+                                                SongsPostResponse::WrongData
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                },
+                                                SongsPostResponse::FileTooLarge
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(413).expect("Unable to turn 413 into a StatusCode");
+                                                },
+                                                SongsPostResponse::SongAlreadyExists
                                                     (body)
                                                 => {
-                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    *response.status_mut() = StatusCode::from_u16(409).expect("Unable to turn 409 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for ROOT_GET_OK"));
+                                                            .expect("Unable to create Content-Type header for SONGS_POST_SONG_ALREADY_EXISTS"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
+                                                SongsPostResponse::FileFormatNotSupported
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(415).expect("Unable to turn 415 into a StatusCode");
+                                                },
+                                                SongsPostResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
                                             },
                                             Err(_) => {
                                                 // Application code returned an error. This should not happen, as the implementation should
@@ -450,10 +1632,16 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         }
 
                                         Ok(response)
+                            },
+                            Err(e) => Ok(Response::builder()
+                                                .status(StatusCode::BAD_REQUEST)
+                                                .body(Body::from(format!("Couldn't read body parameter body: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter body")),
+                        }
             },
 
-            // SearchGet - GET /search
-            hyper::Method::GET if path.matched(paths::ID_SEARCH) => {
+            // SuggestGet - GET /suggest
+            hyper::Method::GET if path.matched(paths::ID_SUGGEST) => {
                 // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
                 let query_params = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).collect::<Vec<_>>();
                 let param_q = query_params.iter().filter(|e| e.0 == "q").map(|e| e.1.clone())
@@ -497,28 +1685,10 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                     },
                     None => None,
                 };
-                let param_offset = query_params.iter().filter(|e| e.0 == "offset").map(|e| e.1.clone())
-                    .next();
-                let param_offset = match param_offset {
-                    Some(param_offset) => {
-                        let param_offset =
-                            <i32 as std::str::FromStr>::from_str
-                                (&param_offset);
-                        match param_offset {
-                            Ok(param_offset) => Some(param_offset),
-                            Err(e) => return Ok(Response::builder()
-                                .status(StatusCode::BAD_REQUEST)
-                                .body(Body::from(format!("Couldn't parse query parameter offset - doesn't match schema: {}", e)))
-                                .expect("Unable to create Bad Request response for invalid query parameter offset")),
-                        }
-                    },
-                    None => None,
-                };
 
-                                let result = api_impl.search_get(
+                                let result = api_impl.suggest_get(
                                             param_q,
                                             param_limit,
-                                            param_offset,
                                         &context
                                     ).await;
                                 let mut response = Response::new(Body::empty());
@@ -529,17 +1699,21 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                SearchGetResponse::ListOfSongMatchingQuery
+                                                SuggestGetResponse::Suggestions
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for SEARCH_GET_LIST_OF_SONG_MATCHING_QUERY"));
+                                                            .expect("Unable to create Content-Type header for SUGGEST_GET_SUGGESTIONS"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
+                                                SuggestGetResponse::UnexpectedError
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
+                                                },
                                             },
                                             Err(_) => {
                                                 // Application code returned an error. This should not happen, as the implementation should
@@ -552,33 +1726,9 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         Ok(response)
             },
 
-            // SongsIdDelete - DELETE /songs/{id}
-            hyper::Method::DELETE if path.matched(paths::ID_SONGS_ID) => {
-                // Path parameters
-                let path: &str = uri.path();
-                let path_params =
-                    paths::REGEX_SONGS_ID
-                    .captures(path)
-                    .unwrap_or_else(||
-                        panic!("Path {} matched RE SONGS_ID in set but failed match against \"{}\"", path, paths::REGEX_SONGS_ID.as_str())
-                    );
-
-                let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
-                    Ok(param_id) => match param_id.parse::<i32>() {
-                        Ok(param_id) => param_id,
-                        Err(e) => return Ok(Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't parse path parameter id: {}", e)))
-                                        .expect("Unable to create Bad Request response for invalid path parameter")),
-                    },
-                    Err(_) => return Ok(Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["id"])))
-                                        .expect("Unable to create Bad Request response for invalid percent decode"))
-                };
-
-                                let result = api_impl.songs_id_delete(
-                                            param_id,
+            // UsersGet - GET /users
+            hyper::Method::GET if path.matched(paths::ID_USERS) => {
+                                let result = api_impl.users_get(
                                         &context
                                     ).await;
                                 let mut response = Response::new(Body::empty());
@@ -589,15 +1739,22 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                SongsIdDeleteResponse::SongDeleted
+                                                UsersGetResponse::ListOfUsers
+                                                    (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for USERS_GET_LIST_OF_USERS"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
                                                 },
-                                                SongsIdDeleteResponse::UnknownSong
+                                                UsersGetResponse::Forbidden
                                                 => {
-                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
                                                 },
-                                                SongsIdDeleteResponse::UnexpectedError
+                                                UsersGetResponse::UnexpectedError
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                 },
@@ -613,15 +1770,15 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         Ok(response)
             },
 
-            // SongsIdGet - GET /songs/{id}
-            hyper::Method::GET if path.matched(paths::ID_SONGS_ID) => {
+            // UsersIdDelete - DELETE /users/{id}
+            hyper::Method::DELETE if path.matched(paths::ID_USERS_ID) => {
                 // Path parameters
                 let path: &str = uri.path();
                 let path_params =
-                    paths::REGEX_SONGS_ID
+                    paths::REGEX_USERS_ID
                     .captures(path)
                     .unwrap_or_else(||
-                        panic!("Path {} matched RE SONGS_ID in set but failed match against \"{}\"", path, paths::REGEX_SONGS_ID.as_str())
+                        panic!("Path {} matched RE USERS_ID in set but failed match against \"{}\"", path, paths::REGEX_USERS_ID.as_str())
                     );
 
                 let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
@@ -638,7 +1795,7 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         .expect("Unable to create Bad Request response for invalid percent decode"))
                 };
 
-                                let result = api_impl.songs_id_get(
+                                let result = api_impl.users_id_delete(
                                             param_id,
                                         &context
                                     ).await;
@@ -650,22 +1807,23 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                SongsIdGetResponse::SongMetadata
-                                                    (body)
+                                                UsersIdDeleteResponse::UserDeleted
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
-                                                    response.headers_mut().insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for SONGS_ID_GET_SONG_METADATA"));
-                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
-                                                    *response.body_mut() = Body::from(body);
                                                 },
-                                                SongsIdGetResponse::UnknownSong
+                                                UsersIdDeleteResponse::Forbidden
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
+                                                },
+                                                UsersIdDeleteResponse::UnknownUser
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
                                                 },
-                                                SongsIdGetResponse::UnexpectedError
+                                                UsersIdDeleteResponse::LastAdministrator
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(422).expect("Unable to turn 422 into a StatusCode");
+                                                },
+                                                UsersIdDeleteResponse::UnexpectedError
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                 },
@@ -681,15 +1839,15 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                         Ok(response)
             },
 
-            // SongsIdPut - PUT /songs/{id}
-            hyper::Method::PUT if path.matched(paths::ID_SONGS_ID) => {
+            // UsersIdPut - PUT /users/{id}
+            hyper::Method::PUT if path.matched(paths::ID_USERS_ID) => {
                 // Path parameters
                 let path: &str = uri.path();
                 let path_params =
-                    paths::REGEX_SONGS_ID
+                    paths::REGEX_USERS_ID
                     .captures(path)
                     .unwrap_or_else(||
-                        panic!("Path {} matched RE SONGS_ID in set but failed match against \"{}\"", path, paths::REGEX_SONGS_ID.as_str())
+                        panic!("Path {} matched RE USERS_ID in set but failed match against \"{}\"", path, paths::REGEX_USERS_ID.as_str())
                     );
 
                 let param_id = match percent_encoding::percent_decode(path_params["id"].as_bytes()).decode_utf8() {
@@ -713,32 +1871,32 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                 match result {
                             Ok(body) => {
                                 let mut unused_elements = Vec::new();
-                                let param_playlist: Option<models::Playlist> = if !body.is_empty() {
+                                let param_user: Option<models::User> = if !body.is_empty() {
                                     let deserializer = &mut serde_json::Deserializer::from_slice(&body);
                                     match serde_ignored::deserialize(deserializer, |path| {
                                             warn!("Ignoring unknown field in body: {}", path);
                                             unused_elements.push(path.to_string());
                                     }) {
-                                        Ok(param_playlist) => param_playlist,
+                                        Ok(param_user) => param_user,
                                         Err(e) => return Ok(Response::builder()
                                                         .status(StatusCode::BAD_REQUEST)
-                                                        .body(Body::from(format!("Couldn't parse body parameter Playlist - doesn't match schema: {}", e)))
-                                                        .expect("Unable to create Bad Request response for invalid body parameter Playlist due to schema")),
+                                                        .body(Body::from(format!("Couldn't parse body parameter User - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter User due to schema")),
                                     }
                                 } else {
                                     None
                                 };
-                                let param_playlist = match param_playlist {
-                                    Some(param_playlist) => param_playlist,
+                                let param_user = match param_user {
+                                    Some(param_user) => param_user,
                                     None => return Ok(Response::builder()
                                                         .status(StatusCode::BAD_REQUEST)
-                                                        .body(Body::from("Missing required body parameter Playlist"))
-                                                        .expect("Unable to create Bad Request response for missing body parameter Playlist")),
+                                                        .body(Body::from("Missing required body parameter User"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter User")),
                                 };
 
-                                let result = api_impl.songs_id_put(
+                                let result = api_impl.users_id_put(
                                             param_id,
-                                            param_playlist,
+                                            param_user,
                                         &context
                                     ).await;
                                 let mut response = Response::new(Body::empty());
@@ -756,19 +1914,38 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                SongsIdPutResponse::PlaylistUpdated
+                                                UsersIdPutResponse::UserUpdated
+                                                    (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for USERS_ID_PUT_USER_UPDATED"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UsersIdPutResponse::WrongData
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                },
+                                                UsersIdPutResponse::Forbidden
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
                                                 },
-                                                SongsIdPutResponse::UnknownPlaylist
+                                                UsersIdPutResponse::UnknownUser
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
                                                 },
-                                                SongsIdPutResponse::WrongData
+                                                UsersIdPutResponse::UserNameAlreadyUsed
                                                 => {
-                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    *response.status_mut() = StatusCode::from_u16(409).expect("Unable to turn 409 into a StatusCode");
                                                 },
-                                                SongsIdPutResponse::UnexpectedError
+                                                UsersIdPutResponse::LastAdministrator
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(422).expect("Unable to turn 422 into a StatusCode");
+                                                },
+                                                UsersIdPutResponse::UnexpectedError
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                 },
@@ -785,64 +1962,45 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                             },
                             Err(e) => Ok(Response::builder()
                                                 .status(StatusCode::BAD_REQUEST)
-                                                .body(Body::from(format!("Couldn't read body parameter Playlist: {}", e)))
-                                                .expect("Unable to create Bad Request response due to unable to read body parameter Playlist")),
+                                                .body(Body::from(format!("Couldn't read body parameter User: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter User")),
                         }
             },
 
-            // SongsPost - POST /songs
-            hyper::Method::POST if path.matched(paths::ID_SONGS) => {
-                // Header parameters
-                let param_x_filename = headers.get(HeaderName::from_static("x-filename"));
-
-                let param_x_filename = match param_x_filename {
-                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
-                        Ok(result) =>
-                            result.0,
-                        Err(err) => {
-                            return Ok(Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Invalid header X-Filename - {}", err)))
-                                        .expect("Unable to create Bad Request response for invalid header X-Filename"));
-
-                        },
-                    },
-                    None => {
-                        return Ok(Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from("Missing required header X-Filename"))
-                                        .expect("Unable to create Bad Request response for missing required header X-Filename"));
-                    }
-                };
-
+            // UsersPost - POST /users
+            hyper::Method::POST if path.matched(paths::ID_USERS) => {
                 // Body parameters (note that non-required body parameters will ignore garbage
                 // values, rather than causing a 400 response). Produce warning header and logs for
                 // any unused fields.
                 let result = body.into_raw().await;
                 match result {
                             Ok(body) => {
-                                let param_body: Option<String> = if !body.is_empty() {
-                                    match String::from_utf8(body.to_vec()) {
-                                        Ok(param_body) => Some(param_body),
+                                let mut unused_elements = Vec::new();
+                                let param_user: Option<models::User> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_user) => param_user,
                                         Err(e) => return Ok(Response::builder()
                                                         .status(StatusCode::BAD_REQUEST)
-                                                        .body(Body::from(format!("Couldn't parse body parameter body - not valid UTF-8: {}", e)))
-                                                        .expect("Unable to create Bad Request response for invalid body parameter body due to UTF-8")),
+                                                        .body(Body::from(format!("Couldn't parse body parameter User - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter User due to schema")),
                                     }
                                 } else {
                                     None
                                 };
-                                let param_body = match param_body {
-                                    Some(param_body) => param_body,
+                                let param_user = match param_user {
+                                    Some(param_user) => param_user,
                                     None => return Ok(Response::builder()
                                                         .status(StatusCode::BAD_REQUEST)
-                                                        .body(Body::from("Missing required body parameter body"))
-                                                        .expect("Unable to create Bad Request response for missing body parameter body")),
+                                                        .body(Body::from("Missing required body parameter User"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter User")),
                                 };
 
-                                let result = api_impl.songs_post(
-                                            param_x_filename,
-                                            param_body,
+                                let result = api_impl.users_post(
+                                            param_user,
                                         &context
                                     ).await;
                                 let mut response = Response::new(Body::empty());
@@ -851,17 +2009,39 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                                             HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
                                                 .expect("Unable to create X-Span-ID header value"));
 
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                SongsPostResponse::SuccessfulOperation
+                                                UsersPostResponse::UserCreated
+                                                    (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(201).expect("Unable to turn 201 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for USERS_POST_USER_CREATED"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
                                                 },
-                                                SongsPostResponse::FileFormatNotSupported
+                                                UsersPostResponse::WrongData
                                                 => {
-                                                    *response.status_mut() = StatusCode::from_u16(415).expect("Unable to turn 415 into a StatusCode");
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
                                                 },
-                                                SongsPostResponse::UnexpectedError
+                                                UsersPostResponse::Forbidden
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
+                                                },
+                                                UsersPostResponse::UserAlreadyExists
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(409).expect("Unable to turn 409 into a StatusCode");
+                                                },
+                                                UsersPostResponse::UnexpectedError
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                 },
@@ -878,17 +2058,28 @@ impl<T, C> hyper::service::Service<(Request<Body>, C)> for Service<T, C> where
                             },
                             Err(e) => Ok(Response::builder()
                                                 .status(StatusCode::BAD_REQUEST)
-                                                .body(Body::from(format!("Couldn't read body parameter body: {}", e)))
-                                                .expect("Unable to create Bad Request response due to unable to read body parameter body")),
+                                                .body(Body::from(format!("Couldn't read body parameter User: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter User")),
                         }
             },
 
             _ if path.matched(paths::ID_) => method_not_allowed(),
+            _ if path.matched(paths::ID_ALBUMS) => method_not_allowed(),
+            _ if path.matched(paths::ID_ALBUMS_INDEX) => method_not_allowed(),
+            _ if path.matched(paths::ID_ALBUMS_ID) => method_not_allowed(),
+            _ if path.matched(paths::ID_ARTISTS) => method_not_allowed(),
+            _ if path.matched(paths::ID_ARTISTS_INDEX) => method_not_allowed(),
+            _ if path.matched(paths::ID_ARTISTS_ID) => method_not_allowed(),
+            _ if path.matched(paths::ID_LIBRARY_SCAN) => method_not_allowed(),
             _ if path.matched(paths::ID_PLAYLISTS) => method_not_allowed(),
             _ if path.matched(paths::ID_PLAYLISTS_ID) => method_not_allowed(),
             _ if path.matched(paths::ID_SEARCH) => method_not_allowed(),
             _ if path.matched(paths::ID_SONGS) => method_not_allowed(),
+            _ if path.matched(paths::ID_SONGS_DUPLICATES) => method_not_allowed(),
             _ if path.matched(paths::ID_SONGS_ID) => method_not_allowed(),
+            _ if path.matched(paths::ID_SUGGEST) => method_not_allowed(),
+            _ if path.matched(paths::ID_USERS) => method_not_allowed(),
+            _ if path.matched(paths::ID_USERS_ID) => method_not_allowed(),
             _ => Ok(Response::builder().status(StatusCode::NOT_FOUND)
                     .body(Body::empty())
                     .expect("Unable to create Not Found response"))
@@ -902,6 +2093,20 @@ impl<T> RequestParser<T> for ApiRequestParser {
     fn parse_operation_id(request: &Request<T>) -> Option<&'static str> {
         let path = paths::GLOBAL_REGEX_SET.matches(request.uri().path());
         match *request.method() {
+            // AlbumsGet - GET /albums
+            hyper::Method::GET if path.matched(paths::ID_ALBUMS) => Some("AlbumsGet"),
+            // AlbumsIndexGet - GET /albums/index
+            hyper::Method::GET if path.matched(paths::ID_ALBUMS_INDEX) => Some("AlbumsIndexGet"),
+            // AlbumsIdGet - GET /albums/{id}
+            hyper::Method::GET if path.matched(paths::ID_ALBUMS_ID) => Some("AlbumsIdGet"),
+            // ArtistsGet - GET /artists
+            hyper::Method::GET if path.matched(paths::ID_ARTISTS) => Some("ArtistsGet"),
+            // ArtistsIndexGet - GET /artists/index
+            hyper::Method::GET if path.matched(paths::ID_ARTISTS_INDEX) => Some("ArtistsIndexGet"),
+            // ArtistsIdGet - GET /artists/{id}
+            hyper::Method::GET if path.matched(paths::ID_ARTISTS_ID) => Some("ArtistsIdGet"),
+            // LibraryScanPost - POST /library/scan
+            hyper::Method::POST if path.matched(paths::ID_LIBRARY_SCAN) => Some("LibraryScanPost"),
             // PlaylistsGet - GET /playlists
             hyper::Method::GET if path.matched(paths::ID_PLAYLISTS) => Some("PlaylistsGet"),
             // PlaylistsIdDelete - DELETE /playlists/{id}
@@ -914,6 +2119,8 @@ impl<T> RequestParser<T> for ApiRequestParser {
             hyper::Method::GET if path.matched(paths::ID_) => Some("RootGet"),
             // SearchGet - GET /search
             hyper::Method::GET if path.matched(paths::ID_SEARCH) => Some("SearchGet"),
+            // SongsDuplicatesGet - GET /songs/duplicates
+            hyper::Method::GET if path.matched(paths::ID_SONGS_DUPLICATES) => Some("SongsDuplicatesGet"),
             // SongsIdDelete - DELETE /songs/{id}
             hyper::Method::DELETE if path.matched(paths::ID_SONGS_ID) => Some("SongsIdDelete"),
             // SongsIdGet - GET /songs/{id}
@@ -922,6 +2129,16 @@ impl<T> RequestParser<T> for ApiRequestParser {
             hyper::Method::PUT if path.matched(paths::ID_SONGS_ID) => Some("SongsIdPut"),
             // SongsPost - POST /songs
             hyper::Method::POST if path.matched(paths::ID_SONGS) => Some("SongsPost"),
+            // SuggestGet - GET /suggest
+            hyper::Method::GET if path.matched(paths::ID_SUGGEST) => Some("SuggestGet"),
+            // UsersGet - GET /users
+            hyper::Method::GET if path.matched(paths::ID_USERS) => Some("UsersGet"),
+            // UsersIdDelete - DELETE /users/{id}
+            hyper::Method::DELETE if path.matched(paths::ID_USERS_ID) => Some("UsersIdDelete"),
+            // UsersIdPut - PUT /users/{id}
+            hyper::Method::PUT if path.matched(paths::ID_USERS_ID) => Some("UsersIdPut"),
+            // UsersPost - POST /users
+            hyper::Method::POST if path.matched(paths::ID_USERS) => Some("UsersPost"),
             _ => None,
         }
     }
@@ -0,0 +1,5 @@
+ALTER TABLE artists MODIFY name VARCHAR(50) NOT NULL;
+ALTER TABLE albums MODIFY name VARCHAR(50) NOT NULL;
+ALTER TABLE songs MODIFY name VARCHAR(50) NOT NULL;
+
+ALTER TABLE songs DROP COLUMN path;
@@ -0,0 +1,7 @@
+-- Location of the audio file, relative to the library folder.
+ALTER TABLE songs ADD COLUMN path VARCHAR(1024) NOT NULL DEFAULT '';
+
+-- 50 characters are too short for a lot of real titles.
+ALTER TABLE songs MODIFY name VARCHAR(255) NOT NULL;
+ALTER TABLE albums MODIFY name VARCHAR(255) NOT NULL;
+ALTER TABLE artists MODIFY name VARCHAR(255) NOT NULL;
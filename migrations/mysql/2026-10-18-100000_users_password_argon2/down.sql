@@ -0,0 +1,3 @@
+-- Argon2id hashes can't be converted back to MD5, those users have to be recreated.
+DELETE FROM users WHERE LENGTH(password) > 32;
+ALTER TABLE users MODIFY password VARCHAR(32) NOT NULL;
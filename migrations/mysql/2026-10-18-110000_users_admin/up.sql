@@ -0,0 +1,4 @@
+-- Administrators can manage users.
+ALTER TABLE users ADD COLUMN admin BOOLEAN NOT NULL DEFAULT FALSE;
+
+UPDATE users SET admin = TRUE WHERE user_id = 'admin';
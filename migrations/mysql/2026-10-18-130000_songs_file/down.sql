@@ -0,0 +1,3 @@
+DROP INDEX songs_hash ON songs;
+ALTER TABLE songs DROP COLUMN hash;
+ALTER TABLE songs DROP COLUMN modified;
@@ -0,0 +1,5 @@
+-- Last modification of the audio file, in seconds since epoch, and md5 of its content.
+-- Used by scans to skip files that didn't change and to follow moved files.
+ALTER TABLE songs ADD COLUMN modified BIGINT;
+ALTER TABLE songs ADD COLUMN hash VARCHAR(32);
+CREATE INDEX songs_hash ON songs (hash);
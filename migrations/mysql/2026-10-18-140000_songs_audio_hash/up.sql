@@ -0,0 +1,6 @@
+-- Hashes now only cover the audio content of the file, tags excluded.
+-- Previous ones are dropped, the next scan computes them again.
+UPDATE songs SET hash = NULL, modified = NULL;
+-- A song is only added once, even by concurrent uploads.
+DROP INDEX songs_hash ON songs;
+CREATE UNIQUE INDEX songs_hash ON songs (hash);
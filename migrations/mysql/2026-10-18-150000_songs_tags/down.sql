@@ -0,0 +1,7 @@
+ALTER TABLE albums DROP COLUMN total_disc;
+ALTER TABLE songs DROP COLUMN lyrics;
+ALTER TABLE songs DROP COLUMN comment;
+ALTER TABLE songs DROP COLUMN composer;
+ALTER TABLE songs DROP COLUMN disc;
+ALTER TABLE songs DROP FOREIGN KEY songs_artists_id_fk;
+ALTER TABLE songs DROP COLUMN artists_id;
@@ -0,0 +1,8 @@
+ALTER TABLE songs ADD COLUMN artists_id INTEGER;
+ALTER TABLE songs ADD CONSTRAINT songs_artists_id_fk FOREIGN KEY (artists_id) REFERENCES artists (id) ON UPDATE CASCADE ON DELETE SET NULL;
+UPDATE songs
+SET artists_id = (SELECT songs_artists.artists_id
+                  FROM songs_artists
+                  WHERE songs_artists.songs_id = songs.id
+                    AND songs_artists.position = 0);
+DROP TABLE songs_artists;
@@ -0,0 +1,2 @@
+-- Name of the cover of the album in the artwork folder, its hash and extension.
+ALTER TABLE albums ADD COLUMN cover VARCHAR(40);
@@ -0,0 +1,5 @@
+ALTER TABLE artists ALTER COLUMN name TYPE VARCHAR(50);
+ALTER TABLE albums ALTER COLUMN name TYPE VARCHAR(50);
+ALTER TABLE songs ALTER COLUMN name TYPE VARCHAR(50);
+
+ALTER TABLE songs DROP COLUMN path;
@@ -0,0 +1,7 @@
+-- Location of the audio file, relative to the library folder.
+ALTER TABLE songs ADD COLUMN path VARCHAR(1024) NOT NULL DEFAULT '';
+
+-- 50 characters are too short for a lot of real titles.
+ALTER TABLE songs ALTER COLUMN name TYPE VARCHAR(255);
+ALTER TABLE albums ALTER COLUMN name TYPE VARCHAR(255);
+ALTER TABLE artists ALTER COLUMN name TYPE VARCHAR(255);
@@ -0,0 +1,2 @@
+-- Argon2id PHC strings. Legacy MD5 digests are rehashed on next login.
+ALTER TABLE users ALTER COLUMN password TYPE VARCHAR(255);
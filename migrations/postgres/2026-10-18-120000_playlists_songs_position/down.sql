@@ -0,0 +1 @@
+ALTER TABLE playlists_songs DROP COLUMN position;
@@ -0,0 +1,2 @@
+-- Order of the songs in a playlist.
+ALTER TABLE playlists_songs ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+DROP INDEX songs_hash;
+CREATE INDEX songs_hash ON songs (hash);
+-- Audio hashes are kept, the next scan computes them again anyway.
+UPDATE songs SET modified = NULL;
@@ -0,0 +1,8 @@
+-- Richer tags, and an artist of the song itself besides the artist of its album.
+ALTER TABLE songs ADD COLUMN artists_id INTEGER;
+ALTER TABLE songs ADD CONSTRAINT songs_artists_id_fk FOREIGN KEY (artists_id) REFERENCES artists (id) ON UPDATE CASCADE ON DELETE SET NULL;
+ALTER TABLE songs ADD COLUMN disc INTEGER;
+ALTER TABLE songs ADD COLUMN composer VARCHAR(255);
+ALTER TABLE songs ADD COLUMN comment TEXT;
+ALTER TABLE songs ADD COLUMN lyrics TEXT;
+ALTER TABLE albums ADD COLUMN total_disc INTEGER;
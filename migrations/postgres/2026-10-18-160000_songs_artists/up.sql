@@ -0,0 +1,18 @@
+-- Artists of a song, in the order of its tags. Replaces the single artist of songs.
+CREATE TABLE songs_artists
+(
+    id         SERIAL PRIMARY KEY,
+    songs_id   INTEGER NOT NULL,
+    artists_id INTEGER NOT NULL,
+    position   INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (songs_id) REFERENCES songs (id) ON UPDATE CASCADE ON DELETE CASCADE,
+    FOREIGN KEY (artists_id) REFERENCES artists (id) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+INSERT INTO songs_artists (songs_id, artists_id, position)
+SELECT id, artists_id, 0
+FROM songs
+WHERE artists_id IS NOT NULL;
+
+ALTER TABLE songs DROP CONSTRAINT songs_artists_id_fk;
+ALTER TABLE songs DROP COLUMN artists_id;
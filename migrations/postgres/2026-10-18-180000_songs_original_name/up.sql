@@ -0,0 +1,2 @@
+-- Name of the file when it was uploaded or found by a scan, files are renamed in the library.
+ALTER TABLE songs ADD COLUMN original_name VARCHAR(255);
@@ -0,0 +1,88 @@
+//! Administration commands, run instead of the server.
+use crate::config::{MainConfig, UserCommand};
+use crate::database::Database;
+use crate::index;
+use crate::library::Library;
+use anyhow::{anyhow, bail, Context, Result};
+use std::path::Path;
+use tantivy::TantivyError;
+
+/// Run a `user` command.
+pub(crate) fn user(command: &UserCommand, config: &MainConfig) -> Result<()> {
+    let database = Database::try_from(config.database())?;
+
+    match command {
+        UserCommand::Add { name, admin } => {
+            let password = read_password()?;
+            let user = database.add_user(name, &password, *admin)?;
+            println!("User '{}' created with id {}", user.user_id(), user.id());
+        }
+        UserCommand::List => {
+            for user in database.users()? {
+                let role = if user.admin() { "admin" } else { "user" };
+                println!("{}\t{}\t{role}", user.id(), user.user_id());
+            }
+        }
+        UserCommand::Passwd { name } => {
+            let user = database
+                .user_by_name(name)?
+                .ok_or_else(|| anyhow!("Unknown user '{name}'"))?;
+            let password = read_password()?;
+            database.update_user(user.id(), None, Some(&password), None)?;
+            println!("Password of user '{name}' changed");
+        }
+        UserCommand::Remove { name } => {
+            let user = database
+                .user_by_name(name)?
+                .ok_or_else(|| anyhow!("Unknown user '{name}'"))?;
+            database.delete_user(user.id())?;
+            println!("User '{name}' removed");
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the `scan` command. The index can only be written by one process,
+/// a running server has to be stopped or asked to scan through the API.
+pub(crate) fn scan(directory: &Path, config: &MainConfig) -> Result<()> {
+    let database = Database::try_from(config.database())?;
+    let path = config.indexing().path();
+    let index = index::init_index(config.indexing()).map_err(|error| {
+        match error.downcast_ref::<TantivyError>() {
+            Some(TantivyError::LockFailure(..)) => anyhow!(
+                "The index {} is used by a running server, stop it or scan through /api/v1/library/scan",
+                path.display()
+            ),
+            _ => error.context(format!("Index folder {}", path.display())),
+        }
+    })?;
+    index.reconcile(&database)?;
+    let library: Library = config.library().into();
+    library.create_folder()?;
+
+    let report = library
+        .scan(directory, &database, &index)?
+        .ok_or_else(|| anyhow!("A scan is already running"))?;
+    println!(
+        "{} added, {} updated, {} failed, {} removed",
+        report.added, report.updated, report.failed, report.removed
+    );
+
+    Ok(())
+}
+
+/// Ask a new password, twice, without echoing it.
+fn read_password() -> Result<String> {
+    let password = rpassword::prompt_password("Password: ").context("Can't read password")?;
+    if password.is_empty() {
+        bail!("Password can't be empty");
+    }
+    let confirmation =
+        rpassword::prompt_password("Confirm password: ").context("Can't read password")?;
+    if password != confirmation {
+        bail!("Passwords don't match");
+    }
+
+    Ok(password)
+}
@@ -1,9 +1,10 @@
 use std::collections::BTreeMap;
 use std::fmt::Formatter;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::{anyhow, Context, Result};
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
 
 // Root config environments
@@ -14,13 +15,32 @@ static ENV_HEADERS: &str = "PARTITION_HEADERS_";
 // Library config environments
 static ENV_LIBRARY_PATH: &str = "PARTITION_LIBRARY_PATH";
 static ENV_LIBRARY_TMP: &str = "PARTITION_LIBRARY_TMP";
+static ENV_LIBRARY_MAX_UPLOAD_SIZE: &str = "PARTITION_LIBRARY_MAX_UPLOAD_SIZE";
+static ENV_LIBRARY_WATCH: &str = "PARTITION_LIBRARY_WATCH";
+static ENV_LIBRARY_ARTWORK: &str = "PARTITION_LIBRARY_ARTWORK";
+
+/// Default maximum size of an uploaded file : 200 MiB
+const DEFAULT_MAX_UPLOAD_SIZE: u64 = 200 * 1024 * 1024;
+/// Default separators of the artists in an artist tag
+const DEFAULT_ARTIST_SEPARATORS: [&str; 4] = [";", " feat. ", " ft. ", " & "];
 
 // Index config environments
 static ENV_INDEXING_PATH: &str = "PARTITION_INDEXING_PATH";
+static ENV_INDEXING_BATCH_SIZE: &str = "PARTITION_INDEXING_BATCH_SIZE";
+static ENV_INDEXING_COMMIT_INTERVAL: &str = "PARTITION_INDEXING_COMMIT_INTERVAL";
+static ENV_INDEXING_WAIT_FOR_VISIBILITY: &str = "PARTITION_INDEXING_WAIT_FOR_VISIBILITY";
+
+/// Default number of changes committed at once
+const DEFAULT_BATCH_SIZE: usize = 1000;
+/// Default longest time a change waits to be committed : 1 second
+const DEFAULT_COMMIT_INTERVAL: u64 = 1000;
 
 // UI config environments
 static ENV_UI_PATH: &str = "PARTITION_UI_PATH";
 
+// Metrics config environments
+static ENV_METRICS_PUBLIC: &str = "PARTITION_METRICS_PUBLIC";
+
 // Database config environments
 static ENV_DATABASE_USERNAME: &str = "PARTITION_DATABASE_USERNAME";
 static ENV_DATABASE_PASSWORD: &str = "PARTITION_DATABASE_PASSWORD";
@@ -35,11 +55,56 @@ static ENV_DATABASE_CONNECTION_POSTGRES: &str = "PARTITION_DATABASE_CONNECTION_P
 #[command(author, version, about, long_about = None)]
 pub struct CommandLine {
     /// Path to configuration file
-    #[arg(short, long, default_value = "./partition.toml")]
+    #[arg(short, long, global = true, default_value = "./partition.toml")]
     configuration: String,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Manage users
+    #[command(subcommand)]
+    User(UserCommand),
+    /// Add the audio files of a folder, and its sub folders, to the library.
+    /// Files are left in place, already known files are skipped. The server must be stopped
+    Scan {
+        /// Folder to scan
+        directory: PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum UserCommand {
+    /// Create a user, the password is asked on the terminal
+    Add {
+        /// User name
+        name: String,
+        /// Allow the user to manage other users
+        #[arg(long)]
+        admin: bool,
+    },
+    /// List users
+    List,
+    /// Change the password of a user, the password is asked on the terminal
+    Passwd {
+        /// User name
+        name: String,
+    },
+    /// Remove a user
+    Remove {
+        /// User name
+        name: String,
+    },
 }
 
 impl CommandLine {
+    /// Command to run instead of the server
+    pub fn command(&self) -> Option<&Command> {
+        self.command.as_ref()
+    }
+
     pub fn configuration(&self) -> Result<MainConfig> {
         let content =
             std::fs::read_to_string(Path::new(&self.configuration)).with_context(|| {
@@ -65,6 +130,7 @@ pub struct MainConfig {
     indexing: Indexing,
     database: Database,
     ui: Option<UI>,
+    metrics: Option<Metrics>,
 }
 
 impl MainConfig {
@@ -128,12 +194,21 @@ impl MainConfig {
     pub fn ui(&self) -> Option<&UI> {
         self.ui.as_ref()
     }
+
+    /// Metrics
+    pub fn metrics(&self) -> Metrics {
+        self.metrics.clone().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub struct Library {
     path: String,
     tmp: String,
+    artwork: Option<String>,
+    max_upload_size: Option<u64>,
+    watch: Option<bool>,
+    artist_separators: Option<Vec<String>>,
 }
 
 impl Library {
@@ -149,11 +224,54 @@ impl Library {
         let path = std::env::var(ENV_LIBRARY_TMP).unwrap_or_else(|_| self.tmp.clone());
         PathBuf::from(path)
     }
+
+    /// Path to the covers extracted from audio files.
+    /// Default to an `.artwork` folder inside the library
+    pub fn artwork(&self) -> PathBuf {
+        match std::env::var(ENV_LIBRARY_ARTWORK)
+            .ok()
+            .or_else(|| self.artwork.clone())
+        {
+            Some(path) => PathBuf::from(path),
+            None => self.path().join(".artwork"),
+        }
+    }
+
+    /// Maximum size of an uploaded file, in bytes. Default to 200 MiB
+    pub fn max_upload_size(&self) -> u64 {
+        std::env::var(ENV_LIBRARY_MAX_UPLOAD_SIZE)
+            .ok()
+            .and_then(|size| size.parse().ok())
+            .or(self.max_upload_size)
+            .unwrap_or(DEFAULT_MAX_UPLOAD_SIZE)
+    }
+
+    /// Apply changes made to the library folder outside the server. Default to `false`
+    pub fn watch(&self) -> bool {
+        std::env::var(ENV_LIBRARY_WATCH)
+            .ok()
+            .and_then(|watch| watch.parse().ok())
+            .or(self.watch)
+            .unwrap_or(false)
+    }
+
+    /// Separators of the artists in an artist tag. Default to `;`, ` feat. `, ` ft. ` and ` & `
+    pub fn artist_separators(&self) -> Vec<String> {
+        self.artist_separators.clone().unwrap_or_else(|| {
+            DEFAULT_ARTIST_SEPARATORS
+                .iter()
+                .map(|separator| separator.to_string())
+                .collect()
+        })
+    }
 }
 
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub struct Indexing {
     path: String,
+    batch_size: Option<usize>,
+    commit_interval: Option<u64>,
+    wait_for_visibility: Option<bool>,
 }
 
 impl Indexing {
@@ -162,6 +280,36 @@ impl Indexing {
         let path = std::env::var(ENV_INDEXING_PATH).unwrap_or_else(|_| self.path.clone());
         PathBuf::from(path)
     }
+
+    /// Number of pending changes that triggers a commit of the index. Default to 1000
+    pub fn batch_size(&self) -> usize {
+        std::env::var(ENV_INDEXING_BATCH_SIZE)
+            .ok()
+            .and_then(|size| size.parse().ok())
+            .or(self.batch_size)
+            .unwrap_or(DEFAULT_BATCH_SIZE)
+            .max(1)
+    }
+
+    /// Longest time a change waits to be committed, in milliseconds. Default to 1000
+    pub fn commit_interval(&self) -> Duration {
+        let interval = std::env::var(ENV_INDEXING_COMMIT_INTERVAL)
+            .ok()
+            .and_then(|interval| interval.parse().ok())
+            .or(self.commit_interval)
+            .unwrap_or(DEFAULT_COMMIT_INTERVAL);
+        Duration::from_millis(interval)
+    }
+
+    /// Songs added, updated or removed through the API are searchable as soon
+    /// as the request is answered. Default to `true`
+    pub fn wait_for_visibility(&self) -> bool {
+        std::env::var(ENV_INDEXING_WAIT_FOR_VISIBILITY)
+            .ok()
+            .and_then(|wait| wait.parse().ok())
+            .or(self.wait_for_visibility)
+            .unwrap_or(true)
+    }
 }
 
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Deserialize, Serialize)]
@@ -177,6 +325,22 @@ impl UI {
     }
 }
 
+#[derive(Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct Metrics {
+    public: Option<bool>,
+}
+
+impl Metrics {
+    /// Allow access to metrics without authentication. Default to `false`
+    pub fn public(&self) -> bool {
+        std::env::var(ENV_METRICS_PUBLIC)
+            .ok()
+            .and_then(|public| public.parse().ok())
+            .or(self.public)
+            .unwrap_or(false)
+    }
+}
+
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub struct Database {
     connection: Connection,
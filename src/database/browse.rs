@@ -0,0 +1,361 @@
+use crate::database::model::{Albums, Songs};
+use crate::database::queries::Queries;
+use crate::database::{into_song, schema, with_connection, Database, DatabaseError};
+use crate::library::Song;
+use diesel::prelude::*;
+use diesel::sql_types::Text;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+sql_function!(fn lower(value: Text) -> Text);
+sql_function!(fn trim(value: Text) -> Text);
+
+/// Order of artists and albums when browsing the library.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Sort {
+    /// Alphabetical order, ignoring case.
+    #[default]
+    Name,
+    /// Newest first, those without year last.
+    Year,
+    /// Most recently added first, by id as they are given in order of creation.
+    /// An artist is added with its first song or album, kept until it has none.
+    Added,
+}
+
+impl FromStr for Sort {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "name" => Ok(Self::Name),
+            "year" => Ok(Self::Year),
+            "added" => Ok(Self::Added),
+            _ => Err(format!("Unknown sort order '{value}'")),
+        }
+    }
+}
+
+/// Artist of the library.
+pub(crate) struct Artist {
+    pub(crate) id: i32,
+    pub(crate) name: String,
+    /// Year of its newest album.
+    pub(crate) year: Option<i32>,
+    /// Albums, by year. `None` when only the artist itself is loaded.
+    pub(crate) albums: Option<Vec<Album>>,
+}
+
+/// Album of the library.
+pub(crate) struct Album {
+    pub(crate) id: i32,
+    pub(crate) name: String,
+    /// Id and name of the artist the album is filed under.
+    pub(crate) artist: Option<(i32, String)>,
+    pub(crate) year: Option<i32>,
+    pub(crate) total_tracks: Option<i32>,
+    pub(crate) total_discs: Option<i32>,
+    pub(crate) cover: bool,
+    /// Tracks, in order. `None` when only the album itself is loaded.
+    pub(crate) songs: Option<Vec<Song>>,
+}
+
+impl Album {
+    pub(super) fn new(album: Albums, artist: Option<(i32, String)>) -> Self {
+        Self {
+            id: album.id,
+            name: album.name,
+            artist,
+            year: album.year,
+            total_tracks: album.total_track,
+            total_discs: album.total_disc,
+            cover: album.cover.is_some(),
+            songs: None,
+        }
+    }
+}
+
+/// Names starting with the same letter in a list sorted by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Bucket {
+    /// Upper case letter, `#` for names not starting with a letter.
+    pub(crate) letter: String,
+    pub(crate) count: usize,
+    /// Position of the first name of the bucket in the list.
+    pub(crate) offset: usize,
+}
+
+/// Part of a list.
+pub(crate) struct Page<T> {
+    pub(crate) items: Vec<T>,
+    /// Length of the whole list.
+    pub(crate) total: usize,
+}
+
+impl<T> Page<T> {
+    fn new(items: Vec<T>, total: i64) -> Self {
+        let total = usize::try_from(total).unwrap_or_default();
+        Self { items, total }
+    }
+}
+
+/// Key sorting names alphabetically, ignoring case, ties broken by id,
+/// like the `ORDER BY` of the browsing queries.
+fn name_key(name: &str, id: i32) -> (String, i32) {
+    (name.trim().to_lowercase(), id)
+}
+
+/// Letter a name is listed under in an alphabetical index.
+fn letter(name: &str) -> String {
+    match name.trim().chars().next() {
+        Some(first) if first.is_alphabetic() => first.to_uppercase().collect(),
+        _ => "#".to_string(),
+    }
+}
+
+/// Alphabetical index of names, in the order of the list sorted by name.
+fn buckets<'a>(names: impl Iterator<Item = &'a str>) -> Vec<Bucket> {
+    let mut buckets: Vec<Bucket> = Vec::new();
+    for (offset, name) in names.enumerate() {
+        let letter = letter(name);
+        match buckets.iter_mut().find(|bucket| bucket.letter == letter) {
+            Some(bucket) => bucket.count += 1,
+            None => buckets.push(Bucket {
+                letter,
+                count: 1,
+                offset,
+            }),
+        }
+    }
+    buckets
+}
+
+impl Database {
+    /// Artists, sorted, from `offset` and at most `limit` of them.
+    pub(crate) fn artists(
+        &self,
+        sort: Sort,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Page<Artist>, DatabaseError> {
+        use schema::{albums, artists, artists_albums};
+
+        // Year of the newest album of each artist
+        let year = diesel::dsl::max(albums::year.nullable());
+        with_connection!(self, conn => {
+            let total = artists::table.count().get_result::<i64>(&mut conn)?;
+            let query = artists::table
+                .left_join(artists_albums::table.left_join(albums::table))
+                .group_by(artists::id)
+                .select((artists::id, artists::name, year))
+                .into_boxed();
+            let query = match sort {
+                Sort::Name => query.order((lower(trim(artists::name)), artists::id)),
+                Sort::Year => query.order((
+                    year.is_null(),
+                    year.desc(),
+                    lower(trim(artists::name)),
+                    artists::id,
+                )),
+                Sort::Added => query.order(artists::id.desc()),
+            };
+            let artists = query
+                .offset(offset)
+                .limit(limit)
+                .load::<(i32, String, Option<i32>)>(&mut conn)?
+                .into_iter()
+                .map(|(id, name, year)| Artist {
+                    id,
+                    name,
+                    year,
+                    albums: None,
+                })
+                .collect();
+            Ok(Page::new(artists, total))
+        })
+    }
+
+    /// Alphabetical index of the artists sorted by name.
+    pub(crate) fn artists_index(&self) -> Result<Vec<Bucket>, DatabaseError> {
+        use schema::artists;
+
+        let names = with_connection!(self, conn => artists::table
+            .order((lower(trim(artists::name)), artists::id))
+            .select(artists::name)
+            .load::<String>(&mut conn)?);
+        Ok(buckets(names.iter().map(String::as_str)))
+    }
+
+    /// Artist with its albums and the albums it appears on, oldest first.
+    pub(crate) fn artist(&self, id: i32) -> Result<Option<Artist>, DatabaseError> {
+        use schema::{artists, artists_albums, songs, songs_artists};
+
+        let (name, mut albums) = with_connection!(self, conn => {
+            let name = artists::table
+                .filter(artists::id.eq(id))
+                .select(artists::name)
+                .first::<String>(&mut conn)
+                .optional()?;
+            let Some(name) = name else {
+                return Ok(None);
+            };
+
+            let mut ids: BTreeSet<i32> = artists_albums::table
+                .filter(artists_albums::artists_id.eq(id))
+                .select(artists_albums::albums_id)
+                .load::<i32>(&mut conn)?
+                .into_iter()
+                .collect();
+            ids.extend(
+                songs_artists::table
+                    .inner_join(songs::table)
+                    .filter(songs_artists::artists_id.eq(id))
+                    .select(songs::albums_id)
+                    .distinct()
+                    .load::<Option<i32>>(&mut conn)?
+                    .into_iter()
+                    .flatten(),
+            );
+
+            (name, conn.albums(Some(&ids))?)
+        });
+
+        albums.sort_by_cached_key(|album| {
+            (
+                album.year.is_none(),
+                album.year,
+                name_key(&album.name, album.id),
+            )
+        });
+        Ok(Some(Artist {
+            id,
+            name,
+            year: albums.iter().filter_map(|album| album.year).max(),
+            albums: Some(albums),
+        }))
+    }
+
+    /// Albums, sorted, from `offset` and at most `limit` of them.
+    pub(crate) fn albums(
+        &self,
+        sort: Sort,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Page<Album>, DatabaseError> {
+        use schema::albums;
+
+        let (ids, mut albums, total) = with_connection!(self, conn => {
+            let total = albums::table.count().get_result::<i64>(&mut conn)?;
+            let query = albums::table.select(albums::id).into_boxed();
+            let query = match sort {
+                Sort::Name => query.order((lower(trim(albums::name)), albums::id)),
+                Sort::Year => query.order((
+                    albums::year.is_null(),
+                    albums::year.desc(),
+                    lower(trim(albums::name)),
+                    albums::id,
+                )),
+                Sort::Added => query.order(albums::id.desc()),
+            };
+            let ids = query.offset(offset).limit(limit).load::<i32>(&mut conn)?;
+            let albums = conn.albums(Some(&ids.iter().copied().collect()))?;
+            (ids, albums, total)
+        });
+
+        albums.sort_by_key(|album| ids.iter().position(|id| *id == album.id));
+        Ok(Page::new(albums, total))
+    }
+
+    /// Alphabetical index of the albums sorted by name.
+    pub(crate) fn albums_index(&self) -> Result<Vec<Bucket>, DatabaseError> {
+        use schema::albums;
+
+        let names = with_connection!(self, conn => albums::table
+            .order((lower(trim(albums::name)), albums::id))
+            .select(albums::name)
+            .load::<String>(&mut conn)?);
+        Ok(buckets(names.iter().map(String::as_str)))
+    }
+
+    /// Album with its tracks, by disc and track number.
+    pub(crate) fn album(&self, id: i32) -> Result<Option<Album>, DatabaseError> {
+        use schema::{albums, songs};
+
+        with_connection!(self, conn => {
+            let Some(mut album) = conn.albums(Some(&BTreeSet::from([id])))?.pop() else {
+                return Ok(None);
+            };
+
+            let songs = songs::table
+                .left_join(albums::table)
+                .filter(songs::albums_id.eq(id))
+                .select((Songs::as_select(), Option::<Albums>::as_select()))
+                .load::<(Songs, Option<Albums>)>(&mut conn)?;
+            let ids: BTreeSet<i32> = songs.iter().map(|(song, _)| song.id).collect();
+            let mut songs_artists = conn.songs_artists(Some(&ids))?;
+
+            let album_artist = album.artist.as_ref().map(|(_, name)| name.clone());
+            let mut songs: Vec<Song> = songs
+                .into_iter()
+                .map(|(song, album)| {
+                    let song_artists = songs_artists.remove(&song.id).unwrap_or_default();
+                    into_song(song, album, album_artist.clone(), song_artists)
+                })
+                .collect();
+            // Songs without disc or track number come last
+            songs.sort_by_cached_key(|song| {
+                (
+                    song.disc().unwrap_or(i32::MAX),
+                    song.track().unwrap_or(i32::MAX),
+                    song.title(),
+                    song.id(),
+                )
+            });
+
+            album.songs = Some(songs);
+            Ok(Some(album))
+        })
+    }
+}
+
+impl From<Artist> for server_lib::models::Artist {
+    fn from(artist: Artist) -> Self {
+        server_lib::models::Artist {
+            id: Some(artist.id),
+            name: Some(artist.name),
+            year: artist.year,
+            albums: artist
+                .albums
+                .map(|albums| albums.into_iter().map(|album| album.into()).collect()),
+        }
+    }
+}
+
+impl From<Album> for server_lib::models::Album {
+    fn from(album: Album) -> Self {
+        let (artist_id, artist) = album.artist.unzip();
+        server_lib::models::Album {
+            id: Some(album.id),
+            name: Some(album.name),
+            artist,
+            artist_id,
+            year: album.year,
+            total_tracks: album.total_tracks,
+            total_discs: album.total_discs,
+            cover: Some(album.cover),
+            songs: album
+                .songs
+                .map(|songs| songs.into_iter().map(|song| song.into()).collect()),
+        }
+    }
+}
+
+impl From<Bucket> for server_lib::models::IndexBucket {
+    fn from(bucket: Bucket) -> Self {
+        server_lib::models::IndexBucket {
+            letter: Some(bucket.letter),
+            count: i32::try_from(bucket.count).ok(),
+            offset: i32::try_from(bucket.offset).ok(),
+        }
+    }
+}
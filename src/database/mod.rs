@@ -1,14 +1,31 @@
 use crate::config::{Connection as ConnectionConfig, Database as DatabaseConfig};
-use crate::database::model::Users;
+use crate::database::model::{
+    Albums, NewPlaylists, NewPlaylistsSongs, NewSongs, NewUsers, NewUsersPlaylists, Songs,
+    UpdateAlbums, UpdateUsers, Users,
+};
+use crate::database::password::Verification;
+use crate::database::queries::Queries;
+use crate::index::TantivyIndex;
+use crate::library::Song;
 use diesel::prelude::*;
 use diesel::r2d2::{ConnectionManager, Pool};
+use diesel::result::DatabaseErrorKind;
 use diesel::MysqlConnection;
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use log::{info, warn};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, MutexGuard};
+use swagger::Nullable;
 use thiserror::Error;
 
+mod browse;
 mod model;
+mod password;
+mod queries;
 mod schema;
 
+pub(crate) use browse::Sort;
+
 #[cfg(feature = "mysql")]
 const MYSQL_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/mysql");
 #[cfg(feature = "postgres")]
@@ -24,6 +41,143 @@ pub enum DatabaseError {
     MigrationError(String),
     #[error("{0}")]
     MissingConfiguration(String),
+    #[error("{0}")]
+    Aborted(String),
+    #[error("{0}")]
+    PasswordError(String),
+    #[error("{0}")]
+    AlreadyExists(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("Same audio content as song {0}")]
+    DuplicateSong(i32),
+}
+
+/// Run `$body` with `$conn` bound to a connection of the pool,
+/// whatever the database is.
+macro_rules! with_connection {
+    ($database:expr, $conn:ident => $body:expr) => {
+        match $database {
+            #[cfg(feature = "mysql")]
+            $crate::database::Database::MySQL(pool) => {
+                let mut $conn = pool.get()?;
+                $body
+            }
+            #[cfg(feature = "postgres")]
+            $crate::database::Database::Postgres(pool) => {
+                let mut $conn = pool.get()?;
+                $body
+            }
+        }
+    };
+}
+
+use with_connection;
+
+#[cfg(feature = "mysql")]
+sql_function!(#[sql_name = "LAST_INSERT_ID"] fn mysql_last_insert_id() -> Unsigned<BigInt>);
+#[cfg(feature = "postgres")]
+sql_function!(#[sql_name = "LASTVAL"] fn postgres_last_insert_id() -> BigInt);
+
+/// Id generated by the last `INSERT` done with this connection.
+trait LastInsertId {
+    fn last_insert_id(&mut self) -> QueryResult<i32>;
+}
+
+#[cfg(feature = "mysql")]
+impl LastInsertId for MysqlConnection {
+    fn last_insert_id(&mut self) -> QueryResult<i32> {
+        let id = diesel::select(mysql_last_insert_id()).get_result::<u64>(self)?;
+        Ok(id as i32)
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl LastInsertId for PgConnection {
+    fn last_insert_id(&mut self) -> QueryResult<i32> {
+        let id = diesel::select(postgres_last_insert_id()).get_result::<i64>(self)?;
+        Ok(id as i32)
+    }
+}
+
+/// Playlist as seen by a user.
+pub(crate) struct Playlist {
+    pub(crate) id: i32,
+    pub(crate) name: String,
+    pub(crate) owner: String,
+    pub(crate) shared: bool,
+    pub(crate) query: Option<String>,
+    /// Songs, in order. `None` when only the playlist itself is loaded.
+    pub(crate) songs: Option<Vec<Song>>,
+    /// Songs removed from the result of the query.
+    pub(crate) excluded: BTreeSet<i32>,
+}
+
+impl Playlist {
+    /// Run the query of a dynamic playlist and merge its result with the songs
+    /// explicitly added or removed.
+    ///
+    /// Songs matching the query come first, ordered by artist, album, track and title,
+    /// followed by the added songs in their playlist order.
+    pub(crate) fn resolve(mut self, index: &TantivyIndex) -> tantivy::Result<Self> {
+        let Some(query) = &self.query else {
+            return Ok(self);
+        };
+
+        let added = self.songs.take().unwrap_or_default();
+        let mut songs: Vec<Song> = index
+            .search_all(query)?
+            .into_iter()
+            .filter(|song| {
+                song.id()
+                    .map(|id| {
+                        !self.excluded.contains(&id)
+                            && !added.iter().any(|added| added.id() == Some(id))
+                    })
+                    .unwrap_or(false)
+            })
+            .collect();
+        songs.sort_by_cached_key(|song| {
+            (
+                song.artist(),
+                song.album(),
+                song.track(),
+                song.title(),
+                song.id(),
+            )
+        });
+        songs.extend(added);
+
+        self.songs = Some(songs);
+        Ok(self)
+    }
+}
+
+/// Audio file of a song.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SongFile {
+    /// Path relative to the library folder, or absolute when outside of it.
+    pub(crate) path: String,
+    /// Last modification, in seconds since epoch.
+    pub(crate) modified: Option<i64>,
+    /// MD5 of the content of the file.
+    pub(crate) hash: Option<String>,
+    /// Name of the file when it was uploaded or found, only kept as metadata.
+    pub(crate) original_name: Option<String>,
+}
+
+/// Held by the transactions creating or removing artists and albums. They are looked up
+/// by name and created when missing, songs of an album added at the same time, like by
+/// a scan, would otherwise create it several times.
+static CATALOG: Mutex<()> = Mutex::new(());
+
+/// Wait for the other transactions creating or removing artists and albums.
+fn lock_catalog() -> MutexGuard<'static, ()> {
+    CATALOG
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
 }
 
 pub enum Database {
@@ -34,31 +188,859 @@ pub enum Database {
 }
 
 impl Database {
+    /// Check user credentials.
+    ///
+    /// Legacy MD5 hashes are replaced by an Argon2id hash once the password is known to be valid.
     pub(crate) fn authenticate_user(
         &self,
         usr: &str,
         pwd: &str,
     ) -> Result<Option<Users>, DatabaseError> {
-        use schema::users::dsl::*;
+        use schema::users;
 
-        let digest = md5::compute(pwd);
-        let pwd = format!("{:x}", digest);
+        let user = with_connection!(self, conn => users::table
+            .filter(users::user_id.eq(usr))
+            .select(Users::as_select())
+            .first::<Users>(&mut conn)
+            .optional()?);
 
-        let select = users.filter(user_id.eq(usr).and(password.eq(pwd)));
-        let result = match self {
-            #[cfg(feature = "mysql")]
-            Database::MySQL(conn) => {
-                let mut conn = conn.get()?;
-                select.load::<Users>(&mut conn)?
+        let Some(user) = user else {
+            password::verify_dummy(pwd);
+            return Ok(None);
+        };
+
+        match password::verify(pwd, user.password()) {
+            Verification::Invalid => Ok(None),
+            Verification::Valid => Ok(Some(user)),
+            Verification::Rehash => {
+                match password::hash(pwd) {
+                    Ok(hash) => {
+                        let update = diesel::update(users::table.filter(users::id.eq(user.id())))
+                            .set(users::password.eq(hash));
+                        let result = with_connection!(self, conn => update.execute(&mut conn));
+                        match result {
+                            Ok(_) => info!("Password of user '{usr}' rehashed"),
+                            Err(error) => {
+                                warn!("Can't rehash password of user '{usr}' : {error:?}")
+                            }
+                        }
+                    }
+                    Err(error) => warn!("Can't rehash password of user '{usr}' : {error}"),
+                }
+                Ok(Some(user))
             }
-            #[cfg(feature = "postgres")]
-            Database::Postgres(conn) => {
-                let mut conn = conn.get()?;
-                select.load::<Users>(&mut conn)?
+        }
+    }
+
+    /// All users, ordered by name.
+    pub(crate) fn users(&self) -> Result<Vec<Users>, DatabaseError> {
+        use schema::users;
+
+        with_connection!(self, conn => Ok(users::table
+            .order(users::user_id)
+            .select(Users::as_select())
+            .load::<Users>(&mut conn)?))
+    }
+
+    /// User with the given name.
+    pub(crate) fn user_by_name(&self, name: &str) -> Result<Option<Users>, DatabaseError> {
+        use schema::users;
+
+        with_connection!(self, conn => Ok(users::table
+            .filter(users::user_id.eq(name))
+            .select(Users::as_select())
+            .first::<Users>(&mut conn)
+            .optional()?))
+    }
+
+    /// Create a user.
+    ///
+    /// Fails with [`DatabaseError::AlreadyExists`] if the name is already used.
+    pub(crate) fn add_user(
+        &self,
+        name: &str,
+        pwd: &str,
+        admin: bool,
+    ) -> Result<Users, DatabaseError> {
+        use schema::users;
+
+        let hash = hash_password(pwd)?;
+
+        with_connection!(self, conn => conn.transaction(|conn| {
+            diesel::insert_into(users::table)
+                .values(NewUsers {
+                    user_id: name,
+                    password: &hash,
+                    admin,
+                })
+                .execute(conn)
+                .map_err(|error| already_exists(error, name))?;
+            let id = conn.last_insert_id()?;
+
+            Ok(users::table
+                .filter(users::id.eq(id))
+                .select(Users::as_select())
+                .first::<Users>(conn)?)
+        }))
+    }
+
+    /// Rename a user, change its password or its role. `None` values are left unchanged.
+    ///
+    /// Returns `None` if there is no user with this id, fails with
+    /// [`DatabaseError::AlreadyExists`] if the new name is already used and with
+    /// [`DatabaseError::Forbidden`] if the last administrator would lose its role.
+    pub(crate) fn update_user(
+        &self,
+        id: i32,
+        name: Option<&str>,
+        pwd: Option<&str>,
+        admin: Option<bool>,
+    ) -> Result<Option<Users>, DatabaseError> {
+        use schema::users;
+
+        let hash = pwd.map(hash_password).transpose()?;
+        let changes = UpdateUsers {
+            user_id: name,
+            password: hash.as_deref(),
+            admin,
+        };
+
+        with_connection!(self, conn => conn.transaction(|conn| {
+            let user = users::table
+                .filter(users::id.eq(id))
+                .select(Users::as_select())
+                .first::<Users>(conn)
+                .optional()?;
+            let Some(user) = user else {
+                return Ok(None);
+            };
+            if changes.is_empty() {
+                return Ok(Some(user));
+            }
+            let admins = users::table
+                .filter(users::admin.eq(true))
+                .count()
+                .get_result::<i64>(conn)?;
+            if admin == Some(false) && user.admin() && admins == 1 {
+                return Err(DatabaseError::Forbidden(format!(
+                    "'{}' is the last administrator",
+                    user.user_id()
+                )));
+            }
+
+            diesel::update(users::table.filter(users::id.eq(id)))
+                .set(&changes)
+                .execute(conn)
+                .map_err(|error| already_exists(error, name.unwrap_or_default()))?;
+
+            Ok(Some(users::table
+                .filter(users::id.eq(id))
+                .select(Users::as_select())
+                .first::<Users>(conn)?))
+        }))
+    }
+
+    /// Delete a user and its playlists. Returns `false` if there is no user with this id.
+    ///
+    /// Fails with [`DatabaseError::Forbidden`] if it is the last administrator.
+    pub(crate) fn delete_user(&self, id: i32) -> Result<bool, DatabaseError> {
+        use schema::{playlists, users, users_playlists};
+
+        with_connection!(self, conn => conn.transaction(|conn| {
+            let user = users::table
+                .filter(users::id.eq(id))
+                .select(Users::as_select())
+                .first::<Users>(conn)
+                .optional()?;
+            let Some(user) = user else {
+                return Ok(false);
+            };
+            let admins = users::table
+                .filter(users::admin.eq(true))
+                .count()
+                .get_result::<i64>(conn)?;
+            if user.admin() && admins == 1 {
+                return Err(DatabaseError::Forbidden(format!(
+                    "'{}' is the last administrator",
+                    user.user_id()
+                )));
+            }
+
+            // Songs of the playlists and owner are removed by cascade
+            let owned = users_playlists::table
+                .filter(users_playlists::users_id.eq(id))
+                .select(users_playlists::playlists_id);
+            diesel::delete(playlists::table.filter(playlists::id.eq_any(owned))).execute(conn)?;
+            diesel::delete(users::table.filter(users::id.eq(id))).execute(conn)?;
+
+            Ok(true)
+        }))
+    }
+
+    /// Playlists of `user` and playlists shared by other users, without their songs.
+    pub(crate) fn playlists(&self, user: &str) -> Result<Vec<Playlist>, DatabaseError> {
+        use schema::{playlists, users, users_playlists};
+
+        let rows = with_connection!(self, conn => users_playlists::table
+            .inner_join(playlists::table)
+            .inner_join(users::table)
+            .filter(users::user_id.eq(user).or(users_playlists::shared.ne(0)))
+            .order(playlists::id)
+            .select((
+                playlists::id,
+                playlists::name,
+                users::user_id,
+                users_playlists::shared,
+                playlists::query,
+            ))
+            .load::<(i32, String, String, i32, Option<String>)>(&mut conn)?);
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, name, owner, shared, query)| Playlist {
+                id,
+                name,
+                owner,
+                shared: shared != 0,
+                query,
+                songs: None,
+                excluded: BTreeSet::new(),
+            })
+            .collect())
+    }
+
+    /// Playlist with its songs, if it belongs to `user` or is shared.
+    ///
+    /// Only the songs explicitly added are loaded, see [`Playlist::resolve`].
+    pub(crate) fn playlist(&self, user: &str, id: i32) -> Result<Option<Playlist>, DatabaseError> {
+        use schema::{playlists, users, users_playlists};
+
+        let row = with_connection!(self, conn => users_playlists::table
+            .inner_join(playlists::table)
+            .inner_join(users::table)
+            .filter(playlists::id.eq(id))
+            .filter(users::user_id.eq(user).or(users_playlists::shared.ne(0)))
+            .select((
+                playlists::name,
+                users::user_id,
+                users_playlists::shared,
+                playlists::query,
+            ))
+            .first::<(String, String, i32, Option<String>)>(&mut conn)
+            .optional()?);
+
+        let Some((name, owner, shared, query)) = row else {
+            return Ok(None);
+        };
+
+        let (songs, excluded) = self.playlist_songs(id)?;
+        Ok(Some(Playlist {
+            id,
+            name,
+            owner,
+            shared: shared != 0,
+            query,
+            songs: Some(songs),
+            excluded,
+        }))
+    }
+
+    /// Songs added to a playlist, in order, and songs removed from its query.
+    fn playlist_songs(&self, id: i32) -> Result<(Vec<Song>, BTreeSet<i32>), DatabaseError> {
+        use schema::{albums, artists, artists_albums, playlists_songs, songs};
+
+        with_connection!(self, conn => {
+            let excluded = playlists_songs::table
+                .filter(playlists_songs::playlists_id.eq(id))
+                .filter(playlists_songs::added.eq(0))
+                .select(playlists_songs::songs_id)
+                .load::<i32>(&mut conn)?
+                .into_iter()
+                .collect();
+
+            let songs = playlists_songs::table
+                .inner_join(songs::table.left_join(albums::table))
+                .filter(playlists_songs::playlists_id.eq(id))
+                .filter(playlists_songs::added.ne(0))
+                .order((playlists_songs::position, playlists_songs::id))
+                .select((Songs::as_select(), Option::<Albums>::as_select()))
+                .load::<(Songs, Option<Albums>)>(&mut conn)?;
+
+            let ids: BTreeSet<i32> = songs.iter().map(|(song, _)| song.id).collect();
+            let mut songs_artists = conn.songs_artists(Some(&ids))?;
+
+            // First artist of each album
+            let albums: BTreeSet<i32> = songs
+                .iter()
+                .filter_map(|(_, album)| album.as_ref().map(|album| album.id))
+                .collect();
+            let mut artists: HashMap<i32, String> = HashMap::new();
+            for (album, artist) in artists_albums::table
+                .inner_join(artists::table)
+                .filter(artists_albums::albums_id.eq_any(albums))
+                .order(artists::id)
+                .select((artists_albums::albums_id, artists::name))
+                .load::<(i32, String)>(&mut conn)?
+            {
+                artists.entry(album).or_insert(artist);
+            }
+
+            let songs = songs
+                .into_iter()
+                .map(|(song, album)| {
+                    let album_artist =
+                        album.as_ref().and_then(|album| artists.get(&album.id).cloned());
+                    let song_artists = songs_artists.remove(&song.id).unwrap_or_default();
+                    into_song(song, album, album_artist, song_artists)
+                })
+                .collect();
+            Ok((songs, excluded))
+        })
+    }
+
+    /// Create a playlist owned by `user`, with `songs` in the given order.
+    ///
+    /// Fails with [`DatabaseError::NotFound`] if one of the songs doesn't exist.
+    pub(crate) fn add_playlist(
+        &self,
+        user: &str,
+        name: &str,
+        query: Option<&str>,
+        shared: bool,
+        songs: &[i32],
+    ) -> Result<i32, DatabaseError> {
+        use schema::{playlists, playlists_songs, songs, users, users_playlists};
+
+        with_connection!(self, conn => conn.transaction(|conn| {
+            let users_id = users::table
+                .filter(users::user_id.eq(user))
+                .select(users::id)
+                .first::<i32>(conn)?;
+
+            let distinct: BTreeSet<i32> = songs.iter().copied().collect();
+            let known = songs::table
+                .filter(songs::id.eq_any(&distinct))
+                .select(songs::id)
+                .load::<i32>(conn)?;
+            if let Some(unknown) = distinct.iter().find(|id| !known.contains(id)) {
+                return Err(DatabaseError::NotFound(format!("Unknown song {unknown}")));
             }
+
+            diesel::insert_into(playlists::table)
+                .values(NewPlaylists { name, query })
+                .execute(conn)?;
+            let id = conn.last_insert_id()?;
+
+            diesel::insert_into(users_playlists::table)
+                .values(NewUsersPlaylists {
+                    users_id,
+                    playlists_id: id,
+                    shared: i32::from(shared),
+                })
+                .execute(conn)?;
+
+            let songs: Vec<NewPlaylistsSongs> = songs
+                .iter()
+                .enumerate()
+                .map(|(position, song)| NewPlaylistsSongs {
+                    playlists_id: id,
+                    songs_id: *song,
+                    added: 1,
+                    position: position as i32,
+                })
+                .collect();
+            diesel::insert_into(playlists_songs::table)
+                .values(&songs)
+                .execute(conn)?;
+
+            Ok(id)
+        }))
+    }
+
+    /// Delete a playlist owned by `user`.
+    ///
+    /// Returns `false` if the playlist doesn't exist or isn't visible by `user`, and fails
+    /// with [`DatabaseError::Forbidden`] if it's shared by another user.
+    pub(crate) fn delete_playlist(&self, user: &str, id: i32) -> Result<bool, DatabaseError> {
+        use schema::{playlists, users, users_playlists};
+
+        with_connection!(self, conn => conn.transaction(|conn| {
+            let owner = users_playlists::table
+                .inner_join(users::table)
+                .filter(users_playlists::playlists_id.eq(id))
+                .select((users::user_id, users_playlists::shared))
+                .first::<(String, i32)>(conn)
+                .optional()?;
+
+            match owner {
+                Some((owner, _)) if owner == user => {
+                    // Songs and owner are removed by cascade
+                    diesel::delete(playlists::table.filter(playlists::id.eq(id))).execute(conn)?;
+                    Ok(true)
+                }
+                Some((owner, shared)) if shared != 0 => Err(DatabaseError::Forbidden(format!(
+                    "Playlist {id} belongs to '{owner}'"
+                ))),
+                _ => Ok(false),
+            }
+        }))
+    }
+
+    /// Insert a song, reusing its artist and album if they already exist.
+    /// The `cover` of the song, if any, becomes the cover of its album.
+    ///
+    /// Fails with [`DatabaseError::DuplicateSong`] if a song has the same hash.
+    /// `on_inserted` is called with the new song, its ids set, and the other songs
+    /// of its album whose details changed, right before the transaction is committed.
+    /// If it fails, nothing is persisted.
+    pub(crate) fn add_song<F>(
+        &self,
+        song: &Song,
+        file: &SongFile,
+        cover: Option<&str>,
+        on_inserted: F,
+    ) -> Result<Song, DatabaseError>
+    where
+        F: FnOnce(&Song, &[Song]) -> anyhow::Result<()>,
+    {
+        use schema::songs;
+
+        let artists_names = song.artists();
+        let album_artist = album_artist(song);
+        let album_name = song.album();
+        let title = song.title();
+
+        let _catalog = lock_catalog();
+        let result = with_connection!(self, conn => conn.transaction(|conn| {
+            if let Some(hash) = &file.hash {
+                let duplicate = songs::table
+                    .filter(songs::hash.eq(hash))
+                    .select(songs::id)
+                    .first::<i32>(conn)
+                    .optional()?;
+                if let Some(id) = duplicate {
+                    return Err(DatabaseError::DuplicateSong(id));
+                }
+            }
+
+            let (album_id, album_changed) =
+                conn.album_id(&album_artist, &album_name, album_details(song, cover))?;
+
+            diesel::insert_into(songs::table)
+                .values(NewSongs {
+                    albums_id: Some(album_id),
+                    name: &title,
+                    genre: song.genre(),
+                    track: song.track(),
+                    duration: song.duration().unwrap_or_default(),
+                    path: &file.path,
+                    modified: file.modified,
+                    hash: file.hash.as_deref(),
+                    disc: song.disc(),
+                    composer: song.composer(),
+                    comment: song.comment(),
+                    lyrics: song.lyrics(),
+                    original_name: file.original_name.as_deref(),
+                })
+                .execute(conn)?;
+            let id = conn.last_insert_id()?;
+            conn.link_artists(id, &artists_names)?;
+
+            let mut song = song.clone();
+            song.set_id(id);
+            song.set_album_id(album_id);
+            let siblings = conn.siblings(album_id, id, album_changed)?;
+            on_inserted(&song, &siblings)
+                .map_err(|error| DatabaseError::Aborted(format!("{error:#}")))?;
+
+            Ok(song)
+        }));
+        // The same song can be added concurrently, after the check above
+        result.map_err(|error| self.duplicate_song(error, file.hash.as_deref()))
+    }
+
+    /// All the songs, with their album and artist.
+    pub(crate) fn songs(&self) -> Result<Vec<Song>, DatabaseError> {
+        with_connection!(self, conn => Ok(conn.songs(None)?))
+    }
+
+    /// Ids of all the songs.
+    pub(crate) fn song_ids(&self) -> Result<BTreeSet<i32>, DatabaseError> {
+        use schema::songs;
+
+        let ids = with_connection!(self, conn => songs::table
+            .select(songs::id)
+            .load::<i32>(&mut conn)?);
+        Ok(ids.into_iter().collect())
+    }
+
+    /// Songs of the album `album`, with their album and artists.
+    pub(crate) fn album_songs(&self, album: i32) -> Result<Vec<Song>, DatabaseError> {
+        with_connection!(self, conn => Ok(conn.songs(Some(album))?))
+    }
+
+    /// Name of the cover of an album in the artwork folder, `None` if the
+    /// album doesn't exist or has no cover.
+    pub(crate) fn album_cover(&self, id: i32) -> Result<Option<String>, DatabaseError> {
+        use schema::albums;
+
+        with_connection!(self, conn => {
+            let cover = albums::table
+                .filter(albums::id.eq(id))
+                .select(albums::cover)
+                .first::<Option<String>>(&mut conn)
+                .optional()?;
+            Ok(cover.flatten())
+        })
+    }
+
+    /// Name of the cover of the album of a song, `None` if the song doesn't
+    /// exist or its album has no cover.
+    pub(crate) fn song_cover(&self, id: i32) -> Result<Option<String>, DatabaseError> {
+        use schema::{albums, songs};
+
+        with_connection!(self, conn => {
+            let cover = songs::table
+                .inner_join(albums::table)
+                .filter(songs::id.eq(id))
+                .select(albums::cover)
+                .first::<Option<String>>(&mut conn)
+                .optional()?;
+            Ok(cover.flatten())
+        })
+    }
+
+    /// Covers among `covers` that no album uses.
+    pub(crate) fn unused_covers(&self, covers: &[String]) -> Result<Vec<String>, DatabaseError> {
+        use schema::albums;
+
+        if covers.is_empty() {
+            return Ok(Vec::new());
+        }
+        let used = with_connection!(self, conn => albums::table
+            .filter(albums::cover.eq_any(covers))
+            .select(albums::cover.assume_not_null())
+            .distinct()
+            .load::<String>(&mut conn)?);
+
+        Ok(covers
+            .iter()
+            .filter(|cover| !used.contains(cover))
+            .cloned()
+            .collect())
+    }
+
+    /// Files of all the songs, by song id.
+    pub(crate) fn song_files(&self) -> Result<Vec<(i32, SongFile)>, DatabaseError> {
+        use schema::songs;
+
+        let files = with_connection!(self, conn => songs::table
+            .order(songs::id)
+            .select((
+                songs::id,
+                songs::path,
+                songs::modified,
+                songs::hash,
+                songs::original_name,
+            ))
+            .load::<(i32, String, Option<i64>, Option<String>, Option<String>)>(&mut conn)?);
+
+        Ok(files
+            .into_iter()
+            .map(|(id, path, modified, hash, original_name)| {
+                (
+                    id,
+                    SongFile {
+                        path,
+                        modified,
+                        hash,
+                        original_name,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Update the location, modification time and hash of the file of a song.
+    ///
+    /// Returns `false` if there is no song with this id, fails with
+    /// [`DatabaseError::DuplicateSong`] if another song has the same hash.
+    pub(crate) fn update_song_file(&self, id: i32, file: &SongFile) -> Result<bool, DatabaseError> {
+        use schema::songs;
+
+        let result = with_connection!(self, conn => diesel::update(songs::table.filter(songs::id.eq(id)))
+            .set((
+                songs::path.eq(&file.path),
+                songs::modified.eq(file.modified),
+                songs::hash.eq(file.hash.as_deref()),
+            ))
+            .execute(&mut conn));
+        let count =
+            result.map_err(|error| self.duplicate_song(error.into(), file.hash.as_deref()))?;
+
+        Ok(count > 0)
+    }
+
+    /// Id of the song whose audio content has this `hash`, if any.
+    fn song_by_hash(&self, hash: &str) -> Result<Option<i32>, DatabaseError> {
+        use schema::songs;
+
+        with_connection!(self, conn => Ok(songs::table
+            .filter(songs::hash.eq(hash))
+            .select(songs::id)
+            .first::<i32>(&mut conn)
+            .optional()?))
+    }
+
+    /// Report unique constraint violations on the hash of a song as [`DatabaseError::DuplicateSong`].
+    fn duplicate_song(&self, error: DatabaseError, hash: Option<&str>) -> DatabaseError {
+        let unique_violation = matches!(
+            error,
+            DatabaseError::QueryError(diesel::result::Error::DatabaseError(
+                DatabaseErrorKind::UniqueViolation,
+                _
+            ))
+        );
+        match hash
+            .filter(|_| unique_violation)
+            .map(|hash| self.song_by_hash(hash))
+        {
+            Some(Ok(Some(id))) => DatabaseError::DuplicateSong(id),
+            _ => error,
+        }
+    }
+
+    /// Song with its album and artist, and the path of its file
+    /// relative to the library.
+    pub(crate) fn song(&self, id: i32) -> Result<Option<(Song, String)>, DatabaseError> {
+        use schema::{albums, artists, artists_albums, songs};
+
+        with_connection!(self, conn => {
+            let result = songs::table
+                .left_join(albums::table)
+                .filter(songs::id.eq(id))
+                .select((Songs::as_select(), Option::<Albums>::as_select()))
+                .first::<(Songs, Option<Albums>)>(&mut conn)
+                .optional()?;
+
+            let Some((song, album)) = result else {
+                return Ok(None);
+            };
+            let song_artists = conn
+                .songs_artists(Some(&BTreeSet::from([id])))?
+                .remove(&id)
+                .unwrap_or_default();
+
+            let album_artist = match &album {
+                Some(album) => artists::table
+                    .inner_join(artists_albums::table)
+                    .filter(artists_albums::albums_id.eq(album.id))
+                    .select(artists::name)
+                    .order(artists::id)
+                    .first::<String>(&mut conn)
+                    .optional()?,
+                None => None,
+            };
+
+            let path = song.path.clone();
+            Ok(Some((into_song(song, album, album_artist, song_artists), path)))
+        })
+    }
+
+    /// Update the metadata of a song, reusing its artists and album if they
+    /// already exist. Metadata missing from `changes` are kept, artists are
+    /// replaced as a whole. The cover of the album is replaced by `cover` if any.
+    ///
+    /// `on_updated` is called with the previous song, the updated one, the path of its file
+    /// and the other songs of its album whose details changed, right before the transaction
+    /// is committed. If it fails, nothing is updated.
+    /// Returns `None` if there is no song with this id.
+    pub(crate) fn update_song<F>(
+        &self,
+        id: i32,
+        changes: &Song,
+        cover: Option<&str>,
+        on_updated: F,
+    ) -> Result<Option<Song>, DatabaseError>
+    where
+        F: FnOnce(&Song, &Song, &str, &[Song]) -> anyhow::Result<()>,
+    {
+        use schema::{songs, songs_artists};
+
+        let Some((previous, path)) = self.song(id)? else {
+            return Ok(None);
+        };
+        let song = previous.merge(changes);
+
+        let artists_names = song.artists();
+        let album_artist = album_artist(&song);
+        let album_name = song.album();
+        let title = song.title();
+
+        let _catalog = lock_catalog();
+        with_connection!(self, conn => conn.transaction(|conn| {
+            let (album_id, album_changed) =
+                conn.album_id(&album_artist, &album_name, album_details(&song, cover))?;
+
+            diesel::update(songs::table.filter(songs::id.eq(id)))
+                .set((
+                    songs::albums_id.eq(album_id),
+                    songs::name.eq(&title),
+                    songs::genre.eq(song.genre()),
+                    songs::track.eq(song.track()),
+                    songs::disc.eq(song.disc()),
+                    songs::composer.eq(song.composer()),
+                    songs::comment.eq(song.comment()),
+                    songs::lyrics.eq(song.lyrics()),
+                ))
+                .execute(conn)?;
+            diesel::delete(songs_artists::table.filter(songs_artists::songs_id.eq(id)))
+                .execute(conn)?;
+            conn.link_artists(id, &artists_names)?;
+            conn.remove_orphans()?;
+
+            let mut song = song.clone();
+            song.set_album_id(album_id);
+            let siblings = conn.siblings(album_id, id, album_changed)?;
+            on_updated(&previous, &song, &path, &siblings)
+                .map_err(|error| DatabaseError::Aborted(format!("{error:#}")))?;
+
+            Ok(Some(song))
+        }))
+    }
+
+    /// Delete a song.
+    ///
+    /// `on_deleted` is called with the song and the path of its file right
+    /// before the transaction is committed. If it fails, nothing is deleted.
+    /// Returns `false` if there is no song with this id.
+    pub(crate) fn delete_song<F>(&self, id: i32, on_deleted: F) -> Result<bool, DatabaseError>
+    where
+        F: FnOnce(&Song, &str) -> anyhow::Result<()>,
+    {
+        use schema::songs;
+
+        let Some((song, path)) = self.song(id)? else {
+            return Ok(false);
         };
 
-        Ok(result.into_iter().next())
+        let _catalog = lock_catalog();
+        with_connection!(self, conn => conn.transaction(|conn| {
+            let count = diesel::delete(songs::table.filter(songs::id.eq(id))).execute(conn)?;
+            if count == 0 {
+                return Ok(false);
+            }
+            conn.remove_orphans()?;
+
+            on_deleted(&song, &path).map_err(|error| DatabaseError::Aborted(format!("{error:#}")))?;
+
+            Ok(true)
+        }))
+    }
+}
+
+/// Artist the album of a song is filed under, its first artist without album artist.
+fn album_artist(song: &Song) -> String {
+    song.album_artist()
+        .map(str::to_string)
+        .unwrap_or_else(|| song.artists().remove(0))
+}
+
+/// Metadata of a song describing its whole album.
+fn album_details<'a>(song: &Song, cover: Option<&'a str>) -> UpdateAlbums<'a> {
+    UpdateAlbums {
+        year: song.year(),
+        total_track: song.total_tracks(),
+        total_disc: song.total_discs(),
+        cover,
+    }
+}
+
+/// Song from its row, album and artists. Songs without artist are
+/// credited to the artist of their album.
+fn into_song(
+    song: Songs,
+    album: Option<Albums>,
+    album_artist: Option<String>,
+    artists: Vec<String>,
+) -> Song {
+    let artists = match (artists.is_empty(), &album_artist) {
+        (true, Some(album_artist)) => vec![album_artist.clone()],
+        _ => artists,
+    };
+    let (album_id, album, year, total_tracks, total_discs) = match album {
+        Some(album) => (
+            Some(album.id),
+            Some(album.name),
+            album.year,
+            album.total_track,
+            album.total_disc,
+        ),
+        None => (None, None, None, None, None),
+    };
+
+    server_lib::models::Song {
+        id: Some(song.id),
+        title: Some(song.name),
+        album,
+        album_id,
+        album_artist,
+        track: song.track,
+        total_tracks,
+        disc: song.disc,
+        total_discs,
+        artist: Song::join_artists(&artists),
+        artists: (!artists.is_empty()).then_some(artists),
+        composer: song.composer,
+        genre: song.genre,
+        year,
+        duration: Some(song.duration),
+        comment: song.comment,
+        lyrics: song.lyrics,
+        original_name: song.original_name,
+        score: None,
+    }
+    .into()
+}
+
+impl From<Users> for server_lib::models::User {
+    fn from(user: Users) -> Self {
+        server_lib::models::User {
+            id: Some(user.id()),
+            name: Some(user.user_id().to_string()),
+            password: None,
+            admin: Some(user.admin()),
+        }
+    }
+}
+
+impl From<Playlist> for server_lib::models::Playlist {
+    fn from(playlist: Playlist) -> Self {
+        server_lib::models::Playlist {
+            id: Some(Nullable::Present(playlist.id)),
+            name: Some(playlist.name),
+            owner: Some(playlist.owner),
+            shared: Some(playlist.shared),
+            query: playlist.query.map(Nullable::Present),
+            songs: playlist
+                .songs
+                .map(|songs| songs.into_iter().map(|song| song.into()).collect()),
+        }
+    }
+}
+
+fn hash_password(pwd: &str) -> Result<String, DatabaseError> {
+    password::hash(pwd)
+        .map_err(|error| DatabaseError::PasswordError(format!("Can't hash password : {error}")))
+}
+
+/// Report unique constraint violations on the user name as [`DatabaseError::AlreadyExists`].
+fn already_exists(error: diesel::result::Error, name: &str) -> DatabaseError {
+    match error {
+        diesel::result::Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+            DatabaseError::AlreadyExists(format!("User '{name}' already exists"))
+        }
+        error => error.into(),
     }
 }
 
@@ -1,4 +1,7 @@
-use super::schema::{albums, artists, artists_albums, users};
+use super::schema::{
+    albums, artists, artists_albums, playlists, playlists_songs, songs, songs_artists, users,
+    users_playlists,
+};
 use diesel::prelude::*;
 
 #[derive(Queryable, Identifiable, Selectable, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
@@ -24,10 +27,34 @@ pub struct ArtistsAlbums {
 #[derive(Queryable, Identifiable, Selectable, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
 #[diesel(table_name = albums)]
 pub(crate) struct Albums {
-    id: i32,
-    name: String,
-    year: Option<i32>,
-    total_track: Option<i32>,
+    pub(crate) id: i32,
+    pub(crate) name: String,
+    pub(crate) year: Option<i32>,
+    pub(crate) total_track: Option<i32>,
+    pub(crate) total_disc: Option<i32>,
+    pub(crate) cover: Option<String>,
+}
+
+#[derive(
+    Queryable, Identifiable, Selectable, Associations, Clone, Debug, Ord, PartialOrd, Eq, PartialEq,
+)]
+#[diesel(belongs_to(Albums))]
+#[diesel(table_name = songs)]
+pub(crate) struct Songs {
+    pub(crate) id: i32,
+    pub(crate) albums_id: Option<i32>,
+    pub(crate) name: String,
+    pub(crate) genre: Option<String>,
+    pub(crate) track: Option<i32>,
+    pub(crate) duration: i32,
+    pub(crate) path: String,
+    pub(crate) modified: Option<i64>,
+    pub(crate) hash: Option<String>,
+    pub(crate) disc: Option<i32>,
+    pub(crate) composer: Option<String>,
+    pub(crate) comment: Option<String>,
+    pub(crate) lyrics: Option<String>,
+    pub(crate) original_name: Option<String>,
 }
 
 #[derive(Queryable, Identifiable, Selectable, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
@@ -36,4 +63,145 @@ pub(crate) struct Users {
     id: i32,
     user_id: String,
     password: String,
+    admin: bool,
+}
+
+impl Users {
+    pub(crate) fn id(&self) -> i32 {
+        self.id
+    }
+
+    pub(crate) fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub(crate) fn password(&self) -> &str {
+        &self.password
+    }
+
+    pub(crate) fn admin(&self) -> bool {
+        self.admin
+    }
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = artists)]
+pub(crate) struct NewArtists<'a> {
+    pub(crate) name: &'a str,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = albums)]
+pub(crate) struct NewAlbums<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) year: Option<i32>,
+    pub(crate) total_track: Option<i32>,
+    pub(crate) total_disc: Option<i32>,
+    pub(crate) cover: Option<&'a str>,
+}
+
+#[derive(AsChangeset, Clone, Debug)]
+#[diesel(table_name = albums)]
+pub(crate) struct UpdateAlbums<'a> {
+    pub(crate) year: Option<i32>,
+    pub(crate) total_track: Option<i32>,
+    pub(crate) total_disc: Option<i32>,
+    pub(crate) cover: Option<&'a str>,
+}
+
+impl UpdateAlbums<'_> {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.year.is_none()
+            && self.total_track.is_none()
+            && self.total_disc.is_none()
+            && self.cover.is_none()
+    }
+
+    /// Whether these details change the year or the numbers of tracks and discs
+    /// of `album`, which are also those of its songs.
+    pub(crate) fn changes_songs(&self, album: &Albums) -> bool {
+        let changes = |value: Option<i32>, current| value.is_some() && value != current;
+        changes(self.year, album.year)
+            || changes(self.total_track, album.total_track)
+            || changes(self.total_disc, album.total_disc)
+    }
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = artists_albums)]
+pub(crate) struct NewArtistsAlbums {
+    pub(crate) artists_id: i32,
+    pub(crate) albums_id: i32,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = songs)]
+pub(crate) struct NewSongs<'a> {
+    pub(crate) albums_id: Option<i32>,
+    pub(crate) name: &'a str,
+    pub(crate) genre: Option<&'a str>,
+    pub(crate) track: Option<i32>,
+    pub(crate) duration: i32,
+    pub(crate) path: &'a str,
+    pub(crate) modified: Option<i64>,
+    pub(crate) hash: Option<&'a str>,
+    pub(crate) disc: Option<i32>,
+    pub(crate) composer: Option<&'a str>,
+    pub(crate) comment: Option<&'a str>,
+    pub(crate) lyrics: Option<&'a str>,
+    pub(crate) original_name: Option<&'a str>,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = songs_artists)]
+pub(crate) struct NewSongsArtists {
+    pub(crate) songs_id: i32,
+    pub(crate) artists_id: i32,
+    pub(crate) position: i32,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = playlists)]
+pub(crate) struct NewPlaylists<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) query: Option<&'a str>,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = playlists_songs)]
+pub(crate) struct NewPlaylistsSongs {
+    pub(crate) playlists_id: i32,
+    pub(crate) songs_id: i32,
+    pub(crate) added: i32,
+    pub(crate) position: i32,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = users_playlists)]
+pub(crate) struct NewUsersPlaylists {
+    pub(crate) users_id: i32,
+    pub(crate) playlists_id: i32,
+    pub(crate) shared: i32,
+}
+
+#[derive(Insertable, Clone, Debug)]
+#[diesel(table_name = users)]
+pub(crate) struct NewUsers<'a> {
+    pub(crate) user_id: &'a str,
+    pub(crate) password: &'a str,
+    pub(crate) admin: bool,
+}
+
+#[derive(AsChangeset, Clone, Debug)]
+#[diesel(table_name = users)]
+pub(crate) struct UpdateUsers<'a> {
+    pub(crate) user_id: Option<&'a str>,
+    pub(crate) password: Option<&'a str>,
+    pub(crate) admin: Option<bool>,
+}
+
+impl UpdateUsers<'_> {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.user_id.is_none() && self.password.is_none() && self.admin.is_none()
+    }
 }
@@ -0,0 +1,71 @@
+//! Password hashing.
+//!
+//! Passwords are stored as Argon2id PHC strings. Unsalted MD5 digests from the
+//! first versions are still accepted, so that they can be replaced by an Argon2id
+//! hash on the next successful login.
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use std::sync::OnceLock;
+use uuid::Uuid;
+
+/// Hash of a random password, verified when the user doesn't exist so that
+/// unknown and known users take the same time to be rejected.
+static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+
+/// Outcome of a password check.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Verification {
+    /// Password doesn't match
+    Invalid,
+    /// Password matches
+    Valid,
+    /// Password matches a legacy hash that must be replaced
+    Rehash,
+}
+
+/// Hash a password with Argon2id and a random salt.
+pub(crate) fn hash(password: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default().hash_password(password.as_bytes(), &salt)?;
+    Ok(hash.to_string())
+}
+
+/// Check a password against a stored hash.
+pub(crate) fn verify(password: &str, stored: &str) -> Verification {
+    if is_legacy(stored) {
+        let digest = format!("{:x}", md5::compute(password));
+        return if constant_time_eq(digest.as_bytes(), stored.to_lowercase().as_bytes()) {
+            Verification::Rehash
+        } else {
+            Verification::Invalid
+        };
+    }
+
+    match PasswordHash::new(stored) {
+        Ok(hash) => match Argon2::default().verify_password(password.as_bytes(), &hash) {
+            Ok(()) => Verification::Valid,
+            Err(_) => Verification::Invalid,
+        },
+        Err(_) => Verification::Invalid,
+    }
+}
+
+/// Spend the same time as a real check, when there is no hash to check against.
+pub(crate) fn verify_dummy(password: &str) {
+    let dummy = DUMMY_HASH.get_or_init(|| hash(&Uuid::new_v4().to_string()).unwrap_or_default());
+    let _ = verify(password, dummy);
+}
+
+/// MD5 hex digest as stored by the first versions.
+fn is_legacy(stored: &str) -> bool {
+    stored.len() == 32 && stored.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Compare without stopping at the first difference.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -0,0 +1,255 @@
+//! Queries run inside the transactions of several operations.
+use crate::database::browse::Album;
+use crate::database::model::{
+    Albums, NewAlbums, NewArtists, NewArtistsAlbums, NewSongsArtists, Songs, UpdateAlbums,
+};
+use crate::database::schema::{albums, artists, artists_albums, songs, songs_artists};
+use crate::database::{into_song, LastInsertId};
+use crate::library::Song;
+use diesel::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+
+/// Queries shared by the operations of [`Database`](crate::database::Database), implemented
+/// for each kind of connection.
+pub(super) trait Queries {
+    /// Id of the artist `artist`, created if it doesn't exist yet. Transactions creating
+    /// artists or albums hold the [catalog lock](super::lock_catalog).
+    fn artist_id(&mut self, artist: &str) -> QueryResult<i32>;
+
+    /// Id of the album `album` of `artist`, both are created if they don't exist yet.
+    /// The year, numbers of tracks and discs and cover of the album are updated when known.
+    /// Also tells if the details its songs share changed.
+    fn album_id(
+        &mut self,
+        artist: &str,
+        album: &str,
+        details: UpdateAlbums,
+    ) -> QueryResult<(i32, bool)>;
+
+    /// Link the song `song` to its `artists`, in order, creating the artists that don't exist yet.
+    fn link_artists(&mut self, song: i32, artists: &[String]) -> QueryResult<()>;
+
+    /// All the songs, or the songs of the album `album`, with their album and artists.
+    fn songs(&mut self, album: Option<i32>) -> QueryResult<Vec<Song>>;
+
+    /// Songs of the album `album` but `song`, if the details of the album they share `changed`.
+    fn siblings(&mut self, album: i32, song: i32, changed: bool) -> QueryResult<Vec<Song>> {
+        if !changed {
+            return Ok(Vec::new());
+        }
+        let songs = self.songs(Some(album))?;
+        Ok(songs
+            .into_iter()
+            .filter(|sibling| sibling.id() != Some(song))
+            .collect())
+    }
+
+    /// Artists of all the songs, or of the songs `songs`, in order, by song id.
+    fn songs_artists(
+        &mut self,
+        songs: Option<&BTreeSet<i32>>,
+    ) -> QueryResult<HashMap<i32, Vec<String>>>;
+
+    /// All the albums, or the albums `albums`, with the first artist of each album.
+    fn albums(&mut self, albums: Option<&BTreeSet<i32>>) -> QueryResult<Vec<Album>>;
+
+    /// Remove the albums without songs, then the artists without songs nor albums.
+    fn remove_orphans(&mut self) -> QueryResult<()>;
+}
+
+macro_rules! impl_queries {
+    ($connection:ty) => {
+        impl Queries for $connection {
+            fn artist_id(&mut self, artist: &str) -> QueryResult<i32> {
+                let artist_id = artists::table
+                    .filter(artists::name.eq(artist))
+                    .select(artists::id)
+                    .first::<i32>(self)
+                    .optional()?;
+                match artist_id {
+                    Some(artist_id) => Ok(artist_id),
+                    None => {
+                        diesel::insert_into(artists::table)
+                            .values(NewArtists { name: artist })
+                            .execute(self)?;
+                        self.last_insert_id()
+                    }
+                }
+            }
+
+            fn album_id(
+                &mut self,
+                artist: &str,
+                album: &str,
+                details: UpdateAlbums,
+            ) -> QueryResult<(i32, bool)> {
+                let artist_id = self.artist_id(artist)?;
+
+                let existing = albums::table
+                    .inner_join(artists_albums::table)
+                    .filter(albums::name.eq(album))
+                    .filter(artists_albums::artists_id.eq(artist_id))
+                    .select(Albums::as_select())
+                    .first::<Albums>(self)
+                    .optional()?;
+                match existing {
+                    Some(existing) => {
+                        if !details.is_empty() {
+                            diesel::update(albums::table.filter(albums::id.eq(existing.id)))
+                                .set(&details)
+                                .execute(self)?;
+                        }
+                        Ok((existing.id, details.changes_songs(&existing)))
+                    }
+                    None => {
+                        diesel::insert_into(albums::table)
+                            .values(NewAlbums {
+                                name: album,
+                                year: details.year,
+                                total_track: details.total_track,
+                                total_disc: details.total_disc,
+                                cover: details.cover,
+                            })
+                            .execute(self)?;
+                        let album_id = self.last_insert_id()?;
+                        diesel::insert_into(artists_albums::table)
+                            .values(NewArtistsAlbums {
+                                artists_id: artist_id,
+                                albums_id: album_id,
+                            })
+                            .execute(self)?;
+                        Ok((album_id, false))
+                    }
+                }
+            }
+
+            fn link_artists(&mut self, song: i32, artists: &[String]) -> QueryResult<()> {
+                for (position, artist) in artists.iter().enumerate() {
+                    let artist_id = self.artist_id(artist)?;
+                    diesel::insert_into(songs_artists::table)
+                        .values(NewSongsArtists {
+                            songs_id: song,
+                            artists_id: artist_id,
+                            position: position as i32,
+                        })
+                        .execute(self)?;
+                }
+                Ok(())
+            }
+
+            fn songs(&mut self, album: Option<i32>) -> QueryResult<Vec<Song>> {
+                let mut query = songs::table
+                    .left_join(albums::table)
+                    .order(songs::id)
+                    .select((Songs::as_select(), Option::<Albums>::as_select()))
+                    .into_boxed();
+                if let Some(album) = album {
+                    query = query.filter(songs::albums_id.eq(album));
+                }
+                let songs = query.load::<(Songs, Option<Albums>)>(self)?;
+                let mut songs_artists = match album {
+                    Some(_) => {
+                        self.songs_artists(Some(&songs.iter().map(|(song, _)| song.id).collect()))?
+                    }
+                    None => self.songs_artists(None)?,
+                };
+
+                // First artist of each album
+                let mut artists: HashMap<i32, String> = HashMap::new();
+                let mut query = artists_albums::table
+                    .inner_join(artists::table)
+                    .order(artists::id)
+                    .select((artists_albums::albums_id, artists::name))
+                    .into_boxed();
+                if let Some(album) = album {
+                    query = query.filter(artists_albums::albums_id.eq(album));
+                }
+                for (album, artist) in query.load::<(i32, String)>(self)? {
+                    artists.entry(album).or_insert(artist);
+                }
+
+                Ok(songs
+                    .into_iter()
+                    .map(|(song, album)| {
+                        let album_artist = album
+                            .as_ref()
+                            .and_then(|album| artists.get(&album.id).cloned());
+                        let song_artists = songs_artists.remove(&song.id).unwrap_or_default();
+                        into_song(song, album, album_artist, song_artists)
+                    })
+                    .collect())
+            }
+
+            fn songs_artists(
+                &mut self,
+                songs: Option<&BTreeSet<i32>>,
+            ) -> QueryResult<HashMap<i32, Vec<String>>> {
+                let mut query = songs_artists::table
+                    .inner_join(artists::table)
+                    .order((songs_artists::songs_id, songs_artists::position))
+                    .select((songs_artists::songs_id, artists::name))
+                    .into_boxed();
+                if let Some(songs) = songs {
+                    query = query.filter(songs_artists::songs_id.eq_any(songs));
+                }
+
+                let mut artists: HashMap<i32, Vec<String>> = HashMap::new();
+                for (song, artist) in query.load::<(i32, String)>(self)? {
+                    artists.entry(song).or_default().push(artist);
+                }
+                Ok(artists)
+            }
+
+            fn albums(&mut self, albums: Option<&BTreeSet<i32>>) -> QueryResult<Vec<Album>> {
+                let mut query = albums::table.select(Albums::as_select()).into_boxed();
+                if let Some(albums) = albums {
+                    query = query.filter(albums::id.eq_any(albums));
+                }
+                let albums = query.load::<Albums>(self)?;
+                let ids: BTreeSet<i32> = albums.iter().map(|album| album.id).collect();
+
+                let mut artists: HashMap<i32, (i32, String)> = HashMap::new();
+                for (album, artist, name) in artists_albums::table
+                    .inner_join(artists::table)
+                    .filter(artists_albums::albums_id.eq_any(ids))
+                    .order(artists::id)
+                    .select((artists_albums::albums_id, artists::id, artists::name))
+                    .load::<(i32, i32, String)>(self)?
+                {
+                    artists.entry(album).or_insert((artist, name));
+                }
+
+                Ok(albums
+                    .into_iter()
+                    .map(|album| {
+                        let artist = artists.remove(&album.id);
+                        Album::new(album, artist)
+                    })
+                    .collect())
+            }
+
+            fn remove_orphans(&mut self) -> QueryResult<()> {
+                // Links to artists are removed by cascade
+                let used = songs::table
+                    .filter(songs::albums_id.is_not_null())
+                    .select(songs::albums_id.assume_not_null());
+                diesel::delete(albums::table.filter(albums::id.ne_all(used))).execute(self)?;
+
+                let credited = songs_artists::table.select(songs_artists::artists_id);
+                let filed = artists_albums::table.select(artists_albums::artists_id);
+                diesel::delete(
+                    artists::table
+                        .filter(artists::id.ne_all(credited))
+                        .filter(artists::id.ne_all(filed)),
+                )
+                .execute(self)?;
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "mysql")]
+impl_queries!(MysqlConnection);
+#[cfg(feature = "postgres")]
+impl_queries!(PgConnection);
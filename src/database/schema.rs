@@ -6,6 +6,8 @@ diesel::table! {
         name -> Varchar,
         year -> Nullable<Integer>,
         total_track -> Nullable<Integer>,
+        total_disc -> Nullable<Integer>,
+        cover -> Nullable<Varchar>,
     }
 }
 
@@ -38,6 +40,7 @@ diesel::table! {
         playlists_id -> Integer,
         songs_id -> Integer,
         added -> Integer,
+        position -> Integer,
     }
 }
 
@@ -49,6 +52,23 @@ diesel::table! {
         genre -> Nullable<Varchar>,
         track -> Nullable<Integer>,
         duration -> Integer,
+        path -> Varchar,
+        modified -> Nullable<BigInt>,
+        hash -> Nullable<Varchar>,
+        disc -> Nullable<Integer>,
+        composer -> Nullable<Varchar>,
+        comment -> Nullable<Text>,
+        lyrics -> Nullable<Text>,
+        original_name -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    songs_artists (id) {
+        id -> Integer,
+        songs_id -> Integer,
+        artists_id -> Integer,
+        position -> Integer,
     }
 }
 
@@ -57,6 +77,7 @@ diesel::table! {
         id -> Integer,
         user_id -> Varchar,
         password -> Varchar,
+        admin -> Bool,
     }
 }
 
@@ -83,6 +104,8 @@ diesel::joinable!(artists_albums -> artists (artists_id));
 diesel::joinable!(playlists_songs -> playlists (playlists_id));
 diesel::joinable!(playlists_songs -> songs (songs_id));
 diesel::joinable!(songs -> albums (albums_id));
+diesel::joinable!(songs_artists -> artists (artists_id));
+diesel::joinable!(songs_artists -> songs (songs_id));
 diesel::joinable!(users_playlists -> playlists (playlists_id));
 diesel::joinable!(users_playlists -> users (users_id));
 diesel::joinable!(users_songs -> songs (songs_id));
@@ -95,6 +118,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     playlists,
     playlists_songs,
     songs,
+    songs_artists,
     users,
     users_playlists,
     users_songs,
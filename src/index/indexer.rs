@@ -0,0 +1,300 @@
+use crate::index::Visibility;
+use crate::METRIC_INDEXER_LAG;
+use log::{debug, error, warn};
+use metrics::histogram;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, SyncSender};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use tantivy::{Document, IndexReader, IndexWriter, TantivyError, Term};
+
+/// Change of the index.
+#[derive(Clone)]
+pub(super) enum Change {
+    Add(Document),
+    Delete(Term),
+    DeleteAll,
+}
+
+/// Changes sent together, they are committed at once.
+struct Changes {
+    changes: Vec<Change>,
+    /// Time the changes were sent at, to measure how long they take to be searchable.
+    sent: Instant,
+    /// Told the result of the commit of the changes, when waiting for their visibility.
+    committed: Option<SyncSender<tantivy::Result<()>>>,
+}
+
+/// Background task applying changes to the index and committing them by batches,
+/// once `batch_size` changes are pending or the oldest one waited for `commit_interval`.
+/// Changes that callers wait for are committed as soon as those already sent are applied.
+///
+/// Callers waiting for their changes are told when they can't be applied or committed.
+/// Other changes are applied again and committed with the next batch.
+pub(super) struct Indexer {
+    sender: Option<Sender<Changes>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Indexer {
+    pub(super) fn start(
+        writer: IndexWriter,
+        reader: IndexReader,
+        batch_size: usize,
+        commit_interval: Duration,
+    ) -> std::io::Result<Self> {
+        let (sender, receiver) = mpsc::channel();
+        let thread = std::thread::Builder::new()
+            .name("indexer".to_string())
+            .spawn(move || run(writer, reader, receiver, batch_size, commit_interval))?;
+
+        Ok(Self {
+            sender: Some(sender),
+            thread: Some(thread),
+        })
+    }
+
+    /// Send changes to the index, waiting for them to be committed if requested.
+    pub(super) fn send(&self, changes: Vec<Change>, visibility: Visibility) -> tantivy::Result<()> {
+        let (committed, result) = match visibility {
+            Visibility::Wait => {
+                let (sender, receiver) = mpsc::sync_channel(1);
+                (Some(sender), Some(receiver))
+            }
+            Visibility::Batched => (None, None),
+        };
+
+        self.sender
+            .as_ref()
+            .and_then(|sender| {
+                sender
+                    .send(Changes {
+                        changes,
+                        sent: Instant::now(),
+                        committed,
+                    })
+                    .ok()
+            })
+            .ok_or_else(stopped)?;
+
+        match result {
+            Some(result) => result.recv().map_err(|_| stopped())?,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Indexer {
+    /// Commit pending changes before leaving.
+    fn drop(&mut self) {
+        self.sender.take();
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                error!("Indexer panicked");
+            }
+        }
+    }
+}
+
+fn stopped() -> TantivyError {
+    TantivyError::ErrorInThread("Indexer stopped".to_string())
+}
+
+/// Changes applied by the index writer and not committed yet.
+#[derive(Default)]
+struct Batch {
+    changes: Vec<Changes>,
+    /// Number of changes.
+    count: usize,
+    /// Whether the writer lost the changes, they have to be applied again before committing.
+    lost: bool,
+}
+
+impl Batch {
+    /// Apply changes and add them to the batch, trying again once after rolling back the writer.
+    fn apply(&mut self, writer: &mut IndexWriter, changes: Changes) -> tantivy::Result<()> {
+        let mut result = apply(writer, changes.changes.clone());
+        if let Err(error) = &result {
+            // Changes applied before the error are rolled back with the batch
+            warn!("Can't apply changes to the index, trying again : {error:?}");
+            result = self
+                .restore(writer)
+                .and_then(|_| apply(writer, changes.changes.clone()));
+            if result.is_err() {
+                let _ = self.restore(writer);
+            }
+        }
+
+        let count = result?;
+        self.count += count;
+        self.changes.push(changes);
+        Ok(())
+    }
+
+    /// Roll the writer back to the last commit and apply the changes of the batch again.
+    fn restore(&mut self, writer: &mut IndexWriter) -> tantivy::Result<()> {
+        let result = writer.rollback().and_then(|_| {
+            for changes in &self.changes {
+                apply(writer, changes.changes.clone())?;
+            }
+            Ok(())
+        });
+        if let Err(error) = &result {
+            error!(
+                "Can't apply again {} changes to the index : {error:?}",
+                self.count
+            );
+        }
+        self.lost = result.is_err();
+        result
+    }
+
+    /// Whether callers wait for changes of the batch.
+    fn is_waited(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|changes| changes.committed.is_some())
+    }
+
+    /// Commit the batch and tell the callers waiting for it. When the commit fails, they
+    /// are told and their changes dropped, the other changes are kept to commit them again.
+    ///
+    /// Returns `false` if changes are kept.
+    fn commit(&mut self, writer: &mut IndexWriter, reader: &IndexReader) -> bool {
+        let restored = if self.lost {
+            self.restore(writer)
+        } else {
+            Ok(())
+        };
+        match restored.and_then(|_| writer.commit()) {
+            Ok(_) => {
+                debug!("{} changes committed to the index", self.count);
+                // Reloaded right away for waiting callers to search their changes
+                let result = reader.reload();
+                match &result {
+                    Ok(()) => {
+                        for changes in &self.changes {
+                            histogram!(METRIC_INDEXER_LAG, changes.sent.elapsed().as_secs_f64());
+                        }
+                    }
+                    Err(error) => error!("Can't reload the index : {error:?}"),
+                }
+                for changes in self.changes.drain(..) {
+                    if let Some(committed) = changes.committed {
+                        let _ = committed.send(result.clone());
+                    }
+                }
+                self.count = 0;
+                true
+            }
+            Err(error) => {
+                warn!(
+                    "Can't commit {} changes to the index : {error:?}",
+                    self.count
+                );
+                let (waited, kept) = self
+                    .changes
+                    .drain(..)
+                    .partition::<Vec<_>, _>(|changes| changes.committed.is_some());
+                for committed in waited.into_iter().filter_map(|changes| changes.committed) {
+                    let _ = committed.send(Err(error.clone()));
+                }
+                self.count = kept.iter().map(|changes| changes.changes.len()).sum();
+                self.changes = kept;
+                // The failed commit may have left some of the changes in the writer
+                let _ = self.restore(writer);
+                self.changes.is_empty()
+            }
+        }
+    }
+}
+
+fn run(
+    mut writer: IndexWriter,
+    reader: IndexReader,
+    receiver: Receiver<Changes>,
+    batch_size: usize,
+    commit_interval: Duration,
+) {
+    let mut batch = Batch::default();
+    // Time the oldest pending change has to be committed by
+    let mut deadline: Option<Instant> = None;
+
+    loop {
+        let received = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        let mut stopped = false;
+        let mut next = match received {
+            Ok(changes) => Some(changes),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => {
+                stopped = true;
+                None
+            }
+        };
+
+        // Apply every change already sent, they are committed together
+        while let Some(changes) = next.take() {
+            let committed = changes.committed.clone();
+            match batch.apply(&mut writer, changes) {
+                Ok(()) => {
+                    deadline.get_or_insert_with(|| Instant::now() + commit_interval);
+                }
+                Err(error) => {
+                    error!("Can't apply changes to the index : {error:?}");
+                    if let Some(committed) = committed {
+                        let _ = committed.send(Err(error));
+                    }
+                }
+            }
+            if batch.count < batch_size {
+                next = receiver.try_recv().ok();
+            }
+        }
+
+        let due = deadline
+            .map(|deadline| Instant::now() >= deadline)
+            .unwrap_or(false);
+        if stopped || due || batch.count >= batch_size || batch.is_waited() {
+            deadline = None;
+            if !batch.changes.is_empty() && !batch.commit(&mut writer, &reader) {
+                if stopped {
+                    error!("{} changes of the index are lost", batch.count);
+                } else {
+                    // Committed again with the next batch
+                    deadline = Some(Instant::now() + commit_interval);
+                }
+            }
+        }
+
+        if stopped {
+            break;
+        }
+    }
+
+    if let Err(error) = writer.wait_merging_threads() {
+        warn!("Can't wait for index merges : {error:?}");
+    }
+}
+
+/// Apply changes to the index, returns their number.
+fn apply(writer: &IndexWriter, changes: Vec<Change>) -> tantivy::Result<usize> {
+    let count = changes.len();
+    for change in changes {
+        match change {
+            Change::Add(document) => {
+                writer.add_document(document)?;
+            }
+            Change::Delete(term) => {
+                writer.delete_term(term);
+            }
+            Change::DeleteAll => {
+                writer.delete_all_documents()?;
+            }
+        }
+    }
+    Ok(count)
+}
@@ -0,0 +1,906 @@
+mod indexer;
+mod query;
+
+use crate::config::Indexing;
+use crate::database::Database;
+use crate::index::indexer::{Change, Indexer};
+use crate::index::query::DEFAULT_FIELDS;
+use crate::library::Song;
+use anyhow::Result;
+use log::{debug, info, warn};
+use std::collections::{BTreeSet, HashSet};
+use std::fs;
+use std::num::NonZeroUsize;
+use std::path::Path;
+use tantivy::collector::{
+    Collector, Count, DocSetCollector, FacetCollector, FacetCounts, MultiCollector, TopDocs,
+};
+use tantivy::directory::{ManagedDirectory, MmapDirectory};
+use tantivy::merge_policy::LogMergePolicy;
+use tantivy::query::{
+    BooleanQuery, BoostQuery, ConstScoreQuery, FuzzyTermQuery, Occur, Query, QueryParser,
+    QueryParserError, TermQuery,
+};
+use tantivy::schema::{
+    Cardinality, Facet, FacetOptions, IndexRecordOption, NumericOptions, Schema, SchemaBuilder,
+    TextFieldIndexing, TextOptions,
+};
+use tantivy::tokenizer::{RawTokenizer, SimpleTokenizer, TextAnalyzer};
+use tantivy::{
+    DocAddress, Document, Index, IndexReader, ReloadPolicy, Score, Searcher, SegmentReader,
+    TantivyError, Term,
+};
+use tantivy_analysis_contrib::commons::{EdgeNgramTokenFilter, TrimTokenFilter};
+use tantivy_analysis_contrib::icu::{Direction, ICUTransformTokenFilter};
+
+pub(crate) use query::{FacetFilter, SearchError, SearchSort};
+
+/// Declare the fields of the index, once : the [`PartitionFields`] enum and the list
+/// of all its variants used by [`init_index`] to build the schema.
+macro_rules! partition_fields {
+    ($($variant:ident => $name:literal, $kind:expr;)*) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub(crate) enum PartitionFields {
+            $($variant,)*
+        }
+
+        impl PartitionFields {
+            /// All the fields of the index.
+            const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            pub(crate) fn field_name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            fn kind(&self) -> FieldKind {
+                match self {
+                    $(Self::$variant => $kind,)*
+                }
+            }
+        }
+    };
+}
+
+partition_fields! {
+    Id => "id", FieldKind::Id;
+    Title => "title", FieldKind::Text(&[Folded, Keyword, Prefix]);
+    Artist => "artist", FieldKind::Facet(&[Folded, Keyword, Prefix]);
+    AlbumArtist => "album_artist", FieldKind::Text(&[Folded, Keyword, Prefix]);
+    Album => "album", FieldKind::Facet(&[Folded, Keyword, Prefix]);
+    AlbumId => "album_id", FieldKind::Number;
+    Composer => "composer", FieldKind::Text(&[Folded, Keyword]);
+    Genre => "genre", FieldKind::Facet(&[Folded, Keyword]);
+    Comment => "comment", FieldKind::Text(&[Folded]);
+    Lyrics => "lyrics", FieldKind::Text(&[Folded]);
+    OriginalName => "original_name", FieldKind::StoredText;
+    Track => "track", FieldKind::FastNumber;
+    TotalTracks => "total_tracks", FieldKind::Number;
+    Disc => "disc", FieldKind::Number;
+    TotalDiscs => "total_discs", FieldKind::Number;
+    Year => "year", FieldKind::FastNumber;
+    Decade => "decade", FieldKind::Facet(&[]);
+    Duration => "duration", FieldKind::FastNumber;
+}
+
+/// How a field is indexed.
+#[derive(Clone, Copy)]
+enum FieldKind {
+    /// Id of the song, indexed to replace or delete its document, and fast field to list the songs.
+    Id,
+    /// Text indexed with each analysis, in a field of its own. The field named
+    /// after the variant holds the folded text and stores the value.
+    Text(&'static [Analysis]),
+    /// Text also indexed as a facet in the `{name}_facet` field, to count and filter songs by value.
+    /// Without analysis, only the facet is indexed.
+    Facet(&'static [Analysis]),
+    /// Text, only stored.
+    StoredText,
+    /// Number, only stored.
+    Number,
+    /// Number, indexed and fast field to filter, sort and aggregate songs on it.
+    FastNumber,
+}
+
+/// Analysis of a text field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Analysis {
+    /// Words, transliterated to latin, without accents and in lower case.
+    Folded,
+    /// Whole value, folded like words, for exact matches like `artist_keyword:"miles davis"`
+    /// and suggestions.
+    Keyword,
+    /// Prefixes of the folded words, up to 20 characters, for suggestions matching words
+    /// in any order. Only indexed, the searched prefix has to be folded beforehand.
+    Prefix,
+}
+
+use Analysis::{Folded, Keyword, Prefix};
+
+/// Transliteration of any script to latin, then removal of accents and case.
+const FOLDING: &str = "Any-Latin; NFD; [:Nonspacing Mark:] Remove; Lower;  NFC";
+
+/// Memory used by the index writer, segments are written when it's full.
+const WRITER_MEMORY: usize = 50_000_000;
+
+/// Number of segments of similar size merged together.
+const MERGE_MIN_SEGMENTS: usize = 4;
+
+/// Longest indexed prefix of a word, longer words are also indexed as a whole.
+const MAX_PREFIX_LENGTH: usize = 20;
+
+/// Searches finding fewer songs also match words with typos.
+const FUZZY_FALLBACK_HITS: usize = 5;
+
+/// Number of values read from the term dictionary of each segment for each suggestion,
+/// the values of deleted songs stay there until their segment is merged.
+const SUGGESTION_CANDIDATES: usize = 4;
+
+/// Number of songs looked at for each suggestion having words starting with the words typed,
+/// songs often share their artist or album.
+const PREFIX_CANDIDATES: usize = 10;
+
+/// Number of values counted for each facet.
+const FACET_VALUES: usize = 20;
+
+/// Number of typos allowed in a word, none in short words.
+fn typos(word: &str) -> u8 {
+    match word.chars().count() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+impl Analysis {
+    /// Name of the field holding this analysis of a field.
+    fn field_name(&self, field: &PartitionFields) -> String {
+        match self {
+            Self::Folded => field.field_name().to_string(),
+            Self::Keyword => format!("{}_keyword", field.field_name()),
+            Self::Prefix => format!("{}_prefix", field.field_name()),
+        }
+    }
+
+    fn tokenizer_name(&self) -> &'static str {
+        match self {
+            Self::Folded => "folded",
+            Self::Keyword => "keyword",
+            Self::Prefix => "prefix",
+        }
+    }
+
+    fn text_options(&self) -> TextOptions {
+        match self {
+            Self::Folded => TextOptions::default()
+                .set_indexing_options(
+                    TextFieldIndexing::default()
+                        .set_tokenizer(self.tokenizer_name())
+                        .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+                )
+                .set_stored(),
+            Self::Keyword | Self::Prefix => TextOptions::default().set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(self.tokenizer_name())
+                    .set_index_option(IndexRecordOption::WithFreqs),
+            ),
+        }
+    }
+
+    fn analyzer(&self) -> TextAnalyzer {
+        let folding = ICUTransformTokenFilter {
+            compound_id: FOLDING.to_string(),
+            rules: None,
+            direction: Direction::Forward,
+        };
+        match self {
+            Self::Folded => TextAnalyzer::from(SimpleTokenizer).filter(folding),
+            Self::Keyword => TextAnalyzer::from(RawTokenizer)
+                .filter(TrimTokenFilter)
+                .filter(folding),
+            Self::Prefix => {
+                let prefixes = EdgeNgramTokenFilter::new(
+                    NonZeroUsize::MIN,
+                    NonZeroUsize::new(MAX_PREFIX_LENGTH),
+                    true,
+                )
+                .expect("Invalid prefix lengths");
+                TextAnalyzer::from(SimpleTokenizer)
+                    .filter(folding)
+                    .filter(prefixes)
+            }
+        }
+    }
+}
+
+impl PartitionFields {
+    /// Analyses of a text field, none for other fields.
+    fn analyses(&self) -> &'static [Analysis] {
+        match self.kind() {
+            FieldKind::Text(analyses) | FieldKind::Facet(analyses) => analyses,
+            FieldKind::Id | FieldKind::StoredText | FieldKind::Number | FieldKind::FastNumber => {
+                &[]
+            }
+        }
+    }
+
+    fn add_to_schema(&self, builder: &mut SchemaBuilder) {
+        let name = self.field_name();
+        match self.kind() {
+            FieldKind::Id => {
+                builder.add_i64_field(
+                    name,
+                    NumericOptions::default()
+                        .set_stored()
+                        .set_indexed()
+                        .set_fast(Cardinality::SingleValue),
+                );
+            }
+            FieldKind::Text(analyses) => {
+                for analysis in analyses {
+                    builder.add_text_field(&analysis.field_name(self), analysis.text_options());
+                }
+            }
+            FieldKind::Facet(analyses) => {
+                for analysis in analyses {
+                    builder.add_text_field(&analysis.field_name(self), analysis.text_options());
+                }
+                builder.add_facet_field(&self.facet_name(), FacetOptions::default());
+            }
+            FieldKind::StoredText => {
+                builder.add_text_field(name, TextOptions::default().set_stored());
+            }
+            FieldKind::Number => {
+                builder.add_i64_field(name, NumericOptions::default().set_stored());
+            }
+            FieldKind::FastNumber => {
+                builder.add_i64_field(
+                    name,
+                    NumericOptions::default()
+                        .set_stored()
+                        .set_indexed()
+                        .set_fast(Cardinality::SingleValue),
+                );
+            }
+        }
+    }
+
+    /// Add a value of a text field to a document, with each of its analyses.
+    pub(crate) fn add_text(&self, schema: &Schema, document: &mut Document, text: &str) {
+        let field = |name: &str| schema.get_field(name).unwrap();
+        let (analyses, facet) = match self.kind() {
+            FieldKind::Text(analyses) => (analyses, false),
+            FieldKind::Facet(analyses) => (analyses, true),
+            FieldKind::StoredText => {
+                document.add_text(field(self.field_name()), text);
+                return;
+            }
+            FieldKind::Id | FieldKind::Number | FieldKind::FastNumber => {
+                warn!("{} isn't a text field", self.field_name());
+                return;
+            }
+        };
+
+        for analysis in analyses {
+            document.add_text(field(&analysis.field_name(self)), text);
+        }
+        if facet && !text.trim().is_empty() {
+            document.add_facet(field(&self.facet_name()), Facet::from_path([text.trim()]));
+        }
+    }
+
+    /// Name of the field holding the values of a facet field.
+    fn facet_name(&self) -> String {
+        format!("{}_facet", self.field_name())
+    }
+}
+
+/// Result of a search : songs of the requested page and
+/// the total number of songs matching the query.
+pub(crate) struct SearchResult {
+    pub(crate) total: usize,
+    pub(crate) songs: Vec<Song>,
+    /// Facet counts, when requested.
+    pub(crate) facets: Option<SearchFacets>,
+}
+
+/// Number of songs found by a search for the most frequent values of each facet.
+pub(crate) struct SearchFacets {
+    pub(crate) genres: Vec<(String, u64)>,
+    /// Decades, by their first year.
+    pub(crate) decades: Vec<(String, u64)>,
+    pub(crate) artists: Vec<(String, u64)>,
+    pub(crate) albums: Vec<(String, u64)>,
+}
+
+/// Completions of the words typed so far, see [`TantivyIndex::suggest`].
+#[derive(Default)]
+pub(crate) struct Suggestions {
+    pub(crate) artists: Vec<String>,
+    pub(crate) albums: Vec<String>,
+    pub(crate) titles: Vec<String>,
+}
+
+/// When changes of the index become visible to searches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Visibility {
+    /// Changes are committed before returning, for callers reading their own writes.
+    Wait,
+    /// Changes are committed later, with other changes.
+    Batched,
+}
+
+pub(crate) struct TantivyIndex {
+    index: Index,
+    schema: Schema,
+    reader: IndexReader,
+    indexer: Indexer,
+    visibility: Visibility,
+}
+
+impl TantivyIndex {
+    /// Visibility of the changes made through the API, see [`Indexing::wait_for_visibility`].
+    pub(crate) fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    pub(crate) fn index(&self, song: Song, visibility: Visibility) -> tantivy::Result<()> {
+        let document = song.into_document(&self.schema);
+        self.indexer.send(vec![Change::Add(document)], visibility)
+    }
+
+    /// Replace the document of a song.
+    pub(crate) fn update(
+        &self,
+        id: i32,
+        song: Song,
+        visibility: Visibility,
+    ) -> tantivy::Result<()> {
+        let document = song.into_document(&self.schema);
+        self.indexer.send(
+            vec![Change::Delete(self.id_term(id)), Change::Add(document)],
+            visibility,
+        )
+    }
+
+    /// Replace the documents of `songs` at once.
+    pub(crate) fn update_all(
+        &self,
+        songs: Vec<Song>,
+        visibility: Visibility,
+    ) -> tantivy::Result<()> {
+        let mut changes = Vec::with_capacity(songs.len() * 2);
+        for song in songs {
+            if let Some(id) = song.id() {
+                changes.push(Change::Delete(self.id_term(id)));
+            }
+            changes.push(Change::Add(song.into_document(&self.schema)));
+        }
+        self.indexer.send(changes, visibility)
+    }
+
+    /// Replace all the documents by those of `songs`, waiting for them to be visible.
+    pub(crate) fn reindex(&self, songs: Vec<Song>) -> tantivy::Result<()> {
+        let mut changes = Vec::with_capacity(songs.len() + 1);
+        changes.push(Change::DeleteAll);
+        changes.extend(
+            songs
+                .into_iter()
+                .map(|song| Change::Add(song.into_document(&self.schema))),
+        );
+        self.indexer.send(changes, Visibility::Wait)
+    }
+
+    /// Index the songs of the database again when the index doesn't match it, like when
+    /// it has been rebuilt or changes couldn't be committed before the server stopped.
+    pub(crate) fn reconcile(&self, database: &Database) -> Result<()> {
+        let ids = database.song_ids()?;
+        let indexed = self.ids()?;
+        if ids != indexed {
+            info!(
+                "{} songs indexed for {} in the database, indexing them again",
+                indexed.len(),
+                ids.len()
+            );
+            self.reindex(database.songs()?)?;
+        }
+        Ok(())
+    }
+
+    /// Ids of the indexed songs.
+    fn ids(&self) -> tantivy::Result<BTreeSet<i32>> {
+        let field = self
+            .schema
+            .get_field(PartitionFields::Id.field_name())
+            .unwrap();
+        let mut ids = BTreeSet::new();
+        for segment_reader in self.reader.searcher().segment_readers() {
+            let values = segment_reader.fast_fields().i64(field)?;
+            ids.extend(
+                segment_reader
+                    .doc_ids_alive()
+                    .map(|doc| values.get_val(doc) as i32),
+            );
+        }
+        Ok(ids)
+    }
+
+    pub(crate) fn delete(&self, id: i32, visibility: Visibility) -> tantivy::Result<()> {
+        self.indexer
+            .send(vec![Change::Delete(self.id_term(id))], visibility)
+    }
+
+    /// Searcher of the last committed changes.
+    fn searcher(&self) -> Searcher {
+        self.reader.searcher()
+    }
+
+    fn id_term(&self, id: i32) -> Term {
+        let field = self
+            .schema
+            .get_field(PartitionFields::Id.field_name())
+            .unwrap();
+        Term::from_field_i64(field, id as i64)
+    }
+
+    fn parse_query(&self, query: &str) -> Result<Box<dyn Query>, QueryParserError> {
+        let field = |field: &PartitionFields| self.schema.get_field(field.field_name()).unwrap();
+        let fields = DEFAULT_FIELDS.iter().map(|(name, _)| field(name)).collect();
+
+        let mut query_parser = QueryParser::for_index(&self.index, fields);
+        for (name, boost) in DEFAULT_FIELDS {
+            query_parser.set_field_boost(field(name), *boost);
+        }
+        query_parser.parse_query(query)
+    }
+
+    /// Whole text, folded like the values of the keyword fields.
+    fn fold_keyword(&self, text: &str) -> String {
+        let mut keyword = String::new();
+        if let Some(analyzer) = self.index.tokenizers().get(Keyword.tokenizer_name()) {
+            let mut tokens = analyzer.token_stream(text);
+            while tokens.advance() {
+                keyword.push_str(&tokens.token().text);
+            }
+        }
+        keyword
+    }
+
+    /// Words of a text analyzed like the default fields.
+    fn fold(&self, text: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut tokens = Folded.analyzer().token_stream(text);
+        while tokens.advance() {
+            words.push(tokens.token().text.clone());
+        }
+        words
+    }
+
+    /// Also match the words of a query with typos when it finds few songs,
+    /// if it's only made of words searched in the default fields.
+    fn with_fuzzy_fallback(
+        &self,
+        searcher: &Searcher,
+        text: &str,
+        query: Box<dyn Query>,
+    ) -> tantivy::Result<Box<dyn Query>> {
+        if searcher.search(&query, &Count)? >= FUZZY_FALLBACK_HITS {
+            return Ok(query);
+        }
+        let Some(words) = query::free_words(text) else {
+            return Ok(query);
+        };
+
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = vec![(Occur::Should, query)];
+        for word in words.iter().flat_map(|word| self.fold(word)) {
+            let distance = typos(&word);
+            if distance == 0 {
+                continue;
+            }
+            for (field, boost) in DEFAULT_FIELDS {
+                let field = self.schema.get_field(field.field_name()).unwrap();
+                let term = Term::from_field_text(field, &word);
+                let fuzzy = Box::new(FuzzyTermQuery::new(term, distance, true));
+                clauses.push((Occur::Should, Box::new(BoostQuery::new(fuzzy, *boost))));
+            }
+        }
+        debug!("Few songs match \"{text}\", also matching words with typos");
+        Ok(Box::new(BooleanQuery::new(clauses)))
+    }
+
+    /// Query of a search, narrowed to the songs having the values of the facet filters.
+    fn search_query(
+        &self,
+        searcher: &Searcher,
+        text: &str,
+        filters: &[FacetFilter],
+    ) -> Result<Box<dyn Query>, SearchError> {
+        let query = self
+            .parse_query(text)
+            .map_err(|error| SearchError::invalid_query(text, error))?;
+        let query = self.with_fuzzy_fallback(searcher, text, query)?;
+        if filters.is_empty() {
+            return Ok(query);
+        }
+
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = vec![(Occur::Must, query)];
+        for filter in filters {
+            let field = self.schema.get_field(&filter.field.facet_name()).unwrap();
+            let term = Term::from_facet(field, &Facet::from_path([filter.value.as_str()]));
+            // Filters don't change the score of the songs
+            let query = TermQuery::new(term, IndexRecordOption::Basic);
+            let query = ConstScoreQuery::new(Box::new(query), 0.0);
+            clauses.push((Occur::Must, Box::new(query)));
+        }
+        Ok(Box::new(BooleanQuery::new(clauses)))
+    }
+
+    /// Tell if a query can be parsed, without running it.
+    pub(crate) fn is_valid_query(&self, query: &str) -> bool {
+        match self.parse_query(query) {
+            Ok(_) => true,
+            Err(error) => {
+                debug!("Invalid query \"{query}\" : {error:?}");
+                false
+            }
+        }
+    }
+
+    pub(crate) fn search(
+        &self,
+        query: &str,
+        filters: &[FacetFilter],
+        sort: SearchSort,
+        offset: usize,
+        limit: usize,
+        facets: bool,
+    ) -> Result<SearchResult, SearchError> {
+        let searcher = self.searcher();
+        let query = self.search_query(&searcher, query, filters)?;
+
+        // TopDocs panics with a limit of 0, only count hits in that case.
+        let top_doc = (limit > 0).then(|| TopDocs::with_limit(limit).and_offset(offset));
+        let (result, total, facets): (Vec<(Score, DocAddress)>, usize, Option<SearchFacets>) =
+            match sort {
+                SearchSort::Relevance => self.collect(&searcher, &query, top_doc, facets)?,
+                SearchSort::Ascending(field) | SearchSort::Descending(field) => {
+                    let field = self.schema.get_field(field.field_name()).unwrap();
+                    let descending = matches!(sort, SearchSort::Descending(_));
+                    // Songs are ranked by value then score, top docs keep the highest ranks.
+                    // Missing values are read as 0, those songs come last in both orders.
+                    let top_doc = top_doc.map(|top_doc| {
+                        top_doc.tweak_score(move |segment_reader: &SegmentReader| {
+                            let values = segment_reader
+                                .fast_fields()
+                                .i64(field)
+                                .expect("Songs are only sorted by fast fields");
+                            move |doc, score| {
+                                let value = values.get_val(doc);
+                                let rank = if descending {
+                                    value
+                                } else {
+                                    value.saturating_neg()
+                                };
+                                ((value != 0, rank), score)
+                            }
+                        })
+                    });
+                    let (result, total, facets) =
+                        self.collect(&searcher, &query, top_doc, facets)?;
+                    let result = result
+                        .into_iter()
+                        .map(|((_, score), doc_address)| (score, doc_address))
+                        .collect();
+                    (result, total, facets)
+                }
+            };
+
+        let mut songs = Vec::with_capacity(result.len());
+        for (score, doc_address) in result {
+            let retrieved_doc = searcher.doc(doc_address)?;
+            debug!("{score} : {}", self.schema.to_json(&retrieved_doc));
+            let mut song = Song::from_document(&retrieved_doc, &self.schema);
+            song.set_score(score);
+            songs.push(song);
+        }
+
+        Ok(SearchResult {
+            total,
+            songs,
+            facets,
+        })
+    }
+
+    /// Collect the top songs of a search, their total and the most frequent values of
+    /// each facet if requested, in a single pass over the matching songs.
+    fn collect<C: Collector>(
+        &self,
+        searcher: &Searcher,
+        query: &dyn Query,
+        top_doc: Option<C>,
+        facets: bool,
+    ) -> tantivy::Result<(C::Fruit, usize, Option<SearchFacets>)>
+    where
+        C::Fruit: Default,
+    {
+        let facet = |field: PartitionFields| {
+            let field = self.schema.get_field(&field.facet_name()).unwrap();
+            let mut collector = FacetCollector::for_field(field);
+            collector.add_facet(Facet::root());
+            collector
+        };
+        let mut collectors = MultiCollector::new();
+        let top_doc = collectors.add_collector(top_doc);
+        let total = collectors.add_collector(Count);
+        let facets = facets.then(|| {
+            [
+                PartitionFields::Genre,
+                PartitionFields::Decade,
+                PartitionFields::Artist,
+                PartitionFields::Album,
+            ]
+            .map(|field| collectors.add_collector(facet(field)))
+        });
+        let mut fruits = searcher.search(query, &collectors)?;
+
+        let top = |counts: FacetCounts| -> Vec<(String, u64)> {
+            counts
+                .top_k(Facet::root(), FACET_VALUES)
+                .into_iter()
+                .map(|(facet, count)| (facet.to_path().concat(), count))
+                .collect()
+        };
+        let facets = facets.map(|[genres, decades, artists, albums]| SearchFacets {
+            genres: top(genres.extract(&mut fruits)),
+            decades: top(decades.extract(&mut fruits)),
+            artists: top(artists.extract(&mut fruits)),
+            albums: top(albums.extract(&mut fruits)),
+        });
+        Ok((
+            top_doc.extract(&mut fruits).unwrap_or_default(),
+            total.extract(&mut fruits),
+            facets,
+        ))
+    }
+
+    /// Artists, albums and titles starting with the text typed so far, whose last word is
+    /// usually incomplete, in alphabetical order. Values are read from the term dictionaries
+    /// of the keyword fields, that hold each distinct value once. When there are fewer than
+    /// `limit`, they are followed by values having words starting with each of the words typed.
+    pub(crate) fn suggest(&self, text: &str, limit: usize) -> tantivy::Result<Suggestions> {
+        let mut suggestions = Suggestions::default();
+        let prefix = self.fold_keyword(text);
+        if prefix.is_empty() || limit == 0 {
+            return Ok(suggestions);
+        }
+        let prefixes: Vec<String> = self
+            .fold(text)
+            .into_iter()
+            .map(|word| word.chars().take(MAX_PREFIX_LENGTH).collect())
+            .collect();
+
+        let searcher = self.searcher();
+        let fields = [
+            (PartitionFields::Artist, &mut suggestions.artists),
+            (PartitionFields::Album, &mut suggestions.albums),
+            (PartitionFields::Title, &mut suggestions.titles),
+        ];
+        for (field, values) in fields {
+            let keyword_field = self.schema.get_field(&Keyword.field_name(&field)).unwrap();
+            let mut keywords = BTreeSet::new();
+            for segment_reader in searcher.segment_readers() {
+                let inverted_index = segment_reader.inverted_index(keyword_field)?;
+                let mut terms = inverted_index
+                    .terms()
+                    .range()
+                    .ge(prefix.as_bytes())
+                    .into_stream()?;
+                let mut count = 0;
+                while count < limit * SUGGESTION_CANDIDATES && terms.advance() {
+                    let Ok(keyword) = std::str::from_utf8(terms.key()) else {
+                        continue;
+                    };
+                    if !keyword.starts_with(prefix.as_str()) {
+                        break;
+                    }
+                    keywords.insert(keyword.to_string());
+                    count += 1;
+                }
+            }
+
+            // Values as stored, read from a song having them, that isn't deleted
+            let stored_field = self.schema.get_field(field.field_name()).unwrap();
+            for keyword in keywords {
+                if values.len() >= limit {
+                    break;
+                }
+                let term = Term::from_field_text(keyword_field, &keyword);
+                let query = TermQuery::new(term, IndexRecordOption::Basic);
+                let Some((_, doc_address)) =
+                    searcher.search(&query, &TopDocs::with_limit(1))?.pop()
+                else {
+                    continue;
+                };
+                // Songs have several artists, only suggest the one found
+                let document = searcher.doc(doc_address)?;
+                let value = document
+                    .get_all(stored_field)
+                    .filter_map(|value| value.as_text())
+                    .find(|value| self.fold_keyword(value) == keyword)
+                    .map(str::to_string);
+                values.extend(value);
+            }
+
+            if values.len() < limit {
+                self.suggest_by_words(&searcher, field, &prefixes, limit, values)?;
+            }
+        }
+
+        Ok(suggestions)
+    }
+
+    /// Add to `values`, up to `limit`, the values of `field` having words starting with each
+    /// of `prefixes`, in any order. Only looks into the prefix field of `field`.
+    fn suggest_by_words(
+        &self,
+        searcher: &Searcher,
+        field: PartitionFields,
+        prefixes: &[String],
+        limit: usize,
+        values: &mut Vec<String>,
+    ) -> tantivy::Result<()> {
+        if prefixes.is_empty() {
+            return Ok(());
+        }
+        let prefix_field = self.schema.get_field(&Prefix.field_name(&field)).unwrap();
+        let query = BooleanQuery::new(
+            prefixes
+                .iter()
+                .map(|prefix| -> (Occur, Box<dyn Query>) {
+                    let term = Term::from_field_text(prefix_field, prefix);
+                    let query = TermQuery::new(term, IndexRecordOption::Basic);
+                    (Occur::Must, Box::new(query))
+                })
+                .collect(),
+        );
+        let top_docs = TopDocs::with_limit(limit * PREFIX_CANDIDATES);
+
+        // Songs have several artists, only suggest those matching the prefixes
+        let stored_field = self.schema.get_field(field.field_name()).unwrap();
+        let mut suggested: HashSet<String> = values
+            .iter()
+            .map(|value| self.fold_keyword(value))
+            .collect();
+        for (_, doc_address) in searcher.search(&query, &top_docs)? {
+            let document = searcher.doc(doc_address)?;
+            for value in document
+                .get_all(stored_field)
+                .filter_map(|value| value.as_text())
+            {
+                let words = self.fold(value);
+                let matches = prefixes
+                    .iter()
+                    .all(|prefix| words.iter().any(|word| word.starts_with(prefix.as_str())));
+                if matches && values.len() < limit && suggested.insert(self.fold_keyword(value)) {
+                    values.push(value.to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// All the songs matching a query, without score nor particular order.
+    pub(crate) fn search_all(&self, query: &str) -> tantivy::Result<Vec<Song>> {
+        let query = self.parse_query(query)?;
+
+        let searcher = self.searcher();
+        let result = searcher.search(&query, &DocSetCollector)?;
+
+        let mut songs = Vec::with_capacity(result.len());
+        for doc_address in result {
+            let retrieved_doc = searcher.doc(doc_address)?;
+            songs.push(Song::from_document(&retrieved_doc, &self.schema));
+        }
+
+        Ok(songs)
+    }
+}
+
+pub(crate) fn init_index(config: &Indexing) -> Result<TantivyIndex> {
+    let path = config.path();
+    let path = path.as_path();
+    if let Err(error) = fs::create_dir_all(path) {
+        warn!("{error:?}");
+    }
+
+    let mut builder = Schema::builder();
+    for field in PartitionFields::ALL {
+        field.add_to_schema(&mut builder);
+    }
+    let schema = builder.build();
+
+    // Songs are indexed again from the database when the index doesn't match it,
+    // so an index built with another schema is simply dropped.
+    let index = match open_or_create(path, schema.clone()) {
+        Err(TantivyError::SchemaError(error)) => {
+            warn!("{error}, rebuilding the index in {}", path.display());
+            remove_index_files(path)?;
+            open_or_create(path, schema)?
+        }
+        result => result?,
+    };
+
+    // Tokenizers of the analyses used by the fields
+    let mut analyses: Vec<Analysis> = Vec::new();
+    for analysis in PartitionFields::ALL
+        .iter()
+        .flat_map(|field| field.analyses())
+    {
+        if !analyses.contains(analysis) {
+            index
+                .tokenizers()
+                .register(analysis.tokenizer_name(), analysis.analyzer());
+            analyses.push(*analysis);
+        }
+    }
+
+    // Each commit writes a segment, merge those of a few batches together
+    let mut merge_policy = LogMergePolicy::default();
+    merge_policy.set_min_layer_size(u32::try_from(config.batch_size()).unwrap_or(u32::MAX));
+    merge_policy.set_min_num_segments(MERGE_MIN_SEGMENTS);
+    let writer = index.writer(WRITER_MEMORY)?;
+    writer.set_merge_policy(Box::new(merge_policy));
+
+    // Searches share a reader, reloaded when changes are committed
+    let reader = index
+        .reader_builder()
+        .reload_policy(ReloadPolicy::OnCommit)
+        .try_into()?;
+
+    let indexer = Indexer::start(
+        writer,
+        reader.clone(),
+        config.batch_size(),
+        config.commit_interval(),
+    )?;
+
+    let schema = index.schema();
+    let visibility = if config.wait_for_visibility() {
+        Visibility::Wait
+    } else {
+        Visibility::Batched
+    };
+
+    Ok(TantivyIndex {
+        index,
+        schema,
+        reader,
+        indexer,
+        visibility,
+    })
+}
+
+/// Remove the files of an index, its meta files and segments, other files of the folder are kept.
+fn remove_index_files(path: &Path) -> std::io::Result<()> {
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        // Files of a segment are named after its id, 32 hexadecimal digits
+        let segment = name
+            .split_once('.')
+            .map(|(id, _)| id.len() == 32 && id.chars().all(|c| c.is_ascii_hexdigit()))
+            .unwrap_or(false);
+        if segment || name == "meta.json" || name == ".managed.json" {
+            fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+fn open_or_create(path: &Path, schema: Schema) -> tantivy::Result<Index> {
+    let mmap_directory = MmapDirectory::open(path)?;
+    let wrapper = ManagedDirectory::wrap(Box::new(mmap_directory))?;
+    Index::open_or_create(wrapper, schema)
+}
@@ -0,0 +1,174 @@
+use crate::index::{FieldKind, PartitionFields};
+use std::str::FromStr;
+use tantivy::query::QueryParserError;
+use tantivy::{Score, TantivyError};
+use tantivy_query_grammar::{Occur, UserInputAst, UserInputLeaf, UserInputLiteral};
+use thiserror::Error;
+
+/// Fields searched by the terms of a query without field, with their boost : a match in
+/// the title counts more than a match in the artists, itself more than one in the album.
+///
+/// Comment and lyrics are only searched when explicitly requested.
+pub(super) const DEFAULT_FIELDS: &[(PartitionFields, Score)] = &[
+    (PartitionFields::Title, 3.0),
+    (PartitionFields::Artist, 2.0),
+    (PartitionFields::AlbumArtist, 2.0),
+    (PartitionFields::Album, 1.5),
+    (PartitionFields::Composer, 1.0),
+    (PartitionFields::Genre, 1.0),
+];
+
+/// Words of a query only made of words searched in the default fields, like
+/// `bethoven sonata`. `None` if it has fields, ranges, exclusions or boosts.
+pub(super) fn free_words(query: &str) -> Option<Vec<String>> {
+    fn collect(ast: UserInputAst, words: &mut Vec<String>) -> Option<()> {
+        match ast {
+            UserInputAst::Clause(clauses) => {
+                for (occur, ast) in clauses {
+                    if occur == Some(Occur::MustNot) {
+                        return None;
+                    }
+                    collect(ast, words)?;
+                }
+                Some(())
+            }
+            UserInputAst::Leaf(leaf) => match *leaf {
+                UserInputLeaf::Literal(UserInputLiteral {
+                    field_name: None,
+                    phrase,
+                    ..
+                }) => {
+                    words.push(phrase);
+                    Some(())
+                }
+                _ => None,
+            },
+            UserInputAst::Boost(..) => None,
+        }
+    }
+
+    let ast = tantivy_query_grammar::parse_query(query).ok()?;
+    let mut words = Vec::new();
+    collect(ast, &mut words)?;
+    Some(words)
+}
+
+#[derive(Error, Debug)]
+pub(crate) enum SearchError {
+    /// The query or the sort order requested is wrong, `code` tells what kind of error it is.
+    #[error("{message}")]
+    InvalidQuery { code: &'static str, message: String },
+    #[error(transparent)]
+    Index(#[from] TantivyError),
+}
+
+impl SearchError {
+    pub(super) fn invalid_query(query: &str, error: QueryParserError) -> Self {
+        let (code, message) = match error {
+            QueryParserError::SyntaxError(_) => {
+                ("syntax", format!("Invalid syntax in \"{query}\""))
+            }
+            QueryParserError::RangeMustNotHavePhrase => {
+                ("syntax", "Bounds of a range can't be phrases".to_string())
+            }
+            QueryParserError::FieldDoesNotExist(field) => {
+                ("unknown_field", format!("Unknown field '{field}'"))
+            }
+            QueryParserError::FieldNotIndexed(field) => (
+                "unknown_field",
+                format!("Field '{field}' can't be searched"),
+            ),
+            QueryParserError::ExpectedInt(_)
+            | QueryParserError::ExpectedFloat(_)
+            | QueryParserError::ExpectedBool(_)
+            | QueryParserError::ExpectedBase64(_)
+            | QueryParserError::DateFormatError(_)
+            | QueryParserError::FacetFormatError(_)
+            | QueryParserError::IpFormatError(_) => {
+                ("invalid_value", format!("Invalid value in \"{query}\""))
+            }
+            QueryParserError::AllButQueryForbidden => (
+                "only_exclusions",
+                "A query can't only exclude songs".to_string(),
+            ),
+            error => ("unsupported", error.to_string()),
+        };
+        Self::InvalidQuery { code, message }
+    }
+}
+
+/// Order of the songs found by a search.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum SearchSort {
+    /// Best matches first.
+    #[default]
+    Relevance,
+    /// Lowest values of a fast field first, then best matches, songs without value last.
+    Ascending(PartitionFields),
+    /// Highest values of a fast field first, then best matches, songs without value last.
+    Descending(PartitionFields),
+}
+
+impl FromStr for SearchSort {
+    type Err = SearchError;
+
+    /// Parse `relevance` or the name of a fast field, prefixed by `-` for descending order.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value == "relevance" {
+            return Ok(Self::Relevance);
+        }
+
+        let (name, descending) = match value.strip_prefix('-') {
+            Some(name) => (name, true),
+            None => (value, false),
+        };
+        PartitionFields::ALL
+            .iter()
+            .find(|field| {
+                field.field_name() == name && matches!(field.kind(), FieldKind::FastNumber)
+            })
+            .map(|field| {
+                if descending {
+                    Self::Descending(*field)
+                } else {
+                    Self::Ascending(*field)
+                }
+            })
+            .ok_or_else(|| SearchError::InvalidQuery {
+                code: "unknown_sort",
+                message: format!("Unknown sort order '{value}'"),
+            })
+    }
+}
+
+/// Value a facet of the songs found by a search must have, like `genre:Jazz`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FacetFilter {
+    pub(super) field: PartitionFields,
+    pub(super) value: String,
+}
+
+impl FromStr for FacetFilter {
+    type Err = SearchError;
+
+    /// Parse the name of a facet field and a value separated by `:`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value
+            .split_once(':')
+            .and_then(|(name, value)| {
+                PartitionFields::ALL
+                    .iter()
+                    .find(|field| {
+                        field.field_name() == name && matches!(field.kind(), FieldKind::Facet(_))
+                    })
+                    .map(|field| Self {
+                        field: *field,
+                        value: value.trim().to_string(),
+                    })
+            })
+            .ok_or_else(|| SearchError::InvalidQuery {
+                code: "unknown_facet",
+                message: format!("Unknown facet '{value}'"),
+            })
+    }
+}
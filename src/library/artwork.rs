@@ -0,0 +1,164 @@
+use crate::database::Database;
+use crate::library::Library;
+use anyhow::Result;
+use audiotags::{MimeType, Tag};
+use image::{DynamicImage, ImageFormat, ImageOutputFormat};
+use log::{debug, warn};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Images of a folder used as cover of its audio files without one, by order of preference.
+const SIDECAR_FILES: [&str; 6] = [
+    "cover.jpg",
+    "cover.png",
+    "folder.jpg",
+    "folder.png",
+    "front.jpg",
+    "front.png",
+];
+
+/// Quality of resized JPEG covers.
+const JPEG_QUALITY: u8 = 85;
+
+impl Library {
+    /// Store the cover of an audio file in the artwork folder, read from its tags or,
+    /// failing that, from an image of its folder like `folder.jpg`. Covers are named
+    /// after the hash of their content, so a cover shared by songs is stored once.
+    ///
+    /// Returns the name of the stored cover, `None` if there is none or it can't be stored.
+    pub(crate) fn store_cover(&self, file: &Path) -> Option<String> {
+        let (data, extension) = embedded_cover(file).or_else(|| {
+            file.parent()
+                .filter(|folder| *folder != self.temporary)
+                .and_then(sidecar_cover)
+        })?;
+
+        let name = format!("{:x}.{extension}", md5::compute(&data));
+        let path = self.artwork.join(&name);
+        if path.exists() {
+            return Some(name);
+        }
+
+        // Written aside then renamed, a cover is never served half written.
+        let partial = self.artwork.join(format!("{}.partial", Uuid::new_v4()));
+        match std::fs::write(&partial, &data).and_then(|_| std::fs::rename(&partial, &path)) {
+            Ok(()) => {
+                debug!("Cover of {} stored as {name}", file.display());
+                Some(name)
+            }
+            Err(error) => {
+                warn!("Can't store cover of {} : {error:?}", file.display());
+                if let Err(error) = std::fs::remove_file(&partial) {
+                    debug!("Can't remove {} : {error:?}", partial.display());
+                }
+                None
+            }
+        }
+    }
+
+    /// Remove `cover` from the artwork folder if no album uses it anymore,
+    /// once the album it belonged to changed cover or was removed.
+    pub(crate) fn remove_unused_cover(&self, cover: Option<String>, database: &Database) {
+        let Some(cover) = cover else {
+            return;
+        };
+        match database.unused_covers(&[cover]) {
+            Ok(unused) => {
+                for name in unused {
+                    let path = self.cover_path(&name);
+                    match std::fs::remove_file(&path) {
+                        Ok(()) => debug!("Unused cover {name} removed"),
+                        Err(error) => warn!("Can't remove {} : {error:?}", path.display()),
+                    }
+                }
+            }
+            Err(error) => warn!("Can't find unused covers : {error:?}"),
+        }
+    }
+
+    /// Path of a cover stored in the artwork folder.
+    pub(crate) fn cover_path(&self, name: &str) -> PathBuf {
+        self.artwork.join(name)
+    }
+}
+
+/// Cover embedded in the tags of an audio file, with its extension.
+fn embedded_cover(file: &Path) -> Option<(Vec<u8>, &'static str)> {
+    let tag = match Tag::new().read_from_path(file) {
+        Ok(tag) => tag,
+        Err(error) => {
+            debug!("Can't read tag of {} : {error}", file.display());
+            return None;
+        }
+    };
+    let cover = tag.album_cover()?;
+    let extension = match cover.mime_type {
+        MimeType::Jpeg => "jpg",
+        MimeType::Png => "png",
+        MimeType::Gif => "gif",
+        MimeType::Bmp => "bmp",
+        MimeType::Tiff => "tiff",
+    };
+    Some((cover.data.to_vec(), extension))
+}
+
+/// Cover image of a folder, whatever the case of its name, with its extension.
+fn sidecar_cover(folder: &Path) -> Option<(Vec<u8>, &'static str)> {
+    let images: Vec<(String, PathBuf)> = std::fs::read_dir(folder)
+        .ok()?
+        .flatten()
+        .map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            (name, entry.path())
+        })
+        .collect();
+
+    let (name, path) = SIDECAR_FILES.iter().find_map(|sidecar| {
+        images
+            .iter()
+            .find(|(name, _)| name == sidecar)
+            .map(|(_, path)| (*sidecar, path))
+    })?;
+    let extension = if name.ends_with(".png") { "png" } else { "jpg" };
+    match std::fs::read(path) {
+        Ok(data) => Some((data, extension)),
+        Err(error) => {
+            warn!("Can't read {} : {error:?}", path.display());
+            None
+        }
+    }
+}
+
+/// Content type of a stored cover, from its extension.
+pub(crate) fn cover_content_type(name: &str) -> &'static str {
+    match Path::new(name).extension().and_then(|v| v.to_str()) {
+        Some("png") => "image/png",
+        Some("gif") => "image/gif",
+        Some("bmp") => "image/bmp",
+        Some("tiff") => "image/tiff",
+        _ => "image/jpeg",
+    }
+}
+
+/// Cover resized to fit in a square of `size` pixels, as PNG for PNG covers and JPEG otherwise,
+/// with its content type. Returns `None` when the cover is already small enough.
+pub(crate) fn resize_cover(data: &[u8], size: u32) -> Result<Option<(Vec<u8>, &'static str)>> {
+    let image = image::load_from_memory(data)?;
+    if image.width() <= size && image.height() <= size {
+        return Ok(None);
+    }
+
+    let resized = image.thumbnail(size, size);
+    let mut buffer = Cursor::new(Vec::new());
+    let content_type = if matches!(image::guess_format(data), Ok(ImageFormat::Png)) {
+        resized.write_to(&mut buffer, ImageOutputFormat::Png)?;
+        "image/png"
+    } else {
+        // JPEG has no transparency
+        DynamicImage::from(resized.to_rgb8())
+            .write_to(&mut buffer, ImageOutputFormat::Jpeg(JPEG_QUALITY))?;
+        "image/jpeg"
+    };
+    Ok(Some((buffer.into_inner(), content_type)))
+}
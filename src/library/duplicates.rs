@@ -0,0 +1,208 @@
+use crate::library::Song;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::path::Path;
+
+/// Songs whose durations differ by at most this number of seconds are near-duplicates.
+const DURATION_TOLERANCE: i32 = 2;
+
+/// MD5 of the audio content of a file. Tags are left out so that retagging
+/// a file keeps its hash : ID3v2, APE and ID3v1 tags of MP3 files, metadata
+/// blocks of FLAC files and everything but the `mdat` atoms of MP4 files.
+pub(crate) fn audio_hash(file: &Path) -> Result<String> {
+    let mut reader = File::open(file).with_context(|| format!("Can't open {}", file.display()))?;
+    let ranges =
+        audio_ranges(&mut reader).with_context(|| format!("Can't read {}", file.display()))?;
+
+    let mut context = md5::Context::new();
+    let mut buffer = vec![0; 64 * 1024];
+    for range in ranges {
+        reader.seek(SeekFrom::Start(range.start))?;
+        let mut remaining = range.end - range.start;
+        while remaining > 0 {
+            let length = remaining.min(buffer.len() as u64) as usize;
+            let read = reader
+                .read(&mut buffer[..length])
+                .with_context(|| format!("Can't read {}", file.display()))?;
+            if read == 0 {
+                break;
+            }
+            context.consume(&buffer[..read]);
+            remaining -= read as u64;
+        }
+    }
+
+    Ok(format!("{:x}", context.compute()))
+}
+
+/// Parts of a file holding audio data, the whole file when its format isn't recognized.
+fn audio_ranges<R: Read + Seek>(reader: &mut R) -> std::io::Result<Vec<Range<u64>>> {
+    let length = reader.seek(SeekFrom::End(0))?;
+    let start = skip_id3v2(reader, length)?;
+
+    let mut magic = [0; 8];
+    let ranges = if read_at(reader, start, &mut magic)? && &magic[4..] == b"ftyp" {
+        mp4_media_data(reader, start, length)?
+    } else {
+        let audio = if &magic[..4] == b"fLaC" {
+            skip_flac_metadata(reader, start + 4, length)?..length
+        } else {
+            start..skip_trailing_tags(reader, start, length)?
+        };
+        vec![audio]
+    };
+
+    if ranges.iter().all(|range| range.is_empty()) {
+        let whole = 0..length;
+        Ok(vec![whole])
+    } else {
+        Ok(ranges)
+    }
+}
+
+/// Fill `buffer` with the bytes at `offset`, returns `false` if the file is too short.
+fn read_at<R: Read + Seek>(
+    reader: &mut R,
+    offset: u64,
+    buffer: &mut [u8],
+) -> std::io::Result<bool> {
+    reader.seek(SeekFrom::Start(offset))?;
+    match reader.read_exact(buffer) {
+        Ok(()) => Ok(true),
+        Err(error) if error.kind() == ErrorKind::UnexpectedEof => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+/// Offset following the ID3v2 tags at the beginning of a file.
+fn skip_id3v2<R: Read + Seek>(reader: &mut R, length: u64) -> std::io::Result<u64> {
+    let mut offset = 0;
+    let mut header = [0; 10];
+    while offset < length && read_at(reader, offset, &mut header)? && &header[..3] == b"ID3" {
+        // Size is stored on 4 bytes of 7 bits, without the header nor the footer.
+        let size = header[6..]
+            .iter()
+            .fold(0, |size, byte| (size << 7) | (byte & 0x7f) as u64);
+        let footer = if header[5] & 0x10 != 0 { 10 } else { 0 };
+        offset = (offset + 10 + size + footer).min(length);
+    }
+    Ok(offset)
+}
+
+/// Offset of the first frame of a FLAC file, following its metadata blocks.
+fn skip_flac_metadata<R: Read + Seek>(
+    reader: &mut R,
+    mut offset: u64,
+    length: u64,
+) -> std::io::Result<u64> {
+    let mut header = [0; 4];
+    while offset < length && read_at(reader, offset, &mut header)? {
+        let size = u32::from_be_bytes([0, header[1], header[2], header[3]]) as u64;
+        offset = (offset + 4 + size).min(length);
+        // First bit flags the last metadata block
+        if header[0] & 0x80 != 0 {
+            break;
+        }
+    }
+    Ok(offset)
+}
+
+/// Content of the `mdat` atoms of a MP4 file.
+fn mp4_media_data<R: Read + Seek>(
+    reader: &mut R,
+    mut offset: u64,
+    length: u64,
+) -> std::io::Result<Vec<Range<u64>>> {
+    let mut ranges = Vec::new();
+    let mut header = [0; 8];
+    while offset < length && read_at(reader, offset, &mut header)? {
+        let mut size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as u64;
+        let mut header_size = 8;
+        if size == 1 {
+            let mut large_size = [0; 8];
+            if !read_at(reader, offset + 8, &mut large_size)? {
+                break;
+            }
+            size = u64::from_be_bytes(large_size);
+            header_size = 16;
+        } else if size == 0 {
+            // Atom extends to the end of the file
+            size = length - offset;
+        }
+        if size < header_size {
+            break;
+        }
+
+        let end = offset.saturating_add(size).min(length);
+        if &header[4..] == b"mdat" {
+            ranges.push((offset + header_size).min(end)..end);
+        }
+        offset = end;
+    }
+    Ok(ranges)
+}
+
+/// End of the audio data of a MP3 file, preceding its APE and ID3v1 tags.
+fn skip_trailing_tags<R: Read + Seek>(
+    reader: &mut R,
+    start: u64,
+    mut end: u64,
+) -> std::io::Result<u64> {
+    let mut id3v1 = [0; 3];
+    if end >= start + 128 && read_at(reader, end - 128, &mut id3v1)? && &id3v1 == b"TAG" {
+        end -= 128;
+    }
+
+    let mut footer = [0; 32];
+    if end >= start + 32 && read_at(reader, end - 32, &mut footer)? && &footer[..8] == b"APETAGEX" {
+        // Size includes the footer but not the optional header.
+        let size = u32::from_le_bytes([footer[12], footer[13], footer[14], footer[15]]) as u64;
+        let flags = u32::from_le_bytes([footer[20], footer[21], footer[22], footer[23]]);
+        let header = if flags & 0x8000_0000 != 0 { 32 } else { 0 };
+        end = end.saturating_sub(size + header).max(start);
+    }
+
+    Ok(end)
+}
+
+/// Group songs sharing artist and title, ignoring case, whose durations are close.
+/// Songs without near-duplicate are left out.
+pub(crate) fn near_duplicates(songs: Vec<Song>) -> Vec<Vec<Song>> {
+    let normalize = |value: String| value.trim().to_lowercase();
+    let mut by_name: BTreeMap<(String, String), Vec<Song>> = BTreeMap::new();
+    for song in songs {
+        by_name
+            .entry((normalize(song.artist()), normalize(song.title())))
+            .or_default()
+            .push(song);
+    }
+
+    let mut groups = Vec::new();
+    for (_, mut songs) in by_name {
+        songs.sort_by_key(|song| (song.duration().unwrap_or_default(), song.id()));
+
+        let mut group: Vec<Song> = Vec::new();
+        for song in songs {
+            let duration = song.duration().unwrap_or_default();
+            let close = group
+                .last()
+                .map(|last| duration - last.duration().unwrap_or_default() <= DURATION_TOLERANCE)
+                .unwrap_or(true);
+            if !close {
+                if group.len() > 1 {
+                    groups.push(std::mem::take(&mut group));
+                } else {
+                    group.clear();
+                }
+            }
+            group.push(song);
+        }
+        if group.len() > 1 {
+            groups.push(group);
+        }
+    }
+    groups
+}
@@ -1,14 +1,32 @@
+mod artwork;
+mod duplicates;
+mod scanner;
 mod song;
+mod watcher;
 
+use crate::database::{Database, SongFile};
+use crate::index::{TantivyIndex, Visibility};
+use crate::library::duplicates::audio_hash;
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use log::{debug, error, warn};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use uuid::Uuid;
 
-pub(crate) use song::Song;
+pub(crate) use artwork::{cover_content_type, resize_cover};
+pub(crate) use duplicates::near_duplicates;
+pub(crate) use song::{Song, UnsupportedFile};
+
+/// Maximum length of the name of an uploaded file, see `original_name` of `songs` table.
+const ORIGINAL_NAME_MAX_LENGTH: usize = 255;
 
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Library {
     library: PathBuf,
     temporary: PathBuf,
+    artwork: PathBuf,
+    max_upload_size: u64,
+    artist_separators: Vec<String>,
 }
 
 impl Library {
@@ -16,16 +34,328 @@ impl Library {
         &self.library
     }
 
-    pub fn temporary_path(&self) -> &PathBuf {
-        &self.temporary
+    /// Unique path in the temporary folder for an uploaded file.
+    ///
+    /// The name given by the client is only kept for its extension, needed to read tags.
+    /// Returns `None` when it isn't a plain file name (path separators, `.`, `..`, control characters)
+    /// or is too long to be kept as the original name of the song.
+    pub fn upload_path(&self, filename: &str) -> Option<PathBuf> {
+        let allowed = !filename.trim().is_empty()
+            && filename.chars().count() <= ORIGINAL_NAME_MAX_LENGTH
+            && filename != "."
+            && filename != ".."
+            && !filename
+                .chars()
+                .any(|c| c == '/' || c == '\\' || c.is_control());
+        if !allowed {
+            return None;
+        }
+
+        let extension = Path::new(filename)
+            .extension()
+            .and_then(|v| v.to_str())
+            .filter(|v| v.chars().all(|c| c.is_ascii_alphanumeric()))
+            .map(|v| v.to_lowercase());
+        let name = match extension {
+            Some(extension) => format!("{}.{extension}", Uuid::new_v4()),
+            None => Uuid::new_v4().to_string(),
+        };
+        Some(self.temporary.join(name))
+    }
+
+    /// Maximum size of an uploaded file, in bytes.
+    pub fn max_upload_size(&self) -> u64 {
+        self.max_upload_size
+    }
+
+    /// Song read from the tags of an audio file, with its artists split.
+    fn read_song(&self, file: &Path) -> Result<Song> {
+        let song = Song::try_from(file.to_path_buf())?;
+        Ok(song.split_artists(&self.artist_separators))
     }
 
     pub fn create_folder(&self) -> Result<()> {
         std::fs::create_dir_all(&self.library)
             .with_context(|| format!("Can't create {}", self.library.display()))?;
         std::fs::create_dir_all(&self.temporary)
-            .with_context(|| format!("Can't create {}", self.temporary.display()))
+            .with_context(|| format!("Can't create {}", self.temporary.display()))?;
+        std::fs::create_dir_all(&self.artwork)
+            .with_context(|| format!("Can't create {}", self.artwork.display()))
+    }
+
+    /// Add an audio file to the library : the song is saved in database,
+    /// the file is moved into the library folder and the song is indexed.
+    ///
+    /// If a step fails, the previous ones are rolled back and the file is
+    /// left at its original place. `original_name` is the name of the file
+    /// given by the client, only kept as metadata.
+    pub(crate) fn add_song(
+        &self,
+        file: &Path,
+        original_name: &str,
+        database: &Database,
+        index: &TantivyIndex,
+    ) -> Result<Song> {
+        let song = self.read_song(file)?;
+
+        let extension = file
+            .extension()
+            .and_then(|v| v.to_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        let relative_path = PathBuf::from(format!("{}.{extension}", Uuid::new_v4()));
+        let destination = self.library.join(&relative_path);
+        // Moving the file keeps its content and, on the same file system, its modification time.
+        let song_file = SongFile {
+            original_name: Some(original_name.to_string()),
+            ..song_file(file, relative_path.to_string_lossy().to_string())?
+        };
+        let cover = self.store_cover(file);
+
+        let mut indexed = None;
+        let mut changed_album = None;
+        let result = database.add_song(&song, &song_file, cover.as_deref(), |song, siblings| {
+            move_file(file, &destination)?;
+
+            // Songs of the album share its details
+            let songs = std::iter::once(song.clone())
+                .chain(siblings.iter().cloned())
+                .collect();
+            if let Err(error) = index.update_all(songs, index.visibility()) {
+                if let Err(error) = move_file(&destination, file) {
+                    error!("Can't move back {} : {error:?}", destination.display());
+                }
+                return Err(error.into());
+            }
+
+            indexed = song.id();
+            changed_album = song.album_id().filter(|_| !siblings.is_empty());
+            Ok(())
+        });
+
+        match result {
+            Ok(song) => {
+                debug!("{} added with id {:?}", destination.display(), song.id());
+                Ok(song)
+            }
+            Err(error) => {
+                if let Some(id) = indexed {
+                    // Everything went fine but the commit, undo index and file move.
+                    warn!("Can't commit {} : {error:?}", file.display());
+                    if let Err(error) = index.delete(id, index.visibility()) {
+                        error!("Can't remove song {id} from index : {error:?}");
+                    }
+                    if let Some(album) = changed_album {
+                        restore_album(album, database, index, index.visibility());
+                    }
+                    if let Err(error) = move_file(&destination, file) {
+                        error!("Can't move back {} : {error:?}", destination.display());
+                    }
+                }
+                Err(error.into())
+            }
+        }
+    }
+
+    /// Update the metadata of a song : database, index and, when `write_tags` is set,
+    /// tags of the audio file.
+    ///
+    /// If a step fails, the previous ones are rolled back.
+    /// Returns `None` if there is no song with this id.
+    pub(crate) fn update_song(
+        &self,
+        id: i32,
+        changes: &Song,
+        cover: Option<&str>,
+        write_tags: bool,
+        database: &Database,
+        index: &TantivyIndex,
+    ) -> Result<Option<Song>> {
+        let changes = changes.clone().split_artists(&self.artist_separators);
+        let previous_cover = database.song_cover(id)?;
+        // A copy of the file is kept in the temporary folder until the update is committed.
+        let mut updated = None;
+        let result = database.update_song(id, &changes, cover, |previous, song, path, siblings| {
+            let file = self.library.join(path);
+            let backup = if write_tags {
+                let backup = self.temporary.join(format!("{}.backup", Uuid::new_v4()));
+                std::fs::copy(&file, &backup).with_context(|| {
+                    format!("Can't copy {} to {}", file.display(), backup.display())
+                })?;
+                if let Err(error) = song.write_tags(&file) {
+                    if let Err(error) = move_file(&backup, &file) {
+                        error!("Can't restore {} : {error:?}", file.display());
+                    }
+                    return Err(error);
+                }
+                Some(backup)
+            } else {
+                None
+            };
+
+            // Songs of the album share its details
+            let songs = std::iter::once(song.clone())
+                .chain(siblings.iter().cloned())
+                .collect();
+            if let Err(error) = index.update_all(songs, index.visibility()) {
+                if let Some(backup) = &backup {
+                    if let Err(error) = move_file(backup, &file) {
+                        error!("Can't restore {} : {error:?}", file.display());
+                    }
+                }
+                return Err(error.into());
+            }
+
+            let changed_album = song.album_id().filter(|_| !siblings.is_empty());
+            updated = Some((previous.clone(), file, backup, changed_album));
+            Ok(())
+        });
+
+        match result {
+            Ok(song) => {
+                if let Some((_, _, Some(backup), _)) = updated {
+                    if let Err(error) = std::fs::remove_file(&backup) {
+                        warn!("Can't remove {} : {error:?}", backup.display());
+                    }
+                }
+                self.remove_unused_cover(previous_cover, database);
+                Ok(song)
+            }
+            Err(error) => {
+                if let Some((previous, file, backup, changed_album)) = updated {
+                    // Everything went fine but the commit, restore index and file.
+                    warn!("Can't commit update of song {id} : {error:?}");
+                    if let Err(error) = index.update(id, previous, index.visibility()) {
+                        error!("Can't index back song {id} : {error:?}");
+                    }
+                    if let Some(album) = changed_album {
+                        restore_album(album, database, index, index.visibility());
+                    }
+                    if let Some(backup) = backup {
+                        if let Err(error) = move_file(&backup, &file) {
+                            error!("Can't restore {} : {error:?}", file.display());
+                        }
+                    }
+                }
+                Err(error.into())
+            }
+        }
+    }
+
+    /// Remove a song from the library : database, index and audio file.
+    ///
+    /// If a step fails, the previous ones are rolled back.
+    /// Returns `false` if there is no song with this id.
+    pub(crate) fn delete_song(
+        &self,
+        id: i32,
+        database: &Database,
+        index: &TantivyIndex,
+    ) -> Result<bool> {
+        let cover = database.song_cover(id)?;
+        // The file is kept in the temporary folder until the deletion is committed.
+        let mut deleted = None;
+        let result = database.delete_song(id, |song, path| {
+            let file = self.library.join(path);
+            let trash = self.temporary.join(format!("{}.deleted", Uuid::new_v4()));
+            let trash = if file.exists() {
+                move_file(&file, &trash)?;
+                Some(trash)
+            } else {
+                warn!("File {} of song {id} doesn't exist", file.display());
+                None
+            };
+
+            if let Err(error) = index.delete(id, index.visibility()) {
+                if let Some(trash) = &trash {
+                    if let Err(error) = move_file(trash, &file) {
+                        error!("Can't move back {} : {error:?}", file.display());
+                    }
+                }
+                return Err(error.into());
+            }
+
+            deleted = Some((song.clone(), file, trash));
+            Ok(())
+        });
+
+        match result {
+            Ok(found) => {
+                if let Some((_, _, Some(trash))) = deleted {
+                    if let Err(error) = std::fs::remove_file(&trash) {
+                        warn!("Can't remove {} : {error:?}", trash.display());
+                    }
+                }
+                self.remove_unused_cover(cover, database);
+                Ok(found)
+            }
+            Err(error) => {
+                if let Some((song, file, trash)) = deleted {
+                    // Everything went fine but the commit, restore index and file.
+                    warn!("Can't commit deletion of song {id} : {error:?}");
+                    if let Err(error) = index.index(song, index.visibility()) {
+                        error!("Can't index back song {id} : {error:?}");
+                    }
+                    if let Some(trash) = trash {
+                        if let Err(error) = move_file(&trash, &file) {
+                            error!("Can't move back {} : {error:?}", file.display());
+                        }
+                    }
+                }
+                Err(error.into())
+            }
+        }
+    }
+}
+
+/// Modification time and audio hash of a file, to be stored at `path`.
+fn song_file(file: &Path, path: String) -> Result<SongFile> {
+    Ok(SongFile {
+        path,
+        modified: modified(file),
+        hash: Some(audio_hash(file)?),
+        original_name: file
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string()),
+    })
+}
+
+/// Index the songs of the album `album` again as they are in the database,
+/// once a change of its details has been rolled back.
+fn restore_album(album: i32, database: &Database, index: &TantivyIndex, visibility: Visibility) {
+    let result = database
+        .album_songs(album)
+        .map_err(anyhow::Error::from)
+        .and_then(|songs| Ok(index.update_all(songs, visibility)?));
+    if let Err(error) = result {
+        error!("Can't index back the songs of album {album} : {error:?}");
+    }
+}
+
+/// Modification time of a file, in seconds since epoch.
+fn modified(file: &Path) -> Option<i64> {
+    std::fs::metadata(file)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|modified| modified.as_secs() as i64)
+}
+
+/// Move a file, falling back to copy then remove when
+/// source and destination are not on the same file system.
+fn move_file(from: &Path, to: &Path) -> Result<()> {
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Can't create {}", parent.display()))?;
     }
+
+    if std::fs::rename(from, to).is_err() {
+        std::fs::copy(from, to)
+            .with_context(|| format!("Can't copy {} to {}", from.display(), to.display()))?;
+        std::fs::remove_file(from).with_context(|| format!("Can't remove {}", from.display()))?;
+    }
+
+    Ok(())
 }
 
 impl From<crate::config::Library> for Library {
@@ -33,6 +363,9 @@ impl From<crate::config::Library> for Library {
         Self {
             library: value.path(),
             temporary: value.tmp(),
+            artwork: value.artwork(),
+            max_upload_size: value.max_upload_size(),
+            artist_separators: value.artist_separators(),
         }
     }
 }
@@ -0,0 +1,330 @@
+use crate::database::{Database, DatabaseError, SongFile};
+use crate::index::{TantivyIndex, Visibility};
+use crate::library::{modified, restore_album, song_file, Library};
+use anyhow::{bail, Context, Result};
+use log::{debug, info, warn};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, TryLockError};
+
+/// Extensions of the files considered by a scan.
+static AUDIO_EXTENSIONS: [&str; 5] = ["flac", "m4a", "m4b", "mp3", "mp4"];
+
+/// Held while a scan is running.
+static SCANNING: Mutex<()> = Mutex::new(());
+
+/// Number of files handled by a scan.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ScanReport {
+    /// New songs.
+    pub(crate) added: usize,
+    /// Songs whose file was modified or moved.
+    pub(crate) updated: usize,
+    /// Files that couldn't be read or saved.
+    pub(crate) failed: usize,
+    /// Songs whose file no longer exists.
+    pub(crate) removed: usize,
+}
+
+/// Outcome of the scan of a file.
+enum Scanned {
+    Added,
+    Updated,
+    Unchanged,
+}
+
+/// Songs already in the library, by path and by hash of their file.
+struct Known {
+    by_path: HashMap<String, (i32, SongFile)>,
+    by_hash: HashMap<String, (i32, SongFile)>,
+}
+
+impl Library {
+    /// Add the audio files of a folder, and its sub folders, to the library. Files are
+    /// left in place and their path is stored relative to the library folder when they are in it.
+    ///
+    /// Files already known by path and modification time, or by hash, are skipped so that an
+    /// interrupted scan can be run again. Songs of the folder whose file no longer exists are removed.
+    /// Returns `None` if a scan is already running.
+    pub(crate) fn scan(
+        &self,
+        directory: &Path,
+        database: &Database,
+        index: &TantivyIndex,
+    ) -> Result<Option<ScanReport>> {
+        let _scanning = match SCANNING.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => return Ok(None),
+        };
+
+        let directory = directory
+            .canonicalize()
+            .with_context(|| format!("Can't access {}", directory.display()))?;
+        if !directory.is_dir() {
+            bail!("{} is not a directory", directory.display());
+        }
+
+        info!("Scanning {}", directory.display());
+        let report = self.synchronize(std::slice::from_ref(&directory), database, index)?;
+        info!("Scan of {} done : {report:?}", directory.display());
+        Ok(Some(report))
+    }
+
+    /// Bring database and index in line with the audio files found at `paths`, which
+    /// are absolute paths to files or folders that may no longer exist.
+    /// Waits for a running scan to finish.
+    pub(crate) fn synchronize_paths(
+        &self,
+        paths: &[PathBuf],
+        database: &Database,
+        index: &TantivyIndex,
+    ) -> Result<ScanReport> {
+        let _scanning = SCANNING
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.synchronize(paths, database, index)
+    }
+
+    fn synchronize(
+        &self,
+        paths: &[PathBuf],
+        database: &Database,
+        index: &TantivyIndex,
+    ) -> Result<ScanReport> {
+        let library = self
+            .library
+            .canonicalize()
+            .with_context(|| format!("Can't access {}", self.library.display()))?;
+        let temporary = self.temporary.canonicalize().ok();
+
+        let mut files = Vec::new();
+        let mut report = ScanReport::default();
+        for path in paths {
+            if temporary
+                .as_deref()
+                .map(|temporary| path.starts_with(temporary))
+                .unwrap_or(false)
+            {
+                continue;
+            }
+            if path.is_dir() {
+                report.failed += audio_files(path, temporary.as_deref(), &mut files);
+            } else if path.is_file() && is_audio_file(path) {
+                files.push(path.clone());
+            }
+        }
+
+        let mut known = Known {
+            by_path: HashMap::new(),
+            by_hash: HashMap::new(),
+        };
+        for (id, file) in database.song_files()? {
+            if let Some(hash) = &file.hash {
+                known.by_hash.insert(hash.clone(), (id, file.clone()));
+            }
+            known.by_path.insert(file.path.clone(), (id, file));
+        }
+
+        let scanned: Vec<Result<Scanned>> = files
+            .par_iter()
+            .map(|file| self.scan_file(file, &library, &known, database, index))
+            .collect();
+        for (file, scanned) in files.iter().zip(scanned) {
+            match scanned {
+                Ok(Scanned::Added) => report.added += 1,
+                Ok(Scanned::Updated) => report.updated += 1,
+                Ok(Scanned::Unchanged) => {}
+                Err(error) => {
+                    warn!("Can't scan {} : {error:?}", file.display());
+                    report.failed += 1;
+                }
+            }
+        }
+
+        // Files are read again, moved songs now have their new path.
+        for (id, file) in database.song_files()? {
+            let path = library.join(&file.path);
+            if !paths.iter().any(|root| path.starts_with(root)) || path.exists() {
+                continue;
+            }
+            match self.delete_song(id, database, index) {
+                Ok(true) => {
+                    debug!("{} no longer exists, song {id} removed", path.display());
+                    report.removed += 1;
+                }
+                Ok(false) => {}
+                Err(error) => {
+                    warn!("Can't remove song {id} : {error:?}");
+                    report.failed += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn scan_file(
+        &self,
+        file: &Path,
+        library: &Path,
+        known: &Known,
+        database: &Database,
+        index: &TantivyIndex,
+    ) -> Result<Scanned> {
+        let path = match file.strip_prefix(library) {
+            Ok(relative) => relative.to_string_lossy().to_string(),
+            Err(_) => file.to_string_lossy().to_string(),
+        };
+
+        if let Some((id, previous)) = known.by_path.get(&path) {
+            if previous.modified.is_some() && previous.modified == modified(file) {
+                return Ok(Scanned::Unchanged);
+            }
+
+            let song_file = song_file(file, path)?;
+            database.update_song_file(*id, &song_file)?;
+            return self.update_tags(*id, file, database, index);
+        }
+
+        let song_file = song_file(file, path)?;
+        if let Some((id, previous)) = song_file.hash.as_ref().and_then(|h| known.by_hash.get(h)) {
+            if library.join(&previous.path).exists() {
+                debug!("{} is a copy of song {id}", file.display());
+                return Ok(Scanned::Unchanged);
+            }
+            database.update_song_file(*id, &song_file)?;
+            debug!("{} moved to {}", previous.path, file.display());
+            self.update_tags(*id, file, database, index)?;
+            return Ok(Scanned::Updated);
+        }
+
+        match self.add_song_in_place(file, &song_file, database, index) {
+            Ok(()) => Ok(Scanned::Added),
+            Err(error) => match error.downcast_ref::<DatabaseError>() {
+                Some(DatabaseError::DuplicateSong(id)) => {
+                    debug!("{} is a copy of song {id}", file.display());
+                    Ok(Scanned::Unchanged)
+                }
+                _ => Err(error),
+            },
+        }
+    }
+
+    /// Read the tags of the file of a song again, they are not part of its hash.
+    fn update_tags(
+        &self,
+        id: i32,
+        file: &Path,
+        database: &Database,
+        index: &TantivyIndex,
+    ) -> Result<Scanned> {
+        let song = self.read_song(file)?;
+        let cover = self.store_cover(file);
+        let unchanged = database
+            .song(id)?
+            .map(|(previous, _)| previous.merge(&song) == previous)
+            .unwrap_or(false);
+        // A file without cover keeps the one of its album, other songs may provide it
+        if unchanged && (cover.is_none() || database.song_cover(id)? == cover) {
+            return Ok(Scanned::Unchanged);
+        }
+
+        self.update_song(id, &song, cover.as_deref(), false, database, index)?;
+        debug!("{} updated", file.display());
+        Ok(Scanned::Updated)
+    }
+
+    /// Save and index a song without moving its file.
+    fn add_song_in_place(
+        &self,
+        file: &Path,
+        song_file: &SongFile,
+        database: &Database,
+        index: &TantivyIndex,
+    ) -> Result<()> {
+        let song = self.read_song(file)?;
+        let cover = self.store_cover(file);
+
+        let mut indexed = None;
+        let mut changed_album = None;
+        let result = database.add_song(&song, song_file, cover.as_deref(), |song, siblings| {
+            // Songs of a scan are committed by batches, those of the album share its details
+            let songs = std::iter::once(song.clone())
+                .chain(siblings.iter().cloned())
+                .collect();
+            index.update_all(songs, Visibility::Batched)?;
+            indexed = song.id();
+            changed_album = song.album_id().filter(|_| !siblings.is_empty());
+            Ok(())
+        });
+
+        match result {
+            Ok(song) => {
+                debug!("{} added with id {:?}", file.display(), song.id());
+                Ok(())
+            }
+            Err(error) => {
+                if let Some(id) = indexed {
+                    // Everything went fine but the commit, undo indexing.
+                    warn!("Can't commit {} : {error:?}", file.display());
+                    if let Err(error) = index.delete(id, Visibility::Batched) {
+                        warn!("Can't remove song {id} from index : {error:?}");
+                    }
+                    if let Some(album) = changed_album {
+                        restore_album(album, database, index, Visibility::Batched);
+                    }
+                }
+                Err(error.into())
+            }
+        }
+    }
+}
+
+/// Collect audio files of a folder and its sub folders, except those of the `skipped` one.
+/// Returns the number of folders that couldn't be read.
+fn audio_files(directory: &Path, skipped: Option<&Path>, files: &mut Vec<PathBuf>) -> usize {
+    let entries = match std::fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(error) => {
+            warn!("Can't read {} : {error:?}", directory.display());
+            return 1;
+        }
+    };
+
+    let mut failed = 0;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            failed += 1;
+            continue;
+        };
+        if file_type.is_dir() {
+            if Some(path.as_path()) != skipped {
+                failed += audio_files(&path, skipped, files);
+            }
+        } else if file_type.is_file() && is_audio_file(&path) {
+            files.push(path);
+        }
+    }
+    failed
+}
+
+fn is_audio_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|v| v.to_str())
+        .map(|v| AUDIO_EXTENSIONS.contains(&v.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+impl From<ScanReport> for server_lib::models::ScanReport {
+    fn from(report: ScanReport) -> Self {
+        server_lib::models::ScanReport {
+            added: Some(report.added as i32),
+            updated: Some(report.updated as i32),
+            failed: Some(report.failed as i32),
+            removed: Some(report.removed as i32),
+        }
+    }
+}
@@ -1,51 +1,137 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use audiotags::Tag;
+use anyhow::{anyhow, Context};
+use audiotags::{AudioTag, FlacTag, Id3v2Tag, Mp4Tag, Tag};
+use id3::TagLike;
 use log::warn;
-use swagger::ApiError;
-use tantivy::schema::Schema;
+use tantivy::schema::{Field, Schema};
 use tantivy::Document;
+use thiserror::Error;
 
 use crate::index::PartitionFields;
 
+/// Separator of the artists in the artist text of a song.
+const ARTISTS_SEPARATOR: &str = "; ";
+/// Maximum length of titles, albums, artists and composers, see `songs`, `albums`
+/// and `artists` tables.
+const NAME_MAX_LENGTH: usize = 255;
+/// Maximum length of a genre, see `songs` table.
+const GENRE_MAX_LENGTH: usize = 50;
+
+/// First `max_length` characters of a tag value, to fit in its column.
+fn truncate(value: &str, max_length: usize) -> String {
+    value.chars().take(max_length).collect()
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Song(server_lib::models::Song);
 
+/// The tags of a file can't be read, it isn't an audio file of a supported format.
+#[derive(Error, Debug)]
+#[error("Can't read tag of {path} : {message}")]
+pub(crate) struct UnsupportedFile {
+    path: String,
+    message: String,
+}
+
 impl Song {
     pub(crate) fn into_document(self, schema: &Schema) -> Document {
+        let field =
+            |field: PartitionFields| -> Field { schema.get_field(field.field_name()).unwrap() };
         let mut document = Document::new();
 
-        document.add_text(
-            schema
-                .get_field(PartitionFields::Title.field_name())
-                .unwrap(),
-            &self.title(),
-        );
-
-        document.add_text(
-            schema
-                .get_field(PartitionFields::Album.field_name())
-                .unwrap(),
-            &self.album(),
-        );
-
-        document.add_text(
-            schema
-                .get_field(PartitionFields::Artist.field_name())
-                .unwrap(),
-            &self.artist(),
-        );
-
-        if let Some(id) = self.id() {
-            document.add_i64(
-                schema.get_field(PartitionFields::Id.field_name()).unwrap(),
-                id as i64,
-            );
+        PartitionFields::Title.add_text(schema, &mut document, &self.title());
+        PartitionFields::Album.add_text(schema, &mut document, &self.album());
+        // Each artist is a value of its own, to find songs by any of their artists
+        for artist in self.artists() {
+            PartitionFields::Artist.add_text(schema, &mut document, &artist);
+        }
+
+        let texts = [
+            (PartitionFields::AlbumArtist, self.album_artist()),
+            (PartitionFields::Composer, self.composer()),
+            (PartitionFields::Genre, self.genre()),
+            (PartitionFields::Comment, self.comment()),
+            (PartitionFields::Lyrics, self.lyrics()),
+            (
+                PartitionFields::OriginalName,
+                self.0.original_name.as_deref(),
+            ),
+        ];
+        for (name, text) in texts {
+            if let Some(text) = text {
+                name.add_text(schema, &mut document, text);
+            }
+        }
+        if let Some(year) = self.0.year {
+            let decade = year - year.rem_euclid(10);
+            PartitionFields::Decade.add_text(schema, &mut document, &decade.to_string());
+        }
+
+        let numbers = [
+            (PartitionFields::Id, self.0.id),
+            (PartitionFields::AlbumId, self.0.album_id),
+            (PartitionFields::Track, self.0.track),
+            (PartitionFields::TotalTracks, self.0.total_tracks),
+            (PartitionFields::Disc, self.0.disc),
+            (PartitionFields::TotalDiscs, self.0.total_discs),
+            (PartitionFields::Year, self.0.year),
+            (PartitionFields::Duration, self.0.duration),
+        ];
+        for (name, number) in numbers {
+            if let Some(number) = number {
+                document.add_i64(field(name), number as i64);
+            }
         }
 
         document
     }
 
+    /// Build a song back from the stored fields of an indexed document.
+    pub(crate) fn from_document(document: &Document, schema: &Schema) -> Self {
+        let field =
+            |field: PartitionFields| -> Field { schema.get_field(field.field_name()).unwrap() };
+        let text = |name: PartitionFields| -> Option<String> {
+            document
+                .get_first(field(name))
+                .and_then(|v| v.as_text())
+                .map(|v| v.to_string())
+        };
+        let artists: Vec<String> = document
+            .get_all(field(PartitionFields::Artist))
+            .filter_map(|v| v.as_text())
+            .map(|v| v.to_string())
+            .collect();
+        let number = |name: PartitionFields| -> Option<i32> {
+            document
+                .get_first(field(name))
+                .and_then(|v| v.as_i64())
+                .and_then(|v| i32::try_from(v).ok())
+        };
+
+        Self(server_lib::models::Song {
+            id: number(PartitionFields::Id),
+            title: text(PartitionFields::Title),
+            album: text(PartitionFields::Album),
+            album_id: number(PartitionFields::AlbumId),
+            album_artist: text(PartitionFields::AlbumArtist),
+            track: number(PartitionFields::Track),
+            total_tracks: number(PartitionFields::TotalTracks),
+            disc: number(PartitionFields::Disc),
+            total_discs: number(PartitionFields::TotalDiscs),
+            artist: Self::join_artists(&artists),
+            artists: (!artists.is_empty()).then_some(artists),
+            composer: text(PartitionFields::Composer),
+            genre: text(PartitionFields::Genre),
+            year: number(PartitionFields::Year),
+            duration: number(PartitionFields::Duration),
+            comment: text(PartitionFields::Comment),
+            lyrics: text(PartitionFields::Lyrics),
+            original_name: text(PartitionFields::OriginalName),
+            score: None,
+        })
+    }
+
     pub(crate) fn title(&self) -> String {
         self.0
             .title
@@ -67,9 +153,200 @@ impl Song {
             .unwrap_or_else(|| "Unknown artist".to_string())
     }
 
+    /// Artists of the song, at least one.
+    pub(crate) fn artists(&self) -> Vec<String> {
+        self.0
+            .artists
+            .clone()
+            .filter(|artists| !artists.is_empty())
+            .unwrap_or_else(|| vec![self.artist()])
+    }
+
+    /// Artist text of several artists.
+    pub(crate) fn join_artists(artists: &[String]) -> Option<String> {
+        (!artists.is_empty()).then(|| artists.join(ARTISTS_SEPARATOR))
+    }
+
+    /// Split the artists on `separators`, like "Artist feat. Guest" into "Artist"
+    /// and "Guest". The artist text is rebuilt from the resulting artists.
+    pub(crate) fn split_artists(mut self, separators: &[String]) -> Self {
+        let names = match (&self.0.artists, &self.0.artist) {
+            (Some(artists), _) => artists.clone(),
+            (None, Some(artist)) => vec![artist.clone()],
+            (None, None) => return self,
+        };
+
+        let mut artists: Vec<String> = Vec::new();
+        for name in names {
+            let mut parts = vec![name];
+            for separator in separators.iter().filter(|separator| !separator.is_empty()) {
+                parts = parts
+                    .iter()
+                    .flat_map(|part| part.split(separator.as_str()))
+                    .map(|part| part.to_string())
+                    .collect();
+            }
+            for part in parts {
+                let part = part.trim();
+                let part = truncate(part, NAME_MAX_LENGTH);
+                if !part.is_empty() && !artists.contains(&part) {
+                    artists.push(part);
+                }
+            }
+        }
+
+        self.0.artist = Self::join_artists(&artists);
+        self.0.artists = (!artists.is_empty()).then_some(artists);
+        self
+    }
+
+    pub(crate) fn track(&self) -> Option<i32> {
+        self.0.track
+    }
+
+    pub(crate) fn album_artist(&self) -> Option<&str> {
+        self.0.album_artist.as_deref()
+    }
+
+    pub(crate) fn total_tracks(&self) -> Option<i32> {
+        self.0.total_tracks
+    }
+
+    pub(crate) fn disc(&self) -> Option<i32> {
+        self.0.disc
+    }
+
+    pub(crate) fn total_discs(&self) -> Option<i32> {
+        self.0.total_discs
+    }
+
+    pub(crate) fn composer(&self) -> Option<&str> {
+        self.0.composer.as_deref()
+    }
+
+    pub(crate) fn genre(&self) -> Option<&str> {
+        self.0.genre.as_deref()
+    }
+
+    pub(crate) fn year(&self) -> Option<i32> {
+        self.0.year
+    }
+
+    pub(crate) fn comment(&self) -> Option<&str> {
+        self.0.comment.as_deref()
+    }
+
+    pub(crate) fn lyrics(&self) -> Option<&str> {
+        self.0.lyrics.as_deref()
+    }
+
+    pub(crate) fn duration(&self) -> Option<i32> {
+        self.0.duration
+    }
+
     pub(crate) fn id(&self) -> Option<i32> {
         self.0.id
     }
+
+    pub(crate) fn album_id(&self) -> Option<i32> {
+        self.0.album_id
+    }
+
+    pub(crate) fn set_id(&mut self, id: i32) {
+        self.0.id = Some(id);
+    }
+
+    pub(crate) fn set_album_id(&mut self, album_id: i32) {
+        self.0.album_id = Some(album_id);
+    }
+
+    pub(crate) fn set_score(&mut self, score: f32) {
+        self.0.score = Some(score);
+    }
+
+    /// This song with the metadata of `changes` that are set.
+    pub(crate) fn merge(&self, changes: &Song) -> Self {
+        Self(server_lib::models::Song {
+            title: changes.0.title.clone().or_else(|| self.0.title.clone()),
+            album: changes.0.album.clone().or_else(|| self.0.album.clone()),
+            album_artist: changes
+                .0
+                .album_artist
+                .clone()
+                .or_else(|| self.0.album_artist.clone()),
+            track: changes.0.track.or(self.0.track),
+            total_tracks: changes.0.total_tracks.or(self.0.total_tracks),
+            disc: changes.0.disc.or(self.0.disc),
+            total_discs: changes.0.total_discs.or(self.0.total_discs),
+            artist: changes.0.artist.clone().or_else(|| self.0.artist.clone()),
+            artists: changes.0.artists.clone().or_else(|| self.0.artists.clone()),
+            composer: changes
+                .0
+                .composer
+                .clone()
+                .or_else(|| self.0.composer.clone()),
+            genre: changes.0.genre.clone().or_else(|| self.0.genre.clone()),
+            year: changes.0.year.or(self.0.year),
+            comment: changes.0.comment.clone().or_else(|| self.0.comment.clone()),
+            lyrics: changes.0.lyrics.clone().or_else(|| self.0.lyrics.clone()),
+            ..self.0.clone()
+        })
+    }
+
+    /// Write the metadata into the tags of an audio file, other tags are kept.
+    /// Comment and lyrics are specific to each format and aren't written.
+    pub(crate) fn write_tags(&self, file_path: &Path) -> anyhow::Result<()> {
+        let path = file_path
+            .to_str()
+            .ok_or_else(|| anyhow!("Invalid path {}", file_path.display()))?;
+        let mut tag = Tag::new()
+            .read_from_path(file_path)
+            .with_context(|| format!("Can't read tag of {}", file_path.display()))?;
+
+        if let Some(title) = &self.0.title {
+            tag.set_title(title);
+        }
+        if let Some(album) = &self.0.album {
+            tag.set_album_title(album);
+        }
+        if let Some(artist) = &self.0.artist {
+            tag.set_artist(artist);
+        }
+        if let Some(track) = self.0.track.and_then(|track| u16::try_from(track).ok()) {
+            tag.set_track_number(track);
+        }
+        if let Some(genre) = &self.0.genre {
+            tag.set_genre(genre);
+        }
+        if let Some(album_artist) = &self.0.album_artist {
+            tag.set_album_artist(album_artist);
+        }
+        if let Some(composer) = &self.0.composer {
+            tag.set_composer(composer.clone());
+        }
+        if let Some(year) = self.0.year {
+            tag.set_year(year);
+        }
+        let number = |value: Option<i32>| value.and_then(|value| u16::try_from(value).ok());
+        if let Some(total_tracks) = number(self.0.total_tracks) {
+            tag.set_total_tracks(total_tracks);
+        }
+        if let Some(disc) = number(self.0.disc) {
+            tag.set_disc_number(disc);
+        }
+        if let Some(total_discs) = number(self.0.total_discs) {
+            tag.set_total_discs(total_discs);
+        }
+
+        tag.write_to_path(path)
+            .with_context(|| format!("Can't write tag of {}", file_path.display()))
+    }
+}
+
+impl From<Song> for server_lib::models::Song {
+    fn from(value: Song) -> Self {
+        value.0
+    }
 }
 
 impl From<server_lib::models::Song> for Song {
@@ -79,24 +356,111 @@ impl From<server_lib::models::Song> for Song {
 }
 
 impl TryFrom<PathBuf> for Song {
-    type Error = ApiError;
+    type Error = UnsupportedFile;
 
     fn try_from(file_path: PathBuf) -> Result<Self, Self::Error> {
+        // The format is told by the extension
+        if file_path.extension().is_none() {
+            return Err(UnsupportedFile {
+                path: file_path.display().to_string(),
+                message: "no extension".to_string(),
+            });
+        }
         let tag = Tag::new().read_from_path(&file_path).map_err(|error| {
             warn!("Can't read tag of {} : {error}", file_path.display());
-            ApiError(format!(
-                "Can't read tag of {} : {error}",
-                file_path.display()
-            ))
+            UnsupportedFile {
+                path: file_path.display().to_string(),
+                message: error.to_string(),
+            }
         })?;
 
-        Ok(Self(server_lib::models::Song {
+        let song = server_lib::models::Song {
             id: None,
-            title: tag.title().map(|v| v.to_string()),
-            album: tag.album().map(|v| v.title.to_string()),
-            track: tag.track().0.map(|v| v as i32),
+            title: tag.title().map(|v| truncate(v, NAME_MAX_LENGTH)),
+            album: tag.album_title().map(|v| truncate(v, NAME_MAX_LENGTH)),
+            album_id: None,
+            album_artist: tag.album_artist().map(|v| truncate(v, NAME_MAX_LENGTH)),
+            track: tag.track_number().map(|v| v as i32),
+            total_tracks: tag.total_tracks().map(|v| v as i32),
+            disc: tag.disc_number().map(|v| v as i32),
+            total_discs: tag.total_discs().map(|v| v as i32),
             artist: tag.artist().map(|v| v.to_string()),
+            artists: None,
+            composer: tag.composer().map(|v| truncate(v, NAME_MAX_LENGTH)),
+            genre: tag.genre().map(|v| truncate(v, GENRE_MAX_LENGTH)),
+            year: tag.year(),
             duration: tag.duration().map(|v| v as i32),
+            comment: None,
+            lyrics: None,
+            original_name: None,
+            score: None,
+        };
+        let tags = FormatTags::from(tag);
+
+        Ok(Self(server_lib::models::Song {
+            artist: (tags.artists.as_deref())
+                .and_then(Self::join_artists)
+                .or_else(|| song.artist.clone()),
+            artists: tags.artists,
+            comment: tags.comment,
+            lyrics: tags.lyrics,
+            ..song
         }))
     }
 }
+
+/// Tags that aren't part of the common tag interface, read from the tag of each format.
+struct FormatTags {
+    /// Values of the artist tag, several values are allowed by ID3v2.4, FLAC and MP4.
+    artists: Option<Vec<String>>,
+    comment: Option<String>,
+    lyrics: Option<String>,
+}
+
+impl From<Box<dyn AudioTag>> for FormatTags {
+    fn from(tag: Box<dyn AudioTag>) -> Self {
+        let text = |value: Option<&str>| {
+            value
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let texts = |values: Vec<&str>| {
+            let values: Vec<String> = values.into_iter().filter_map(|v| text(Some(v))).collect();
+            (!values.is_empty()).then_some(values)
+        };
+
+        if tag.to_any().is::<Id3v2Tag>() {
+            let tag = id3::Tag::from(tag);
+            let comment = text(tag.comments().map(|comment| comment.text.as_str()).next());
+            let lyrics = text(tag.lyrics().map(|lyrics| lyrics.text.as_str()).next());
+            Self {
+                artists: tag.artists().and_then(texts),
+                comment,
+                lyrics,
+            }
+        } else if tag.to_any().is::<FlacTag>() {
+            let tag = metaflac::Tag::from(tag);
+            let vorbis = |key: &str| text(tag.get_vorbis(key).and_then(|mut values| values.next()));
+            Self {
+                artists: tag
+                    .get_vorbis("ARTIST")
+                    .and_then(|values| texts(values.collect())),
+                comment: vorbis("COMMENT"),
+                lyrics: vorbis("LYRICS"),
+            }
+        } else if tag.to_any().is::<Mp4Tag>() {
+            let tag = mp4ameta::Tag::from(tag);
+            Self {
+                artists: texts(tag.artists().collect()),
+                comment: text(tag.comment()),
+                lyrics: text(tag.lyrics()),
+            }
+        } else {
+            Self {
+                artists: tag.artists().and_then(texts),
+                comment: None,
+                lyrics: None,
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::database::Database;
+use crate::index::TantivyIndex;
+use crate::library::Library;
+use anyhow::{Context, Result};
+use log::{debug, info, warn};
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Events on a file are merged until it is left untouched for this delay,
+/// so that a file being copied is read once complete.
+const DEBOUNCE_DELAY: Duration = Duration::from_secs(2);
+
+/// Watch of the library folder, stopped when dropped.
+pub(crate) struct LibraryWatcher {
+    _debouncer: Debouncer<RecommendedWatcher>,
+}
+
+impl Library {
+    /// Keep database and index in sync with files added, modified, moved or deleted
+    /// in the library folder outside the server.
+    pub(crate) fn watch(
+        &self,
+        database: Arc<Database>,
+        index: Arc<TantivyIndex>,
+    ) -> Result<LibraryWatcher> {
+        let folder = self
+            .library
+            .canonicalize()
+            .with_context(|| format!("Can't access {}", self.library.display()))?;
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let mut debouncer = new_debouncer(DEBOUNCE_DELAY, sender)?;
+        debouncer
+            .watcher()
+            .watch(&folder, RecursiveMode::Recursive)
+            .with_context(|| format!("Can't watch {}", folder.display()))?;
+
+        let library = self.clone();
+        std::thread::Builder::new()
+            .name("library-watcher".to_string())
+            .spawn(move || library.apply_events(receiver, &database, &index))
+            .context("Can't start library watcher")?;
+
+        info!("Watching {}", folder.display());
+        Ok(LibraryWatcher {
+            _debouncer: debouncer,
+        })
+    }
+
+    /// Synchronize the paths of each batch of events, until the watcher is dropped.
+    fn apply_events(
+        &self,
+        receiver: Receiver<DebounceEventResult>,
+        database: &Database,
+        index: &TantivyIndex,
+    ) {
+        for events in receiver {
+            let paths = match events {
+                Ok(events) => events
+                    .into_iter()
+                    .map(|event| event.path)
+                    .collect::<BTreeSet<PathBuf>>(),
+                Err(error) => {
+                    warn!("Error watching library : {error:?}");
+                    continue;
+                }
+            };
+
+            debug!("Library changes : {paths:?}");
+            let paths = paths.into_iter().collect::<Vec<_>>();
+            match self.synchronize_paths(&paths, database, index) {
+                Ok(report) => info!("Library changes applied : {report:?}"),
+                Err(error) => warn!("Can't apply library changes : {error:?}"),
+            }
+        }
+        debug!("Library watcher stopped");
+    }
+}
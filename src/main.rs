@@ -1,9 +1,10 @@
 use anyhow::{Context, Result};
 use clap::Parser;
-use config::CommandLine;
+use config::{Command, CommandLine};
 use log4rs::config::Deserializers;
 use std::default::Default;
 
+mod cli;
 mod config;
 mod database;
 mod index;
@@ -11,6 +12,7 @@ mod library;
 mod server;
 
 static METRIC_DISALLOWED_PATH: &str = "disallowed_path_counter";
+static METRIC_INDEXER_LAG: &str = "indexer_lag";
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -22,9 +24,15 @@ async fn main() -> Result<()> {
     log4rs::init_file(&path, Deserializers::default())
         .with_context(|| format!("log file {path}"))?;
 
+    match cli.command() {
+        Some(Command::User(command)) => return cli::user(command, &config),
+        Some(Command::Scan { directory }) => return cli::scan(directory, &config),
+        None => {}
+    }
+
     let path = config.indexing().path();
-    let index =
-        index::init_index(&path).with_context(|| format!("Index folder {}", path.display()))?;
+    let index = index::init_index(config.indexing())
+        .with_context(|| format!("Index folder {}", path.display()))?;
 
     server::create(&config.listen(), index, config).await?;
 
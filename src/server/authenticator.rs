@@ -0,0 +1,250 @@
+use crate::database::Database;
+use crate::server::{ServiceError, OPENAPI_URL};
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use hyper::header::WWW_AUTHENTICATE;
+use hyper::service::Service;
+use hyper::{Body, Method, Request, Response, StatusCode};
+use log::{debug, warn};
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeSet, HashMap};
+use std::hash::BuildHasher;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use swagger::auth::{AuthData, Authorization, Scopes};
+use swagger::{Has, Push, XSpanIdString};
+
+static REALM: &str = "Basic realm=\"partition\", charset=\"UTF-8\"";
+
+/// Scope granted to administrators.
+pub(crate) static ADMIN_SCOPE: &str = "admin";
+
+/// How long a successful authentication is remembered. Players send many range requests
+/// while streaming a song, each of them would otherwise verify the password again.
+/// Changes to a user are taken into account once it expires.
+const CREDENTIALS_TTL: Duration = Duration::from_secs(30);
+
+/// Successful authentications, by user name and hash of the password.
+///
+/// Passwords are hashed with a random key, so that the cache never holds them in clear
+/// and its keys can't be computed outside of the process.
+#[derive(Default)]
+struct Credentials {
+    hasher: RandomState,
+    authorizations: Mutex<HashMap<(String, u64), (Authorization, Instant)>>,
+}
+
+impl Credentials {
+    fn key(&self, username: &str, password: &str) -> (String, u64) {
+        (username.to_string(), self.hasher.hash_one(password))
+    }
+
+    fn get(&self, username: &str, password: &str) -> Option<Authorization> {
+        let key = self.key(username, password);
+        let authorizations = self.authorizations.lock().ok()?;
+        authorizations
+            .get(&key)
+            .filter(|(_, added)| added.elapsed() < CREDENTIALS_TTL)
+            .map(|(authorization, _)| authorization.clone())
+    }
+
+    fn insert(&self, username: &str, password: &str, authorization: Authorization) {
+        let key = self.key(username, password);
+        if let Ok(mut authorizations) = self.authorizations.lock() {
+            authorizations.retain(|_, (_, added)| added.elapsed() < CREDENTIALS_TTL);
+            authorizations.insert(key, (authorization, Instant::now()));
+        }
+    }
+}
+
+/// Authenticate requests with HTTP basic authentication against the users table.
+pub struct MakeAuthenticatorService<Inner, RC> {
+    inner: Inner,
+    database: Arc<Database>,
+    credentials: Arc<Credentials>,
+    public_metrics: bool,
+    marker: PhantomData<RC>,
+}
+
+impl<Inner, RC> MakeAuthenticatorService<Inner, RC> {
+    pub fn new(inner: Inner, database: Arc<Database>, public_metrics: bool) -> Self {
+        Self {
+            inner,
+            database,
+            credentials: Arc::default(),
+            public_metrics,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Inner, RC, Target> Service<Target> for MakeAuthenticatorService<Inner, RC>
+where
+    Inner: Service<Target>,
+    Inner::Future: Send + 'static,
+{
+    type Response = AuthenticatorService<Inner::Response, RC>;
+    type Error = Inner::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, target: Target) -> Self::Future {
+        let database = self.database.clone();
+        let credentials = self.credentials.clone();
+        let public_metrics = self.public_metrics;
+        Box::pin(self.inner.call(target).map(move |s| {
+            Ok(AuthenticatorService::new(
+                s?,
+                database,
+                credentials,
+                public_metrics,
+            ))
+        }))
+    }
+}
+
+pub struct AuthenticatorService<Inner, C> {
+    inner: Inner,
+    database: Arc<Database>,
+    credentials: Arc<Credentials>,
+    public_metrics: bool,
+    marker: PhantomData<C>,
+}
+
+impl<Inner, C> AuthenticatorService<Inner, C> {
+    fn new(
+        inner: Inner,
+        database: Arc<Database>,
+        credentials: Arc<Credentials>,
+        public_metrics: bool,
+    ) -> Self {
+        Self {
+            inner,
+            database,
+            credentials,
+            public_metrics,
+            marker: PhantomData,
+        }
+    }
+
+    /// Paths that can be accessed without credentials.
+    fn is_public(&self, path: &str) -> bool {
+        path.is_empty()
+            || path == "/"
+            || path == "/favicon.ico"
+            || path == "/ui"
+            || path.starts_with("/ui/")
+            || path == OPENAPI_URL
+            || (self.public_metrics && path == "/metrics")
+    }
+}
+
+/// Check the credentials of a request, remembered for a while once valid.
+async fn authenticate(
+    database: Arc<Database>,
+    credentials: Arc<Credentials>,
+    auth_data: Option<AuthData>,
+) -> Option<Authorization> {
+    let Some(AuthData::Basic(basic)) = auth_data else {
+        debug!("No basic authentication provided");
+        return None;
+    };
+    let username = basic.username;
+    let password = basic.password.unwrap_or_default();
+
+    if let Some(authorization) = credentials.get(&username, &password) {
+        return Some(authorization);
+    }
+
+    // Database and password hashing are blocking
+    let user = tokio::task::spawn_blocking({
+        let username = username.clone();
+        let password = password.clone();
+        move || database.authenticate_user(&username, &password)
+    })
+    .await;
+
+    match user {
+        Ok(Ok(Some(user))) => {
+            let mut scopes = BTreeSet::new();
+            if user.admin() {
+                scopes.insert(ADMIN_SCOPE.to_string());
+            }
+            let authorization = Authorization {
+                subject: user.user_id().to_string(),
+                scopes: Scopes::Some(scopes),
+                issuer: None,
+            };
+            credentials.insert(&username, &password, authorization.clone());
+            Some(authorization)
+        }
+        Ok(Ok(None)) => {
+            warn!("Authentication failed for user '{username}'");
+            None
+        }
+        Ok(Err(error)) => {
+            warn!("Can't authenticate user '{username}' : {error:?}");
+            None
+        }
+        Err(error) => {
+            warn!("Can't authenticate user '{username}' : {error:?}");
+            None
+        }
+    }
+}
+
+impl<Inner, RC> Service<(Request<Body>, RC)> for AuthenticatorService<Inner, RC>
+where
+    RC: Has<XSpanIdString> + Has<Option<AuthData>> + Push<Option<Authorization>> + Send + 'static,
+    RC::Result: Send + 'static,
+    Inner: Service<(Request<Body>, RC::Result), Response = Response<Body>, Error = ServiceError>
+        + Clone
+        + Send
+        + 'static,
+    Inner::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = ServiceError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: (Request<Body>, RC)) -> Self::Future {
+        let (request, context) = req;
+
+        // CORS preflight requests never carry credentials.
+        let public = request.method() == Method::OPTIONS || self.is_public(request.uri().path());
+        if public {
+            let context = context.push(None);
+            return Box::pin(self.inner.call((request, context)));
+        }
+
+        let auth_data = <RC as Has<Option<AuthData>>>::get(&context).clone();
+        let database = self.database.clone();
+        let credentials = self.credentials.clone();
+        let mut inner = self.inner.clone();
+        Box::pin(async move {
+            let authorization = authenticate(database, credentials, auth_data).await;
+            if authorization.is_none() {
+                let xspanid = <RC as Has<XSpanIdString>>::get(&context).0.clone();
+                let response = Response::builder()
+                    .status(StatusCode::UNAUTHORIZED)
+                    .header("x-span-id", xspanid.as_str())
+                    .header(WWW_AUTHENTICATE, REALM)
+                    .body(Body::empty())
+                    .expect("Unable to build response");
+                return Ok(response);
+            }
+
+            let context = context.push(authorization);
+            inner.call((request, context)).await
+        })
+    }
+}
@@ -1,76 +1,449 @@
-use crate::index::TantivyIndex;
-use crate::library::Library;
+use crate::database::{Database, DatabaseError, Playlist, Sort};
+use crate::index::{FacetFilter, SearchError, SearchSort, TantivyIndex};
+use crate::library::{near_duplicates, Library, UnsupportedFile};
+use crate::server::authenticator::ADMIN_SCOPE;
+use crate::METRIC_DISALLOWED_PATH;
 use anyhow::Result;
 use async_trait::async_trait;
-use base64::Engine;
 use function_timer::time;
 use log::{debug, info, warn};
+use metrics::increment_counter;
 use server_lib::models::Informations;
 use server_lib::{
-    models, Api, PlaylistsGetResponse, PlaylistsIdDeleteResponse, PlaylistsIdGetResponse,
-    PlaylistsPostResponse, RootGetResponse, SearchGetResponse, SongsIdDeleteResponse,
-    SongsIdGetResponse, SongsIdPutResponse, SongsPostResponse,
+    models, AlbumsGetResponse, AlbumsIdGetResponse, AlbumsIndexGetResponse, Api,
+    ArtistsGetResponse, ArtistsIdGetResponse, ArtistsIndexGetResponse, LibraryScanPostResponse,
+    PlaylistsGetResponse, PlaylistsIdDeleteResponse, PlaylistsIdGetResponse, PlaylistsPostResponse,
+    RootGetResponse, SearchGetResponse, SongsDuplicatesGetResponse, SongsIdDeleteResponse,
+    SongsIdGetResponse, SongsIdPutResponse, SongsPostResponse, SuggestGetResponse,
+    UsersGetResponse, UsersIdDeleteResponse, UsersIdPutResponse, UsersPostResponse,
 };
 use std::marker::PhantomData;
 use std::sync::Arc;
-use swagger::{ApiError, Has, XSpanIdString};
+use swagger::auth::Scopes;
+use swagger::{ApiError, Authorization, Has, Nullable, XSpanIdString};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Maximum length of a user name, see `users` table.
+const USER_NAME_MAX_LENGTH: usize = 50;
+/// Maximum length of a playlist name, see `playlists` table.
+const PLAYLIST_NAME_MAX_LENGTH: usize = 50;
+/// Number of artists or albums returned when browsing without limit.
+const BROWSE_DEFAULT_LIMIT: i32 = 50;
+/// Number of songs returned by a search without limit.
+const SEARCH_DEFAULT_LIMIT: i32 = 10;
+/// Number of suggestions of each kind returned without limit.
+const SUGGEST_DEFAULT_LIMIT: i32 = 5;
+/// Maximum number of suggestions of each kind.
+const SUGGEST_MAX_LIMIT: i32 = 20;
+
+/// Tell if the authenticated user is an administrator.
+fn is_admin<C: Has<Option<Authorization>>>(context: &C) -> bool {
+    match Has::<Option<Authorization>>::get(context) {
+        Some(authorization) => match &authorization.scopes {
+            Scopes::Some(scopes) => scopes.contains(ADMIN_SCOPE),
+            Scopes::All => true,
+        },
+        None => false,
+    }
+}
+
+/// Name of the authenticated user.
+fn authenticated_user<C: Has<Option<Authorization>>>(context: &C) -> Result<&str, ApiError> {
+    match Has::<Option<Authorization>>::get(context) {
+        Some(authorization) => Ok(&authorization.subject),
+        None => {
+            warn!("No authenticated user");
+            Err(ApiError("No authenticated user".to_string()))
+        }
+    }
+}
+
+fn is_valid_user_name(name: &str) -> bool {
+    !name.trim().is_empty() && name.chars().count() <= USER_NAME_MAX_LENGTH
+}
+
+fn is_valid_playlist_name(name: &str) -> bool {
+    !name.trim().is_empty() && name.chars().count() <= PLAYLIST_NAME_MAX_LENGTH
+}
+
+/// Facet filters of a search, like `genre:Jazz`.
+fn facet_filters(facet: Option<&Vec<String>>) -> Result<Vec<FacetFilter>, SearchError> {
+    facet
+        .map(|values| values.iter().map(|value| value.parse()).collect())
+        .unwrap_or_else(|| Ok(Vec::new()))
+}
+
+fn query_error(code: &str, message: String) -> models::QueryError {
+    models::QueryError {
+        code: Some(code.to_string()),
+        message: Some(message),
+    }
+}
+
+/// Page requested when browsing artists or albums, `None` if the sort order is unknown
+/// or the limit or offset negative.
+fn browse_page(
+    limit: Option<i32>,
+    offset: Option<i32>,
+    sort: Option<String>,
+) -> Option<(Sort, i64, i64)> {
+    let sort = match sort {
+        Some(sort) => match sort.parse() {
+            Ok(sort) => sort,
+            Err(error) => {
+                debug!("{error}");
+                return None;
+            }
+        },
+        None => Sort::default(),
+    };
+    let limit = limit.unwrap_or(BROWSE_DEFAULT_LIMIT);
+    let offset = offset.unwrap_or(0);
+    if limit < 0 || offset < 0 {
+        debug!("Negative limit {limit} or offset {offset}");
+        return None;
+    }
+    Some((sort, i64::from(offset), i64::from(limit)))
+}
+
+/// Offset and limit of the songs requested by a search, an error if one of them is negative.
+fn search_page(limit: Option<i32>, offset: Option<i32>) -> Result<(usize, usize), SearchError> {
+    let limit = limit.unwrap_or(SEARCH_DEFAULT_LIMIT);
+    let offset = offset.unwrap_or(0);
+    match (usize::try_from(offset), usize::try_from(limit)) {
+        (Ok(offset), Ok(limit)) => Ok((offset, limit)),
+        _ => Err(SearchError::InvalidQuery {
+            code: "invalid_page",
+            message: format!("Negative limit {limit} or offset {offset}"),
+        }),
+    }
+}
+
 #[derive(Clone)]
 pub struct Server<C> {
     index: Arc<TantivyIndex>,
     library: Library,
+    database: Arc<Database>,
     marker: PhantomData<C>,
 }
 
 impl<C> Server<C> {
-    pub(crate) fn new(tantivy_index: TantivyIndex, library: Library) -> Result<Self> {
+    pub(crate) fn new(
+        index: Arc<TantivyIndex>,
+        library: Library,
+        database: Arc<Database>,
+    ) -> Result<Self> {
         library.create_folder()?;
         Ok(Server {
-            index: Arc::new(tantivy_index),
+            index,
             library,
+            database,
             marker: PhantomData,
         })
     }
+
+    /// Evaluate the query of a dynamic playlist.
+    fn resolve(&self, playlist: Playlist) -> Result<Playlist, ApiError> {
+        let id = playlist.id;
+        playlist.resolve(&self.index).map_err(|error| {
+            warn!("Can't resolve playlist {id} : {error:?}");
+            ApiError(format!("Can't resolve playlist {id} : {error}"))
+        })
+    }
 }
 
 #[async_trait]
 #[time("api_time")]
 impl<C> Api<C> for Server<C>
 where
-    C: Has<XSpanIdString> + Send + Sync,
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync,
 {
-    async fn playlists_get(&self, _context: &C) -> Result<PlaylistsGetResponse, ApiError> {
+    async fn albums_get(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<String>,
+        _context: &C,
+    ) -> Result<AlbumsGetResponse, ApiError> {
+        info!("albums_get({limit:?}, {offset:?}, {sort:?})");
+        let Some((sort, offset, limit)) = browse_page(limit, offset, sort) else {
+            return Ok(AlbumsGetResponse::WrongData);
+        };
+
+        let albums = self.database.albums(sort, offset, limit).map_err(|error| {
+            warn!("Can't list albums : {error:?}");
+            ApiError(format!("Can't list albums : {error}"))
+        })?;
+
+        Ok(AlbumsGetResponse::ListOfAlbums {
+            body: albums.items.into_iter().map(|album| album.into()).collect(),
+            x_total_count: i64::try_from(albums.total).ok(),
+        })
+    }
+
+    async fn albums_id_get(&self, id: i32, _context: &C) -> Result<AlbumsIdGetResponse, ApiError> {
+        info!("albums_id_get({id})");
+
+        let album = self.database.album(id).map_err(|error| {
+            warn!("Can't get album {id} : {error:?}");
+            ApiError(format!("Can't get album {id} : {error}"))
+        })?;
+
+        match album {
+            Some(album) => Ok(AlbumsIdGetResponse::Album(album.into())),
+            None => Ok(AlbumsIdGetResponse::UnknownAlbum),
+        }
+    }
+
+    async fn albums_index_get(&self, _context: &C) -> Result<AlbumsIndexGetResponse, ApiError> {
+        info!("albums_index_get()");
+
+        let buckets = self.database.albums_index().map_err(|error| {
+            warn!("Can't index albums : {error:?}");
+            ApiError(format!("Can't index albums : {error}"))
+        })?;
+
+        Ok(AlbumsIndexGetResponse::AlphabeticalIndex(
+            buckets.into_iter().map(|bucket| bucket.into()).collect(),
+        ))
+    }
+
+    async fn artists_get(
+        &self,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        sort: Option<String>,
+        _context: &C,
+    ) -> Result<ArtistsGetResponse, ApiError> {
+        info!("artists_get({limit:?}, {offset:?}, {sort:?})");
+        let Some((sort, offset, limit)) = browse_page(limit, offset, sort) else {
+            return Ok(ArtistsGetResponse::WrongData);
+        };
+
+        let artists = self
+            .database
+            .artists(sort, offset, limit)
+            .map_err(|error| {
+                warn!("Can't list artists : {error:?}");
+                ApiError(format!("Can't list artists : {error}"))
+            })?;
+
+        Ok(ArtistsGetResponse::ListOfArtists {
+            body: artists
+                .items
+                .into_iter()
+                .map(|artist| artist.into())
+                .collect(),
+            x_total_count: i64::try_from(artists.total).ok(),
+        })
+    }
+
+    async fn artists_id_get(
+        &self,
+        id: i32,
+        _context: &C,
+    ) -> Result<ArtistsIdGetResponse, ApiError> {
+        info!("artists_id_get({id})");
+
+        let artist = self.database.artist(id).map_err(|error| {
+            warn!("Can't get artist {id} : {error:?}");
+            ApiError(format!("Can't get artist {id} : {error}"))
+        })?;
+
+        match artist {
+            Some(artist) => Ok(ArtistsIdGetResponse::Artist(artist.into())),
+            None => Ok(ArtistsIdGetResponse::UnknownArtist),
+        }
+    }
+
+    async fn artists_index_get(&self, _context: &C) -> Result<ArtistsIndexGetResponse, ApiError> {
+        info!("artists_index_get()");
+
+        let buckets = self.database.artists_index().map_err(|error| {
+            warn!("Can't index artists : {error:?}");
+            ApiError(format!("Can't index artists : {error}"))
+        })?;
+
+        Ok(ArtistsIndexGetResponse::AlphabeticalIndex(
+            buckets.into_iter().map(|bucket| bucket.into()).collect(),
+        ))
+    }
+
+    async fn library_scan_post(
+        &self,
+        path: Option<String>,
+        context: &C,
+    ) -> Result<LibraryScanPostResponse, ApiError> {
+        info!("library_scan_post({path:?})");
+        if !is_admin(context) {
+            return Ok(LibraryScanPostResponse::Forbidden);
+        }
+
+        // Only folders of the library can be scanned, songs are streamed from where they are
+        let library_path = self.library.library_path();
+        let directory = match &path {
+            Some(path) => library_path.join(path),
+            None => library_path.clone(),
+        };
+        let directory = match (directory.canonicalize(), library_path.canonicalize()) {
+            (Ok(directory), Ok(library_path)) if directory.starts_with(&library_path) => directory,
+            _ => {
+                debug!("{} is not a folder of the library", directory.display());
+                return Ok(LibraryScanPostResponse::WrongData);
+            }
+        };
+        if !directory.is_dir() {
+            debug!("{} is not a directory", directory.display());
+            return Ok(LibraryScanPostResponse::WrongData);
+        }
+
+        // Reading tags, database and index are blocking
+        let library = self.library.clone();
+        let database = self.database.clone();
+        let index = self.index.clone();
+        let report =
+            tokio::task::spawn_blocking(move || library.scan(&directory, &database, &index))
+                .await
+                .map_err(|error| ApiError(format!("Can't scan : {error}")))?
+                .map_err(|error| {
+                    warn!("Can't scan : {error:?}");
+                    ApiError(format!("Can't scan : {error}"))
+                })?;
+
+        match report {
+            Some(report) => Ok(LibraryScanPostResponse::ScanDone(report.into())),
+            None => Ok(LibraryScanPostResponse::ScanAlreadyRunning),
+        }
+    }
+
+    async fn playlists_get(&self, context: &C) -> Result<PlaylistsGetResponse, ApiError> {
         info!("playlists_get()");
-        Err(ApiError("Generic failure".into()))
+        let user = authenticated_user(context)?;
+
+        let playlists = self.database.playlists(user).map_err(|error| {
+            warn!("Can't list playlists : {error:?}");
+            ApiError(format!("Can't list playlists : {error}"))
+        })?;
+
+        Ok(PlaylistsGetResponse::ListAllPlaylists(
+            playlists
+                .into_iter()
+                .map(|playlist| playlist.into())
+                .collect(),
+        ))
     }
 
     async fn playlists_id_delete(
         &self,
         id: i32,
-        _context: &C,
+        context: &C,
     ) -> Result<PlaylistsIdDeleteResponse, ApiError> {
         info!("playlists_id_delete({id})");
-        Err(ApiError("Generic failure".into()))
+        let user = authenticated_user(context)?;
+
+        match self.database.delete_playlist(user, id) {
+            Ok(true) => Ok(PlaylistsIdDeleteResponse::PlaylistDeleted),
+            Ok(false) => Ok(PlaylistsIdDeleteResponse::UnknownPlaylist),
+            Err(DatabaseError::Forbidden(message)) => {
+                debug!("{message}");
+                Ok(PlaylistsIdDeleteResponse::PlaylistSharedByAnotherUser)
+            }
+            Err(error) => {
+                warn!("Can't delete playlist {id} : {error:?}");
+                Err(ApiError(format!("Can't delete playlist {id} : {error}")))
+            }
+        }
     }
 
     async fn playlists_id_get(
         &self,
         id: i32,
-        _context: &C,
+        context: &C,
     ) -> Result<PlaylistsIdGetResponse, ApiError> {
         info!("playlists_id_get({id})");
-        Err(ApiError("Generic failure".into()))
+        let user = authenticated_user(context)?;
+
+        let playlist = self.database.playlist(user, id).map_err(|error| {
+            warn!("Can't get playlist {id} : {error:?}");
+            ApiError(format!("Can't get playlist {id} : {error}"))
+        })?;
+
+        match playlist {
+            Some(playlist) => Ok(PlaylistsIdGetResponse::Playlist(
+                self.resolve(playlist)?.into(),
+            )),
+            None => Ok(PlaylistsIdGetResponse::UnknownPlaylist),
+        }
     }
 
     async fn playlists_post(
         &self,
         playlist: models::Playlist,
-        _context: &C,
+        context: &C,
     ) -> Result<PlaylistsPostResponse, ApiError> {
         info!("playlists_post({playlist:?})");
-        Err(ApiError("Generic failure".into()))
+        let user = authenticated_user(context)?;
+
+        let Some(name) = playlist
+            .name
+            .as_deref()
+            .filter(|name| is_valid_playlist_name(name))
+        else {
+            return Ok(PlaylistsPostResponse::WrongData);
+        };
+        let query = match &playlist.query {
+            Some(Nullable::Present(query)) => Some(query.as_str()),
+            _ => None,
+        };
+        if !query
+            .map(|query| self.index.is_valid_query(query))
+            .unwrap_or(true)
+        {
+            return Ok(PlaylistsPostResponse::WrongData);
+        }
+        let songs: Option<Vec<i32>> = playlist
+            .songs
+            .unwrap_or_default()
+            .iter()
+            .map(|song| song.id)
+            .collect();
+        let Some(songs) = songs else {
+            debug!("Songs of a playlist must have an id");
+            return Ok(PlaylistsPostResponse::WrongData);
+        };
+
+        let id = match self.database.add_playlist(
+            user,
+            name,
+            query,
+            playlist.shared.unwrap_or(false),
+            &songs,
+        ) {
+            Ok(id) => id,
+            Err(DatabaseError::NotFound(message)) => {
+                debug!("{message}");
+                return Ok(PlaylistsPostResponse::WrongData);
+            }
+            Err(error) => {
+                warn!("Can't add playlist \"{name}\" : {error:?}");
+                return Err(ApiError(format!("Can't add playlist \"{name}\" : {error}")));
+            }
+        };
+
+        match self.database.playlist(user, id) {
+            Ok(Some(playlist)) => Ok(PlaylistsPostResponse::PlaylistSuccessfullyCreated(
+                self.resolve(playlist)?.into(),
+            )),
+            Ok(None) => {
+                warn!("Playlist {id} not found after creation");
+                Err(ApiError(format!("Playlist {id} not found after creation")))
+            }
+            Err(error) => {
+                warn!("Can't get playlist {id} : {error:?}");
+                Err(ApiError(format!("Can't get playlist {id} : {error}")))
+            }
+        }
     }
 
     async fn root_get(&self, _context: &C) -> Result<RootGetResponse, ApiError> {
@@ -84,21 +457,80 @@ where
         q: String,
         limit: Option<i32>,
         offset: Option<i32>,
+        sort: Option<String>,
+        facet: Option<&Vec<String>>,
+        facets: Option<bool>,
         _context: &C,
     ) -> Result<SearchGetResponse, ApiError> {
-        info!("search_get(\"{}\", {:?}, {:?})", q, limit, offset);
+        info!(
+            "search_get(\"{}\", {:?}, {:?}, {:?}, {:?}, {:?})",
+            q, limit, offset, sort, facet, facets
+        );
 
-        let limit = limit.unwrap_or(10);
-        let limit = usize::try_from(limit).unwrap_or(0);
-        let offset = offset.unwrap_or(0);
-        let offset = usize::try_from(offset).unwrap_or(0);
+        let result = search_page(limit, offset).and_then(|(offset, limit)| {
+            let sort = sort.as_deref().map(str::parse).transpose()?;
+            let sort = sort.unwrap_or(SearchSort::Relevance);
+            let filters = facet_filters(facet)?;
+            let facets = facets.unwrap_or(false);
+            self.index.search(&q, &filters, sort, offset, limit, facets)
+        });
 
-        self.index.search(q, offset, limit).map_err(|error| {
-            warn!("Error while searching : {error:?}");
-            ApiError(format!("Error while searching : {error:?}"))
+        let counts = |counts: Vec<(String, u64)>| {
+            Some(
+                counts
+                    .into_iter()
+                    .map(|(value, count)| models::FacetCount {
+                        value: Some(value),
+                        count: i64::try_from(count).ok(),
+                    })
+                    .collect(),
+            )
+        };
+        match result {
+            Ok(result) => Ok(SearchGetResponse::SongsMatchingQuery {
+                body: models::SearchResult {
+                    songs: Some(result.songs.into_iter().map(|song| song.into()).collect()),
+                    facets: result.facets.map(|facets| models::Facets {
+                        genres: counts(facets.genres),
+                        decades: counts(facets.decades),
+                        artists: counts(facets.artists),
+                        albums: counts(facets.albums),
+                    }),
+                },
+                x_total_count: i64::try_from(result.total).ok(),
+            }),
+            Err(SearchError::InvalidQuery { code, message }) => {
+                debug!("Invalid query \"{q}\" : {message}");
+                Ok(SearchGetResponse::InvalidQuery(query_error(code, message)))
+            }
+            Err(SearchError::Index(error)) => {
+                warn!("Error while searching : {error:?}");
+                Err(ApiError(format!("Error while searching : {error:?}")))
+            }
+        }
+    }
+
+    async fn songs_duplicates_get(
+        &self,
+        _context: &C,
+    ) -> Result<SongsDuplicatesGetResponse, ApiError> {
+        info!("songs_duplicates_get()");
+
+        let songs = self.database.songs().map_err(|error| {
+            warn!("Can't list songs : {error:?}");
+            ApiError(format!("Can't list songs : {error}"))
         })?;
 
-        Err(ApiError("Generic failure".into()))
+        Ok(SongsDuplicatesGetResponse::NearDuplicates(
+            near_duplicates(songs)
+                .into_iter()
+                .map(|songs| models::NearDuplicates {
+                    artist: songs.first().map(|song| song.artist()),
+                    title: songs.first().map(|song| song.title()),
+                    songs: Some(songs.into_iter().map(|song| song.into()).collect()),
+                })
+                .collect(),
+        ))
     }
 
     async fn songs_id_delete(
@@ -107,56 +539,305 @@ where
         _context: &C,
     ) -> Result<SongsIdDeleteResponse, ApiError> {
         info!("songs_id_delete({id})");
-        Err(ApiError("Generic failure".into()))
+
+        // Database, file and index changes are blocking
+        let library = self.library.clone();
+        let database = self.database.clone();
+        let index = self.index.clone();
+        let deleted =
+            tokio::task::spawn_blocking(move || library.delete_song(id, &database, &index))
+                .await
+                .map_err(|error| ApiError(format!("Can't delete song {id} : {error}")))?
+                .map_err(|error| {
+                    warn!("Can't delete song {id} : {error:?}");
+                    ApiError(format!("Can't delete song {id} : {error}"))
+                })?;
+
+        if deleted {
+            Ok(SongsIdDeleteResponse::SongDeleted)
+        } else {
+            Ok(SongsIdDeleteResponse::UnknownSong)
+        }
     }
 
     async fn songs_id_get(&self, id: i32, _context: &C) -> Result<SongsIdGetResponse, ApiError> {
         info!("songs_id_get({id})");
-        Err(ApiError("Generic failure".into()))
+
+        let song = self.database.song(id).map_err(|error| {
+            warn!("Can't get song {id} : {error:?}");
+            ApiError(format!("Can't get song {id} : {error}"))
+        })?;
+
+        match song {
+            Some((song, _)) => Ok(SongsIdGetResponse::SongMetadata(song.into())),
+            None => Ok(SongsIdGetResponse::UnknownSong),
+        }
     }
 
     async fn songs_id_put(
         &self,
         id: i32,
-        playlist: models::Playlist,
+        write_tags: Option<bool>,
+        song: models::Song,
         _context: &C,
     ) -> Result<SongsIdPutResponse, ApiError> {
-        info!("songs_id_put({id}, {playlist:?})");
-        Err(ApiError("Generic failure".into()))
+        info!("songs_id_put({id}, {write_tags:?}, {song:?})");
+
+        let is_blank = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(|value| value.trim().is_empty())
+                .unwrap_or(false)
+        };
+        if song.id.map(|song_id| song_id != id).unwrap_or(false)
+            || is_blank(&song.title)
+            || is_blank(&song.album)
+            || is_blank(&song.artist)
+            || is_blank(&song.album_artist)
+            || [
+                song.track,
+                song.total_tracks,
+                song.disc,
+                song.total_discs,
+                song.year,
+            ]
+            .iter()
+            .any(|number| number.map(|number| number < 0).unwrap_or(false))
+        {
+            return Ok(SongsIdPutResponse::WrongData);
+        }
+
+        // Database, tags and index changes are blocking
+        let library = self.library.clone();
+        let database = self.database.clone();
+        let index = self.index.clone();
+        let write_tags = write_tags.unwrap_or(false);
+        let song = tokio::task::spawn_blocking(move || {
+            library.update_song(id, &song.into(), None, write_tags, &database, &index)
+        })
+        .await
+        .map_err(|error| ApiError(format!("Can't update song {id} : {error}")))?
+        .map_err(|error| {
+            warn!("Can't update song {id} : {error:?}");
+            ApiError(format!("Can't update song {id} : {error}"))
+        })?;
+
+        match song {
+            Some(song) => Ok(SongsIdPutResponse::SongUpdated(song.into())),
+            None => Ok(SongsIdPutResponse::UnknownSong),
+        }
     }
 
+    /// Uploads are received by the upload endpoint, which streams them to disk.
+    /// This implementation is only reached when the generated service is used directly.
     async fn songs_post(
         &self,
         x_filename: String,
-        body: String,
+        body: swagger::ByteArray,
         _context: &C,
     ) -> Result<SongsPostResponse, ApiError> {
         info!("songs_post(\"{x_filename}\")");
-        let path = self.library.temporary_path().join(&x_filename);
-        let file = base64::engine::general_purpose::STANDARD
-            .decode(body)
-            .map_err(|error| {
-                warn!("Error uploading song : {error:?}");
-                ApiError(error.to_string())
-            })?;
-        std::fs::write(&path, file).map_err(|error| {
+        let Some(path) = self.library.upload_path(&x_filename) else {
+            warn!("File name \"{x_filename}\" is not allowed");
+            increment_counter!(METRIC_DISALLOWED_PATH);
+            return Ok(SongsPostResponse::WrongData);
+        };
+        let file = body.0;
+        if file.len() as u64 > self.library.max_upload_size() {
+            return Ok(SongsPostResponse::FileTooLarge);
+        }
+        tokio::fs::write(&path, file).await.map_err(|error| {
             warn!("Error uploading song : {error:?}");
             ApiError(error.to_string())
         })?;
 
-        let song = crate::library::Song::try_from(path)?;
+        let song = match self
+            .library
+            .add_song(&path, &x_filename, &self.database, &self.index)
+        {
+            Ok(song) => song,
+            Err(error) => {
+                if let Err(error) = std::fs::remove_file(&path) {
+                    warn!("Can't remove {} : {error:?}", path.display());
+                }
+                if error.is::<UnsupportedFile>() {
+                    debug!("\"{x_filename}\" isn't a supported audio file : {error}");
+                    return Ok(SongsPostResponse::FileFormatNotSupported);
+                }
+                if let Some(DatabaseError::DuplicateSong(id)) = error.downcast_ref() {
+                    debug!("\"{x_filename}\" is a duplicate of song {id}");
+                    let song = self.database.song(*id).map_err(|error| {
+                        warn!("Can't get song {id} : {error:?}");
+                        ApiError(format!("Can't get song {id} : {error}"))
+                    })?;
+                    let song = song
+                        .map(|(song, _)| song.into())
+                        .unwrap_or_else(models::Song::new);
+                    return Ok(SongsPostResponse::SongAlreadyExists(models::Song {
+                        id: Some(*id),
+                        ..song
+                    }));
+                }
+                warn!("Can't add \"{x_filename}\" : {error:?}");
+                return Err(ApiError(format!("Can't add \"{x_filename}\" : {error}")));
+            }
+        };
+        debug!("Add \"{x_filename}\" with id {:?}", song.id());
 
-        // TODO insert into database to get an id.
+        Ok(SongsPostResponse::SuccessfulOperation)
+    }
 
-        // TODO move file from tmp into library
+    async fn suggest_get(
+        &self,
+        q: String,
+        limit: Option<i32>,
+        _context: &C,
+    ) -> Result<SuggestGetResponse, ApiError> {
+        info!("suggest_get(\"{}\", {:?})", q, limit);
 
-        // Index
-        let op = self.index.index(song).map_err(|error| {
-            warn!("Can't index \"{x_filename}\" : {error:?}");
-            ApiError(format!("Can't index \"{x_filename}\" : {error:?}"))
+        // Suggestions are requested on each key stroke, keep them few
+        let limit = limit.unwrap_or(SUGGEST_DEFAULT_LIMIT);
+        let limit = usize::try_from(limit.min(SUGGEST_MAX_LIMIT)).unwrap_or(0);
+
+        let suggestions = self.index.suggest(&q, limit).map_err(|error| {
+            warn!("Can't suggest completions of \"{q}\" : {error:?}");
+            ApiError(format!("Can't suggest completions of \"{q}\" : {error}"))
         })?;
-        debug!("Index \"{x_filename}\" {op}");
 
-        Ok(SongsPostResponse::SuccessfulOperation)
+        Ok(SuggestGetResponse::Suggestions(models::Suggestions {
+            artists: Some(suggestions.artists),
+            albums: Some(suggestions.albums),
+            titles: Some(suggestions.titles),
+        }))
+    }
+
+    async fn users_get(&self, context: &C) -> Result<UsersGetResponse, ApiError> {
+        info!("users_get()");
+        if !is_admin(context) {
+            return Ok(UsersGetResponse::Forbidden);
+        }
+
+        let users = self.database.users().map_err(|error| {
+            warn!("Can't list users : {error:?}");
+            ApiError(format!("Can't list users : {error}"))
+        })?;
+
+        Ok(UsersGetResponse::ListOfUsers(
+            users.into_iter().map(|user| user.into()).collect(),
+        ))
+    }
+
+    async fn users_id_delete(
+        &self,
+        id: i32,
+        context: &C,
+    ) -> Result<UsersIdDeleteResponse, ApiError> {
+        info!("users_id_delete({id})");
+        if !is_admin(context) {
+            return Ok(UsersIdDeleteResponse::Forbidden);
+        }
+
+        match self.database.delete_user(id) {
+            Ok(true) => Ok(UsersIdDeleteResponse::UserDeleted),
+            Ok(false) => Ok(UsersIdDeleteResponse::UnknownUser),
+            Err(DatabaseError::Forbidden(message)) => {
+                debug!("{message}");
+                Ok(UsersIdDeleteResponse::LastAdministrator)
+            }
+            Err(error) => {
+                warn!("Can't delete user {id} : {error:?}");
+                Err(ApiError(format!("Can't delete user {id} : {error}")))
+            }
+        }
+    }
+
+    async fn users_id_put(
+        &self,
+        id: i32,
+        user: models::User,
+        context: &C,
+    ) -> Result<UsersIdPutResponse, ApiError> {
+        // Never log the password
+        info!("users_id_put({id}, {:?}, {:?})", user.name, user.admin);
+        if !is_admin(context) {
+            return Ok(UsersIdPutResponse::Forbidden);
+        }
+
+        let name = user.name.as_deref();
+        let password = user.password.as_deref();
+        if !name.map(is_valid_user_name).unwrap_or(true)
+            || password.map(str::is_empty).unwrap_or(false)
+        {
+            return Ok(UsersIdPutResponse::WrongData);
+        }
+
+        // Database and password hashing are blocking
+        let updated = tokio::task::spawn_blocking({
+            let database = self.database.clone();
+            let name = user.name.clone();
+            let password = user.password.clone();
+            move || database.update_user(id, name.as_deref(), password.as_deref(), user.admin)
+        })
+        .await
+        .map_err(|error| ApiError(format!("Can't update user {id} : {error}")))?;
+
+        match updated {
+            Ok(Some(user)) => Ok(UsersIdPutResponse::UserUpdated(user.into())),
+            Ok(None) => Ok(UsersIdPutResponse::UnknownUser),
+            Err(DatabaseError::AlreadyExists(message)) => {
+                debug!("{message}");
+                Ok(UsersIdPutResponse::UserNameAlreadyUsed)
+            }
+            Err(DatabaseError::Forbidden(message)) => {
+                debug!("{message}");
+                Ok(UsersIdPutResponse::LastAdministrator)
+            }
+            Err(error) => {
+                warn!("Can't update user {id} : {error:?}");
+                Err(ApiError(format!("Can't update user {id} : {error}")))
+            }
+        }
+    }
+
+    async fn users_post(
+        &self,
+        user: models::User,
+        context: &C,
+    ) -> Result<UsersPostResponse, ApiError> {
+        // Never log the password
+        info!("users_post({:?}, {:?})", user.name, user.admin);
+        if !is_admin(context) {
+            return Ok(UsersPostResponse::Forbidden);
+        }
+
+        let (Some(name), Some(password)) = (user.name.as_deref(), user.password.as_deref()) else {
+            return Ok(UsersPostResponse::WrongData);
+        };
+        if !is_valid_user_name(name) || password.is_empty() {
+            return Ok(UsersPostResponse::WrongData);
+        }
+
+        // Database and password hashing are blocking
+        let added = tokio::task::spawn_blocking({
+            let database = self.database.clone();
+            let name = name.to_string();
+            let password = password.to_string();
+            let admin = user.admin.unwrap_or(false);
+            move || database.add_user(&name, &password, admin)
+        })
+        .await
+        .map_err(|error| ApiError(format!("Can't add user \"{name}\" : {error}")))?;
+
+        match added {
+            Ok(user) => Ok(UsersPostResponse::UserCreated(user.into())),
+            Err(DatabaseError::AlreadyExists(message)) => {
+                debug!("{message}");
+                Ok(UsersPostResponse::UserAlreadyExists)
+            }
+            Err(error) => {
+                warn!("Can't add user \"{name}\" : {error:?}");
+                Err(ApiError(format!("Can't add user \"{name}\" : {error}")))
+            }
+        }
     }
 }
@@ -0,0 +1,263 @@
+use crate::database::Database;
+use crate::library::{cover_content_type, resize_cover, Library};
+use crate::server::endpoints::stream_endpoint::is_not_modified;
+use crate::server::{ServiceError, ServiceFuture};
+use futures::future;
+use hyper::header::{CACHE_CONTROL, CONTENT_LENGTH, CONTENT_TYPE, ETAG};
+use hyper::{Body, HeaderMap, Method, Request, Response, StatusCode};
+use log::{debug, warn};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use swagger::{Authorization, Has, XSpanIdString};
+
+static COVER_PREFIX: &str = "/api/v1/albums/";
+static COVER_SUFFIX: &str = "/cover";
+
+/// Largest size a cover can be resized to, in pixels.
+const MAX_SIZE: u32 = 2000;
+
+/// Covers never change for a given entity tag, but the cover of an album can.
+const CACHE_POLICY: &str = "private, max-age=86400";
+
+/// Tell if the path is the one of the cover endpoint.
+pub fn is_cover_path(path: &str) -> bool {
+    album_id(path).is_some()
+}
+
+fn album_id(path: &str) -> Option<i32> {
+    path.strip_prefix(COVER_PREFIX)
+        .and_then(|path| path.strip_suffix(COVER_SUFFIX))
+        .and_then(|id| id.parse().ok())
+}
+
+/// Value of the `size` query parameter, `Err(())` when it isn't a valid size.
+fn size(query: Option<&str>) -> Result<Option<u32>, ()> {
+    let Some(size) = query
+        .unwrap_or_default()
+        .split('&')
+        .filter_map(|parameter| parameter.split_once('='))
+        .find(|(name, _)| *name == "size")
+        .map(|(_, size)| size)
+    else {
+        return Ok(None);
+    };
+
+    match size.parse() {
+        Ok(size) if size > 0 && size <= MAX_SIZE => Ok(Some(size)),
+        _ => Err(()),
+    }
+}
+
+#[derive(Clone)]
+pub struct MakeCoverEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    database: Arc<Database>,
+    library: Library,
+    marker: PhantomData<C>,
+}
+
+impl<C> MakeCoverEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    pub fn new(database: Arc<Database>, library: Library) -> Self {
+        Self {
+            database,
+            library,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C, Target> hyper::service::Service<Target> for MakeCoverEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    type Response = CoverEndpointService<C>;
+    type Error = ServiceError;
+    type Future = future::Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _target: Target) -> Self::Future {
+        future::ok(CoverEndpointService::new(
+            self.database.clone(),
+            self.library.clone(),
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct CoverEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    database: Arc<Database>,
+    library: Library,
+    marker: PhantomData<C>,
+}
+
+impl<C> CoverEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    pub fn new(database: Arc<Database>, library: Library) -> Self {
+        Self {
+            database,
+            library,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> hyper::service::Service<(Request<Body>, C)> for CoverEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    type Response = Response<Body>;
+    type Error = ServiceError;
+    type Future = ServiceFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: (Request<Body>, C)) -> Self::Future {
+        let (request, context) = req;
+
+        let xspanid = <C as Has<XSpanIdString>>::get(&context).0.clone();
+
+        if request.method() != Method::GET && request.method() != Method::HEAD {
+            return Box::pin(empty(StatusCode::METHOD_NOT_ALLOWED, xspanid));
+        }
+
+        let Some(id) = album_id(request.uri().path()) else {
+            return Box::pin(async move { crate::server::not_found(xspanid) });
+        };
+        let Ok(size) = size(request.uri().query()) else {
+            debug!("Invalid cover size in '{:?}'", request.uri().query());
+            return Box::pin(empty(StatusCode::BAD_REQUEST, xspanid));
+        };
+
+        let head = request.method() == Method::HEAD;
+        Box::pin(serve(
+            self.database.clone(),
+            self.library.clone(),
+            id,
+            size,
+            request.headers().clone(),
+            head,
+            xspanid,
+        ))
+    }
+}
+
+async fn empty(status: StatusCode, xspanid: String) -> Result<Response<Body>, ServiceError> {
+    let response = Response::builder()
+        .status(status)
+        .header("x-span-id", xspanid.as_str())
+        .body(Body::empty())
+        .expect("Unable to build response");
+    Ok(response)
+}
+
+/// Look the cover of the album up and serve it.
+async fn serve(
+    database: Arc<Database>,
+    library: Library,
+    id: i32,
+    size: Option<u32>,
+    headers: HeaderMap,
+    head: bool,
+    xspanid: String,
+) -> Result<Response<Body>, ServiceError> {
+    // Database is blocking
+    let album_cover = tokio::task::spawn_blocking(move || database.album_cover(id)).await?;
+    let name = match album_cover {
+        Ok(Some(name)) => name,
+        Ok(None) => {
+            debug!("No cover for album {id}");
+            return crate::server::not_found(xspanid);
+        }
+        Err(error) => {
+            warn!("Can't get cover of album {id} : {error:?}");
+            return empty(StatusCode::INTERNAL_SERVER_ERROR, xspanid).await;
+        }
+    };
+
+    let file = library.cover_path(&name);
+    cover(file, name, size, headers, head, xspanid).await
+}
+
+/// Serve a cover, resized when `size` is set, honoring the `If-None-Match` header.
+async fn cover(
+    file: PathBuf,
+    name: String,
+    size: Option<u32>,
+    headers: HeaderMap,
+    head: bool,
+    xspanid: String,
+) -> Result<Response<Body>, ServiceError> {
+    // Covers are named after their content, so is their entity tag.
+    let etag = match size {
+        Some(size) => format!("\"{name}-{size}\""),
+        None => format!("\"{name}\""),
+    };
+    let builder = Response::builder()
+        .header("x-span-id", xspanid.as_str())
+        .header(ETAG, etag.as_str())
+        .header(CACHE_CONTROL, CACHE_POLICY);
+
+    if is_not_modified(&headers, &etag) {
+        let response = builder
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Body::empty())
+            .expect("Unable to build response");
+        return Ok(response);
+    }
+
+    let data = match tokio::fs::read(&file).await {
+        Ok(data) => data,
+        Err(error) => {
+            warn!("Can't read cover {} : {error:?}", file.display());
+            return crate::server::not_found(xspanid);
+        }
+    };
+
+    let (data, content_type) = match size {
+        Some(size) => {
+            let resized = tokio::task::spawn_blocking(move || {
+                let resized = resize_cover(&data, size);
+                (data, resized)
+            })
+            .await?;
+            match resized {
+                (_, Ok(Some(resized))) => resized,
+                (data, Ok(None)) => (data, cover_content_type(&name)),
+                (data, Err(error)) => {
+                    warn!("Can't resize cover {} : {error:?}", file.display());
+                    (data, cover_content_type(&name))
+                }
+            }
+        }
+        None => (data, cover_content_type(&name)),
+    };
+
+    let builder = builder
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, content_type)
+        .header(CONTENT_LENGTH, data.len());
+    let body = if head {
+        Body::empty()
+    } else {
+        Body::from(data)
+    };
+    let response = builder.body(body).expect("Unable to build response");
+    Ok(response)
+}
@@ -1,6 +1,9 @@
 pub mod api_endpoint;
+pub mod cover_endpoint;
 pub mod metrics_endpoint;
 pub mod openapi_endpoint;
+pub mod stream_endpoint;
+pub mod upload_endpoint;
 
 pub use api_endpoint::Server;
 pub use metrics_endpoint::*;
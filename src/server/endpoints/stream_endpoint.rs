@@ -0,0 +1,340 @@
+use crate::database::Database;
+use crate::library::Library;
+use crate::server::{ServiceError, ServiceFuture};
+use futures::{future, stream};
+use hyper::header::{
+    ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, ETAG, IF_NONE_MATCH, RANGE,
+};
+use hyper::{Body, HeaderMap, Method, Request, Response, StatusCode};
+use log::{debug, warn};
+use std::io::SeekFrom;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::UNIX_EPOCH;
+use swagger::{Authorization, Has, XSpanIdString};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+static STREAM_PREFIX: &str = "/api/v1/songs/";
+static STREAM_SUFFIX: &str = "/stream";
+
+/// Size of the chunks sent to the client.
+const CHUNK_SIZE: u64 = 64 * 1024;
+
+/// Tell if the path is the one of the stream endpoint.
+pub fn is_stream_path(path: &str) -> bool {
+    song_id(path).is_some()
+}
+
+fn song_id(path: &str) -> Option<i32> {
+    path.strip_prefix(STREAM_PREFIX)
+        .and_then(|path| path.strip_suffix(STREAM_SUFFIX))
+        .and_then(|id| id.parse().ok())
+}
+
+#[derive(Clone)]
+pub struct MakeStreamEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    database: Arc<Database>,
+    library: Library,
+    marker: PhantomData<C>,
+}
+
+impl<C> MakeStreamEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    pub fn new(database: Arc<Database>, library: Library) -> Self {
+        Self {
+            database,
+            library,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C, Target> hyper::service::Service<Target> for MakeStreamEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    type Response = StreamEndpointService<C>;
+    type Error = ServiceError;
+    type Future = future::Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _target: Target) -> Self::Future {
+        future::ok(StreamEndpointService::new(
+            self.database.clone(),
+            self.library.clone(),
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct StreamEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    database: Arc<Database>,
+    library: Library,
+    marker: PhantomData<C>,
+}
+
+impl<C> StreamEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    pub fn new(database: Arc<Database>, library: Library) -> Self {
+        Self {
+            database,
+            library,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> hyper::service::Service<(Request<Body>, C)> for StreamEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    type Response = Response<Body>;
+    type Error = ServiceError;
+    type Future = ServiceFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: (Request<Body>, C)) -> Self::Future {
+        let (request, context) = req;
+
+        let xspanid = <C as Has<XSpanIdString>>::get(&context).0.clone();
+
+        if request.method() != Method::GET && request.method() != Method::HEAD {
+            async fn run(xspanid: String) -> Result<Response<Body>, ServiceError> {
+                let response = Response::builder()
+                    .status(StatusCode::METHOD_NOT_ALLOWED)
+                    .header("x-span-id", xspanid.as_str())
+                    .body(Body::empty())
+                    .expect("Unable to build response");
+                Ok(response)
+            }
+            return Box::pin(run(xspanid));
+        }
+
+        let Some(id) = song_id(request.uri().path()) else {
+            return Box::pin(async move { crate::server::not_found(xspanid) });
+        };
+
+        let head = request.method() == Method::HEAD;
+        Box::pin(serve(
+            self.database.clone(),
+            self.library.clone(),
+            id,
+            request.headers().clone(),
+            head,
+            xspanid,
+        ))
+    }
+}
+
+/// Look the song up and stream its file.
+async fn serve(
+    database: Arc<Database>,
+    library: Library,
+    id: i32,
+    headers: HeaderMap,
+    head: bool,
+    xspanid: String,
+) -> Result<Response<Body>, ServiceError> {
+    // Database is blocking
+    let song = tokio::task::spawn_blocking(move || database.song(id)).await?;
+    let file = match song {
+        Ok(Some((_, path))) => library.library_path().join(path),
+        Ok(None) => {
+            debug!("Song {id} not found");
+            return crate::server::not_found(xspanid);
+        }
+        Err(error) => {
+            warn!("Can't get song {id} : {error:?}");
+            let response = Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .header("x-span-id", xspanid.as_str())
+                .body(Body::empty())
+                .expect("Unable to build response");
+            return Ok(response);
+        }
+    };
+
+    stream(file, headers, head, xspanid).await
+}
+
+/// Serve an audio file, honoring `Range` and `If-None-Match` headers.
+async fn stream(
+    file: PathBuf,
+    headers: HeaderMap,
+    head: bool,
+    xspanid: String,
+) -> Result<Response<Body>, ServiceError> {
+    let metadata = match tokio::fs::metadata(&file).await {
+        Ok(metadata) => metadata,
+        Err(error) => {
+            warn!("Can't read metadata of {} : {error:?}", file.display());
+            return crate::server::not_found(xspanid);
+        }
+    };
+    let length = metadata.len();
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|modified| modified.as_nanos())
+        .unwrap_or_default();
+    let etag = format!("\"{:x}\"", md5::compute(format!("{length}-{modified}")));
+
+    let builder = Response::builder()
+        .header("x-span-id", xspanid.as_str())
+        .header(ETAG, etag.as_str())
+        .header(ACCEPT_RANGES, "bytes");
+
+    if is_not_modified(&headers, &etag) {
+        let response = builder
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Body::empty())
+            .expect("Unable to build response");
+        return Ok(response);
+    }
+
+    let range = match headers.get(RANGE).and_then(|value| value.to_str().ok()) {
+        Some(range) => match parse_range(range, length) {
+            Ok(range) => range,
+            Err(()) => {
+                debug!("Unsatisfiable range '{range}' for a length of {length}");
+                let response = builder
+                    .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                    .header(CONTENT_RANGE, format!("bytes */{length}"))
+                    .body(Body::empty())
+                    .expect("Unable to build response");
+                return Ok(response);
+            }
+        },
+        None => None,
+    };
+
+    let builder = builder.header(CONTENT_TYPE, content_type(&file));
+    let (builder, start, end) = match range {
+        Some((start, end)) => (
+            builder
+                .status(StatusCode::PARTIAL_CONTENT)
+                .header(CONTENT_RANGE, format!("bytes {start}-{end}/{length}")),
+            start,
+            end,
+        ),
+        None => (builder.status(StatusCode::OK), 0, length.saturating_sub(1)),
+    };
+    let size = if length == 0 { 0 } else { end - start + 1 };
+    let builder = builder.header(CONTENT_LENGTH, size);
+
+    if head || size == 0 {
+        let response = builder
+            .body(Body::empty())
+            .expect("Unable to build response");
+        return Ok(response);
+    }
+
+    let mut reader = tokio::fs::File::open(&file).await?;
+    reader.seek(SeekFrom::Start(start)).await?;
+    let chunks = stream::try_unfold((reader, size), |(mut reader, remaining)| async move {
+        if remaining == 0 {
+            return Ok(None);
+        }
+        let mut chunk = vec![0; remaining.min(CHUNK_SIZE) as usize];
+        let read = reader.read(&mut chunk).await?;
+        if read == 0 {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof));
+        }
+        chunk.truncate(read);
+        Ok::<_, std::io::Error>(Some((chunk, (reader, remaining - read as u64))))
+    });
+
+    let response = builder
+        .body(Body::wrap_stream(chunks))
+        .expect("Unable to build response");
+    Ok(response)
+}
+
+/// Tell if the `If-None-Match` header matches the current entity tag.
+pub(crate) fn is_not_modified(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+        })
+        .unwrap_or(false)
+}
+
+/// Parse a `Range` header into an inclusive range of bytes.
+///
+/// Returns `Ok(None)` when the range should be ignored (unknown unit or several
+/// ranges), in which case the whole file is sent, and `Err(())` when the range
+/// can't be satisfied.
+fn parse_range(range: &str, length: u64) -> Result<Option<(u64, u64)>, ()> {
+    let Some(range) = range.trim().strip_prefix("bytes=") else {
+        return Ok(None);
+    };
+    if range.contains(',') {
+        return Ok(None);
+    }
+    let Some((start, end)) = range.trim().split_once('-') else {
+        return Err(());
+    };
+
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", "") => return Err(()),
+        // Suffix : last bytes of the file
+        ("", suffix) => {
+            let suffix: u64 = suffix.parse().map_err(|_| ())?;
+            if suffix == 0 {
+                return Err(());
+            }
+            (length.saturating_sub(suffix), length.saturating_sub(1))
+        }
+        (start, "") => (start.parse().map_err(|_| ())?, length.saturating_sub(1)),
+        (start, end) => {
+            let start: u64 = start.parse().map_err(|_| ())?;
+            let end: u64 = end.parse().map_err(|_| ())?;
+            (start, end.min(length.saturating_sub(1)))
+        }
+    };
+
+    if start >= length || start > end {
+        Err(())
+    } else {
+        Ok(Some((start, end)))
+    }
+}
+
+fn content_type(file: &Path) -> &'static str {
+    match file
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_lowercase())
+        .as_deref()
+    {
+        Some("mp3") => "audio/mpeg",
+        Some("flac") => "audio/flac",
+        Some("ogg") | Some("oga") => "audio/ogg",
+        Some("m4a") | Some("mp4") => "audio/mp4",
+        _ => "application/octet-stream",
+    }
+}
@@ -0,0 +1,416 @@
+use crate::database::{Database, DatabaseError};
+use crate::index::TantivyIndex;
+use crate::library::{Library, UnsupportedFile};
+use crate::server::{ServiceError, ServiceFuture};
+use crate::METRIC_DISALLOWED_PATH;
+use base64::Engine;
+use futures::{future, StreamExt};
+use hyper::header::{CONTENT_LENGTH, CONTENT_TYPE};
+use hyper::{Body, HeaderMap, Method, Request, Response, StatusCode};
+use log::{debug, warn};
+use metrics::increment_counter;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use swagger::{Authorization, Has, XSpanIdString};
+use thiserror::Error;
+use tokio::io::AsyncWriteExt;
+
+static UPLOAD_PATH: &str = "/api/v1/songs";
+
+/// Name of the form part holding the file in `multipart/form-data` uploads.
+static FILE_PART: &str = "file";
+
+/// Header telling a body is encoded in base64, as sent by the first clients.
+static CONTENT_TRANSFER_ENCODING: &str = "content-transfer-encoding";
+
+/// Tell if the path is the one of the upload endpoint.
+pub fn is_upload_path(path: &str) -> bool {
+    path == UPLOAD_PATH
+}
+
+/// Tell if a body is encoded in base64 : its `Content-Transfer-Encoding` is `base64`,
+/// or its `Content-Type` is textual, like `text/plain`, which audio files can't be.
+fn is_base64(headers: &HeaderMap) -> bool {
+    let header = |name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim().to_lowercase())
+            .unwrap_or_default()
+    };
+    header(CONTENT_TRANSFER_ENCODING) == "base64"
+        || header(CONTENT_TYPE.as_str()).starts_with("text/")
+}
+
+#[derive(Error, Debug)]
+enum UploadError {
+    #[error("Missing '{FILE_PART}' part")]
+    MissingFile,
+    #[error("Empty file")]
+    Empty,
+    #[error("File exceeds {0} bytes")]
+    TooLarge(u64),
+    #[error(transparent)]
+    Base64(#[from] base64::DecodeError),
+    #[error(transparent)]
+    Multipart(#[from] multer::Error),
+    #[error(transparent)]
+    Body(#[from] hyper::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Decode base64 chunks, keeping incomplete quadruplets for the next chunk.
+#[derive(Default)]
+struct Base64Decoder {
+    pending: Vec<u8>,
+}
+
+impl Base64Decoder {
+    fn decode(&mut self, chunk: &[u8]) -> Result<Vec<u8>, base64::DecodeError> {
+        self.pending
+            .extend(chunk.iter().filter(|byte| !byte.is_ascii_whitespace()));
+        let complete = self.pending.len() - self.pending.len() % 4;
+        let decoded =
+            base64::engine::general_purpose::STANDARD.decode(&self.pending[..complete])?;
+        self.pending.drain(..complete);
+        Ok(decoded)
+    }
+
+    fn finish(self) -> Result<Vec<u8>, base64::DecodeError> {
+        base64::engine::general_purpose::STANDARD.decode(self.pending)
+    }
+}
+
+#[derive(Clone)]
+pub struct MakeUploadEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    database: Arc<Database>,
+    index: Arc<TantivyIndex>,
+    library: Library,
+    marker: PhantomData<C>,
+}
+
+impl<C> MakeUploadEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    pub fn new(database: Arc<Database>, index: Arc<TantivyIndex>, library: Library) -> Self {
+        Self {
+            database,
+            index,
+            library,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C, Target> hyper::service::Service<Target> for MakeUploadEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    type Response = UploadEndpointService<C>;
+    type Error = ServiceError;
+    type Future = future::Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _target: Target) -> Self::Future {
+        future::ok(UploadEndpointService::new(
+            self.database.clone(),
+            self.index.clone(),
+            self.library.clone(),
+        ))
+    }
+}
+
+/// Receive audio files without holding them in memory : raw `audio/*` bodies,
+/// `multipart/form-data` forms and, for compatibility, base64 encoded bodies
+/// are streamed to the temporary folder before being added to the library.
+#[derive(Clone)]
+pub struct UploadEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    database: Arc<Database>,
+    index: Arc<TantivyIndex>,
+    library: Library,
+    marker: PhantomData<C>,
+}
+
+impl<C> UploadEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    pub fn new(database: Arc<Database>, index: Arc<TantivyIndex>, library: Library) -> Self {
+        Self {
+            database,
+            index,
+            library,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> hyper::service::Service<(Request<Body>, C)> for UploadEndpointService<C>
+where
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync + 'static,
+{
+    type Response = Response<Body>;
+    type Error = ServiceError;
+    type Future = ServiceFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: (Request<Body>, C)) -> Self::Future {
+        let (request, context) = req;
+
+        let xspanid = <C as Has<XSpanIdString>>::get(&context).0.clone();
+
+        if request.method() != Method::POST {
+            return Box::pin(async move { response(StatusCode::METHOD_NOT_ALLOWED, xspanid) });
+        }
+
+        Box::pin(upload(
+            request,
+            self.database.clone(),
+            self.index.clone(),
+            self.library.clone(),
+            xspanid,
+        ))
+    }
+}
+
+async fn upload(
+    request: Request<Body>,
+    database: Arc<Database>,
+    index: Arc<TantivyIndex>,
+    library: Library,
+    xspanid: String,
+) -> Result<Response<Body>, ServiceError> {
+    let max_size = library.max_upload_size();
+
+    let length = request
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    if length.map(|length| length > max_size).unwrap_or(false) {
+        debug!("Upload of {length:?} bytes exceeds {max_size} bytes");
+        return response(StatusCode::PAYLOAD_TOO_LARGE, xspanid);
+    }
+
+    // The name given by the client is only kept as metadata and for its extension.
+    // Clients send names that aren't ASCII as UTF-8 bytes.
+    let Some(filename) = request
+        .headers()
+        .get("x-filename")
+        .map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned())
+    else {
+        debug!("Missing X-Filename header");
+        return response(StatusCode::BAD_REQUEST, xspanid);
+    };
+    let Some(path) = library.upload_path(&filename) else {
+        warn!("File name \"{filename}\" is not allowed");
+        increment_counter!(METRIC_DISALLOWED_PATH);
+        return response(StatusCode::BAD_REQUEST, xspanid);
+    };
+
+    let boundary = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| value.starts_with("multipart/"))
+        .map(multer::parse_boundary);
+    let base64 = is_base64(request.headers());
+    let body = request.into_body();
+    let result = match boundary {
+        Some(Ok(boundary)) => receive_multipart(body, boundary, &path, max_size).await,
+        Some(Err(error)) => Err(error.into()),
+        None => receive(body, base64, &path, max_size).await,
+    };
+    if result.is_err() {
+        if let Err(error) = tokio::fs::remove_file(&path).await {
+            debug!("Can't remove {} : {error:?}", path.display());
+        }
+    }
+
+    match result {
+        Ok(()) => debug!("\"{filename}\" received as {}", path.display()),
+        Err(UploadError::TooLarge(max_size)) => {
+            debug!("Upload exceeds {max_size} bytes");
+            return response(StatusCode::PAYLOAD_TOO_LARGE, xspanid);
+        }
+        Err(error @ UploadError::Io(_)) => {
+            warn!("Error uploading song : {error:?}");
+            return response(StatusCode::INTERNAL_SERVER_ERROR, xspanid);
+        }
+        Err(error) => {
+            debug!("Error uploading song : {error:?}");
+            return response(StatusCode::BAD_REQUEST, xspanid);
+        }
+    }
+
+    // Reading tags, database and index are blocking
+    let added = tokio::task::spawn_blocking({
+        let database = database.clone();
+        let filename = filename.clone();
+        move || {
+            let added = library.add_song(&path, &filename, &database, &index);
+            if added.is_err() {
+                if let Err(error) = std::fs::remove_file(&path) {
+                    warn!("Can't remove {} : {error:?}", path.display());
+                }
+            }
+            added
+        }
+    })
+    .await?;
+
+    match added {
+        Ok(song) => {
+            debug!("Add \"{filename}\" with id {:?}", song.id());
+            response(StatusCode::CREATED, xspanid)
+        }
+        Err(error) if error.is::<UnsupportedFile>() => {
+            debug!("\"{filename}\" isn't a supported audio file : {error}");
+            response(StatusCode::UNSUPPORTED_MEDIA_TYPE, xspanid)
+        }
+        Err(error) => match error.downcast_ref::<DatabaseError>() {
+            Some(DatabaseError::DuplicateSong(id)) => {
+                debug!("\"{filename}\" is a duplicate of song {id}");
+                duplicate(*id, &database, xspanid)
+            }
+            _ => {
+                warn!("Can't add \"{filename}\" : {error:?}");
+                response(StatusCode::INTERNAL_SERVER_ERROR, xspanid)
+            }
+        },
+    }
+}
+
+/// Conflict response holding the song that has the same audio content.
+fn duplicate(
+    id: i32,
+    database: &Database,
+    xspanid: String,
+) -> Result<Response<Body>, ServiceError> {
+    let song = match database.song(id) {
+        Ok(Some((song, _))) => song.into(),
+        Ok(None) => server_lib::models::Song::new(),
+        Err(error) => {
+            warn!("Can't get song {id} : {error:?}");
+            server_lib::models::Song::new()
+        }
+    };
+    let song = server_lib::models::Song {
+        id: Some(id),
+        ..song
+    };
+
+    let response = Response::builder()
+        .status(StatusCode::CONFLICT)
+        .header("x-span-id", xspanid.as_str())
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(serde_json::to_string(&song)?))
+        .expect("Unable to build response");
+    Ok(response)
+}
+
+/// Write a raw or base64 encoded body into `path`.
+async fn receive(
+    mut body: Body,
+    base64: bool,
+    path: &Path,
+    max_size: u64,
+) -> Result<(), UploadError> {
+    let mut upload = Upload::create(path, max_size).await?;
+    let mut decoder = base64.then(|| {
+        debug!("Decoding base64 body");
+        Base64Decoder::default()
+    });
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk?;
+        match &mut decoder {
+            Some(decoder) => upload.write(&decoder.decode(&chunk)?).await?,
+            None => upload.write(&chunk).await?,
+        }
+    }
+    if let Some(decoder) = decoder {
+        upload.write(&decoder.finish()?).await?;
+    }
+    upload.finish().await
+}
+
+/// Write the `file` part of a form into `path`.
+async fn receive_multipart(
+    body: Body,
+    boundary: String,
+    path: &Path,
+    max_size: u64,
+) -> Result<(), UploadError> {
+    let mut multipart = multer::Multipart::new(body, boundary);
+    while let Some(mut field) = multipart.next_field().await? {
+        if field.name() != Some(FILE_PART) {
+            continue;
+        }
+
+        let mut upload = Upload::create(path, max_size).await?;
+        while let Some(chunk) = field.chunk().await? {
+            upload.write(&chunk).await?;
+        }
+        return upload.finish().await;
+    }
+    Err(UploadError::MissingFile)
+}
+
+/// File being uploaded, that can't exceed a maximum size.
+struct Upload {
+    file: tokio::fs::File,
+    size: u64,
+    max_size: u64,
+}
+
+impl Upload {
+    async fn create(path: &Path, max_size: u64) -> Result<Self, UploadError> {
+        Ok(Self {
+            file: tokio::fs::File::create(path).await?,
+            size: 0,
+            max_size,
+        })
+    }
+
+    async fn write(&mut self, chunk: &[u8]) -> Result<(), UploadError> {
+        self.size += chunk.len() as u64;
+        if self.size > self.max_size {
+            return Err(UploadError::TooLarge(self.max_size));
+        }
+        self.file.write_all(chunk).await?;
+        Ok(())
+    }
+
+    async fn finish(mut self) -> Result<(), UploadError> {
+        if self.size == 0 {
+            return Err(UploadError::Empty);
+        }
+        self.file.flush().await?;
+        Ok(())
+    }
+}
+
+fn response(status: StatusCode, xspanid: String) -> Result<Response<Body>, ServiceError> {
+    let response = Response::builder()
+        .status(status)
+        .header("x-span-id", xspanid.as_str())
+        .body(Body::empty())
+        .expect("Unable to build response");
+    Ok(response)
+}
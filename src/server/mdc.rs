@@ -40,6 +40,7 @@ where
     }
 }
 
+#[derive(Clone)]
 pub struct MDCService<Inner, C> {
     inner: Inner,
     marker: PhantomData<C>,
@@ -1,11 +1,16 @@
 use crate::config::MainConfig;
+use crate::database::Database;
 use crate::index::TantivyIndex;
 use crate::library::Library;
-use crate::METRIC_DISALLOWED_PATH;
+use crate::{METRIC_DISALLOWED_PATH, METRIC_INDEXER_LAG};
 use anyhow::Result;
+use authenticator::MakeAuthenticatorService;
 use endpoints::api_endpoint::Server;
+use endpoints::cover_endpoint::MakeCoverEndpointService;
 use endpoints::metrics_endpoint::MakeMetricsEndpointService;
 use endpoints::openapi_endpoint::MakeOpenAPIEndpointService;
+use endpoints::stream_endpoint::MakeStreamEndpointService;
+use endpoints::upload_endpoint::MakeUploadEndpointService;
 use futures::future::BoxFuture;
 use headers::MakeHeadersService;
 use hyper::{Body, Response, StatusCode};
@@ -17,7 +22,7 @@ use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
 use router::MakeRouterService;
 use server_lib::server::MakeService;
 use std::error::Error;
-use swagger::auth::MakeAllowAllAuthenticator;
+use std::sync::Arc;
 use swagger::EmptyContext;
 use ui::MakeUIService;
 
@@ -50,24 +55,39 @@ pub(crate) async fn create(
 ) -> Result<()> {
     let addr = addr.parse().expect("Failed to parse bind address");
 
-    //let db = config.database();
-    //let connection = crate::database::Database::try_from(db)?;
-    //connection.authenticate_user("admin", "admin")?;
+    let database = Arc::new(Database::try_from(config.database())?);
+
+    tantivy_index.reconcile(&database)?;
 
     // Expose API
+    let index = Arc::new(tantivy_index);
     let library: Library = config.library().into();
-    let server = Server::new(tantivy_index, library)?;
+    let server = Server::new(index.clone(), library.clone(), database.clone())?;
     let api = MakeService::new(server);
 
+    // Apply changes made to the library folder outside the server, until the server stops
+    let watcher = if config.library().watch() {
+        Some(library.watch(database.clone(), index.clone())?)
+    } else {
+        None
+    };
+
     // Expose openapi spec in json
     let openapi = MakeOpenAPIEndpointService::default();
 
     // Expose metrics
     let bucket = [0.05, 0.1, 0.5, 1.0, 2.0, 5.0];
+    let lag_bucket = [0.01, 0.05, 0.1, 0.5, 1.0, 2.0, 5.0, 30.0];
     let handle = PrometheusBuilder::new()
         .set_buckets_for_metric(Matcher::Suffix("api_time".to_owned()), &bucket)
+        .and_then(|builder| {
+            builder.set_buckets_for_metric(
+                Matcher::Full(METRIC_INDEXER_LAG.to_owned()),
+                &lag_bucket,
+            )
+        })
         .unwrap_or_else(|error| {
-            warn!("Can't set bucket for 'api_time' metrics, defaulting to summary : {error:?}");
+            warn!("Can't set bucket for 'api_time' and '{METRIC_INDEXER_LAG}' metrics, defaulting to summary : {error:?}");
             PrometheusBuilder::new()
         })
         .install_recorder()
@@ -76,17 +96,31 @@ pub(crate) async fn create(
 
     describe_counter!(
         METRIC_DISALLOWED_PATH,
-        "Request count for path that contains '..'."
+        "Request count for path or uploaded file name that are not allowed."
     );
     describe_counter!(RESPONSE_COUNT, "Response count by http status");
     describe_histogram!("api_time", Unit::Seconds, "API implementation time");
+    describe_histogram!(
+        METRIC_INDEXER_LAG,
+        Unit::Seconds,
+        "Time from changes sent to the index to the reload making them searchable"
+    );
+
+    // Expose audio files
+    let stream = MakeStreamEndpointService::new(database.clone(), library.clone());
+
+    // Expose album covers
+    let cover = MakeCoverEndpointService::new(database.clone(), library.clone());
+
+    // Receive audio files
+    let upload = MakeUploadEndpointService::new(database.clone(), index.clone(), library);
 
     // Expose ui and favicon
     let path = config.ui().map(|ui| ui.path());
     let ui = MakeUIService::new(path);
 
     // Route between different endpoint (api, openapi spec, metrics, ...etc)
-    let service = MakeRouterService::new(api, openapi, metrics, ui);
+    let service = MakeRouterService::new(api, openapi, metrics, stream, cover, upload, ui);
 
     // Headers service
     let service = MakeHeadersService::new(service, config.headers());
@@ -97,14 +131,54 @@ pub(crate) async fn create(
     // Add MDC, especially set X-Span-ID in MDC
     let service = MakeMDCService::new(service);
 
-    // TODO Change this to an authentication service...
-    let service = MakeAllowAllAuthenticator::new(service, "admin");
+    // Authenticate users
+    let service = MakeAuthenticatorService::new(service, database, config.metrics().public());
 
     let service = server_lib::server::context::MakeAddContext::<_, EmptyContext>::new(service);
 
     info!("Ready to server on {addr}");
 
-    hyper::server::Server::bind(&addr).serve(service).await?;
+    hyper::server::Server::bind(&addr)
+        .serve(service)
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+
+    // Pending changes are committed when the index is dropped
+    drop(watcher);
+    match Arc::try_unwrap(index) {
+        Ok(index) => drop(index),
+        Err(_) => warn!("Index still in use, its pending changes may be lost"),
+    }
 
     Ok(())
 }
+
+/// Resolve once the server is asked to stop, by Ctrl+C or `SIGTERM`.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(error) = tokio::signal::ctrl_c().await {
+            warn!("Can't listen to Ctrl+C : {error:?}");
+            std::future::pending::<()>().await;
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(error) => {
+                warn!("Can't listen to SIGTERM : {error:?}");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+    info!("Stopping the server");
+}
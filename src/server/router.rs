@@ -1,5 +1,14 @@
+use super::endpoints::cover_endpoint::{
+    is_cover_path, CoverEndpointService, MakeCoverEndpointService,
+};
 use super::endpoints::metrics_endpoint::{MakeMetricsEndpointService, MetricsEndpointService};
 use super::endpoints::openapi_endpoint::{MakeOpenAPIEndpointService, OpenAPIEndpointService};
+use super::endpoints::stream_endpoint::{
+    is_stream_path, MakeStreamEndpointService, StreamEndpointService,
+};
+use super::endpoints::upload_endpoint::{
+    is_upload_path, MakeUploadEndpointService, UploadEndpointService,
+};
 use super::ui::{MakeUIService, UIService};
 use super::{ServiceError, ServiceFuture, OPENAPI_URL};
 use futures::executor::block_on;
@@ -21,6 +30,9 @@ where
     inner_api: MakeService<Inner, C>,
     inner_openapi: MakeOpenAPIEndpointService<C>,
     inner_metrics: MakeMetricsEndpointService<C>,
+    inner_stream: MakeStreamEndpointService<C>,
+    inner_cover: MakeCoverEndpointService<C>,
+    inner_upload: MakeUploadEndpointService<C>,
     inner_ui: MakeUIService<C>,
     marker: PhantomData<C>,
 }
@@ -34,12 +46,18 @@ where
         inner_api: MakeService<Inner, C>,
         inner_openapi: MakeOpenAPIEndpointService<C>,
         inner_metrics: MakeMetricsEndpointService<C>,
+        inner_stream: MakeStreamEndpointService<C>,
+        inner_cover: MakeCoverEndpointService<C>,
+        inner_upload: MakeUploadEndpointService<C>,
         inner_ui: MakeUIService<C>,
     ) -> Self {
         Self {
             inner_api,
             inner_openapi,
             inner_metrics,
+            inner_stream,
+            inner_cover,
+            inner_upload,
             inner_ui,
             marker: PhantomData,
         }
@@ -52,19 +70,27 @@ where
         let api = self.inner_api.call(target.clone());
         let openapi = self.inner_openapi.call(target.clone());
         let metrics = self.inner_metrics.call(target.clone());
+        let stream = self.inner_stream.call(target.clone());
+        let cover = self.inner_cover.call(target.clone());
+        let upload = self.inner_upload.call(target.clone());
         let ui = self.inner_ui.call(target);
 
         let future = async {
             let api = api.await;
             let openapi = openapi.await;
             let metrics = metrics.await;
+            let stream = stream.await;
+            let cover = cover.await;
+            let upload = upload.await;
             let ui = ui.await;
-            (api, openapi, metrics, ui)
+            (api, openapi, metrics, stream, cover, upload, ui)
         };
 
-        let (api, openapi, metrics, ui) = block_on(future);
+        let (api, openapi, metrics, stream, cover, upload, ui) = block_on(future);
 
-        Ok(HeaderService::new(api?, openapi?, metrics?, ui?))
+        Ok(HeaderService::new(
+            api?, openapi?, metrics?, stream?, cover?, upload?, ui?,
+        ))
     }
 }
 
@@ -97,6 +123,9 @@ where
     api: Service<Inner, C>,
     openapi: OpenAPIEndpointService<C>,
     metrics: MetricsEndpointService<C>,
+    stream: StreamEndpointService<C>,
+    cover: CoverEndpointService<C>,
+    upload: UploadEndpointService<C>,
     ui: UIService<C>,
     marker: PhantomData<C>,
 }
@@ -110,12 +139,18 @@ where
         api: Service<Inner, C>,
         openapi: OpenAPIEndpointService<C>,
         metrics: MetricsEndpointService<C>,
+        stream: StreamEndpointService<C>,
+        cover: CoverEndpointService<C>,
+        upload: UploadEndpointService<C>,
         ui: UIService<C>,
     ) -> Self {
         Self {
             api,
             openapi,
             metrics,
+            stream,
+            cover,
+            upload,
             ui,
             marker: PhantomData,
         }
@@ -142,7 +177,16 @@ where
 
         let path = request.uri().path();
         debug!("Routing {path}");
-        if path.starts_with("/api/") {
+        if is_stream_path(path) {
+            debug!("Routing to stream");
+            self.stream.call((request, context))
+        } else if is_cover_path(path) {
+            debug!("Routing to cover");
+            self.cover.call((request, context))
+        } else if is_upload_path(path) {
+            debug!("Routing to upload");
+            self.upload.call((request, context))
+        } else if path.starts_with("/api/") {
             debug!("Routing to api");
             self.api.call((request, context))
         } else if path == OPENAPI_URL {
@@ -2,7 +2,7 @@ use crate::common::{PartitionWorld, CONFIGURATION_FILE};
 use cucumber::{then, World};
 use futures::FutureExt;
 use reqwest::StatusCode;
-use server_lib::models::Informations;
+use server_lib::models::{Album, Informations, Playlist, QueryError, SearchResult, Suggestions};
 use std::process::Command;
 use std::time::Duration;
 use std::{env, future};
@@ -32,6 +32,157 @@ async fn check_api_root(world: &mut PartitionWorld) {
     );
 }
 
+#[then(expr = "search returns {int} songs out of {int}")]
+async fn check_search(world: &mut PartitionWorld, expected_songs: usize, expected_total: i64) {
+    assert_eq!(world.status(), StatusCode::from_u16(200).ok(),);
+
+    let total = world.header("x-total-count");
+    assert_eq!(
+        total,
+        expected_total.to_string(),
+        "Wrong X-Total-Count header."
+    );
+
+    let result = world.content::<SearchResult>().await;
+
+    assert!(
+        result.is_ok(),
+        "Deserialization returned an error : {:?}.",
+        result.unwrap_err(),
+    );
+    let result = result.unwrap();
+    assert_eq!(
+        result.songs.as_ref().map(Vec::len).unwrap_or_default(),
+        expected_songs
+    );
+    world.search_result(result);
+}
+
+#[then(expr = "the query error is {string}")]
+async fn check_query_error(world: &mut PartitionWorld, expected_code: String) {
+    assert_eq!(world.status(), StatusCode::from_u16(400).ok(),);
+
+    let result = world.content::<QueryError>().await;
+
+    assert!(
+        result.is_ok(),
+        "Deserialization returned an error : {:?}.",
+        result.unwrap_err(),
+    );
+    assert_eq!(result.unwrap().code, Some(expected_code));
+}
+
+#[then(expr = "the playlist {string} is returned")]
+async fn check_playlist(world: &mut PartitionWorld, name: String) {
+    let result = world.content::<Playlist>().await;
+
+    assert!(
+        result.is_ok(),
+        "Deserialization returned an error : {:?}.",
+        result.unwrap_err(),
+    );
+    let playlist = result.unwrap();
+    assert_eq!(playlist.name, Some(name));
+    assert_eq!(playlist.owner, Some("test".to_string()));
+}
+
+#[then(expr = "the albums listed are {string}")]
+async fn check_albums(world: &mut PartitionWorld, expected: String) {
+    assert_eq!(world.status(), StatusCode::from_u16(200).ok(),);
+
+    let result = world.content::<Vec<Album>>().await;
+
+    assert!(
+        result.is_ok(),
+        "Deserialization returned an error : {:?}.",
+        result.unwrap_err(),
+    );
+    let names: Vec<String> = result
+        .unwrap()
+        .into_iter()
+        .filter_map(|album| album.name)
+        .collect();
+    assert_eq!(names.join("; "), expected);
+}
+
+#[then(expr = "the album tracks are {string}")]
+async fn check_album_tracks(world: &mut PartitionWorld, expected: String) {
+    assert_eq!(world.status(), StatusCode::from_u16(200).ok(),);
+
+    let result = world.content::<Album>().await;
+
+    assert!(
+        result.is_ok(),
+        "Deserialization returned an error : {:?}.",
+        result.unwrap_err(),
+    );
+    let titles: Vec<String> = result
+        .unwrap()
+        .songs
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|song| song.title)
+        .collect();
+    assert_eq!(titles.join("; "), expected);
+}
+
+#[then(expr = "the {word} facet counts are {string}")]
+async fn check_facets(world: &mut PartitionWorld, kind: String, expected: String) {
+    let facets = world
+        .last_search_result()
+        .and_then(|result| result.facets.clone())
+        .expect("No facets in the last search result");
+    let counts = match kind.as_str() {
+        "genre" => facets.genres,
+        "decade" => facets.decades,
+        "artist" => facets.artists,
+        "album" => facets.albums,
+        _ => panic!("Unknown facet '{kind}'"),
+    };
+    let counts: Vec<String> = counts
+        .unwrap_or_default()
+        .into_iter()
+        .map(|count| {
+            format!(
+                "{}: {}",
+                count.value.unwrap_or_default(),
+                count.count.unwrap_or_default()
+            )
+        })
+        .collect();
+    assert_eq!(counts.join("; "), expected);
+}
+
+#[then(expr = "the suggested {word} are {string}")]
+async fn check_suggestions(world: &mut PartitionWorld, kind: String, expected: String) {
+    assert_eq!(world.status(), StatusCode::from_u16(200).ok(),);
+
+    let result = world.content::<Suggestions>().await;
+
+    assert!(
+        result.is_ok(),
+        "Deserialization returned an error : {:?}.",
+        result.unwrap_err(),
+    );
+    let suggestions = result.unwrap();
+    let values = match kind.as_str() {
+        "artists" => suggestions.artists,
+        "albums" => suggestions.albums,
+        "titles" => suggestions.titles,
+        _ => panic!("Unknown kind of suggestions '{kind}'"),
+    };
+    assert_eq!(values.unwrap_or_default().join("; "), expected);
+}
+
+#[then("basic authentication is requested")]
+async fn check_authentication_requested(world: &mut PartitionWorld) {
+    let authenticate = world.header("www-authenticate");
+    assert!(
+        authenticate.starts_with("Basic "),
+        "Wrong WWW-Authenticate header : '{authenticate}'."
+    );
+}
+
 #[tokio::main]
 async fn main() {
     PartitionWorld::cucumber()
@@ -4,8 +4,190 @@ Feature: Test API
 
   Background:
     Given partition is running
+    And authenticated as "test" with password "test"
 
   @serial
   Scenario: Get server information
     When accessing "/api/v1/"
     Then version match Cargo.toml
+
+  @serial
+  Scenario: Search an empty library
+    When accessing "/api/v1/search?q=notturno"
+    Then search returns 0 songs out of 0
+
+  @serial
+  Scenario: Get an unknown song
+    When accessing "/api/v1/songs/42"
+    Then the HTTP status is 404
+
+  @serial
+  Scenario: Delete an unknown song
+    When deleting "/api/v1/songs/42"
+    Then the HTTP status is 404
+
+  @serial
+  Scenario: Stream an unknown song
+    When accessing "/api/v1/songs/42/stream"
+    Then the HTTP status is 404
+
+  @serial
+  Scenario: Update an unknown song
+    When putting '{"title": "Notturno"}' to "/api/v1/songs/42"
+    Then the HTTP status is 404
+
+  @serial
+  Scenario: Update a song with another id
+    When putting '{"id": 43, "title": "Notturno"}' to "/api/v1/songs/42"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Update a song with a negative year
+    When putting '{"year": -1}' to "/api/v1/songs/42"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: List duplicates of an empty library
+    When accessing "/api/v1/songs/duplicates"
+    Then the HTTP status is 200
+
+  @serial
+  Scenario: Get the cover of an unknown album
+    When accessing "/api/v1/albums/42/cover"
+    Then the HTTP status is 404
+
+  @serial
+  Scenario: Get a cover with an invalid size
+    When accessing "/api/v1/albums/42/cover?size=0"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Get the tracks of an album in order
+    Given the song "moonlight.mp3" is in the library
+    And the song "pathetique.mp3" is in the library
+    When accessing the album of the song titled "Moonlight Sonata"
+    Then the album tracks are "Sonata Pathetique; Moonlight Sonata"
+
+  @serial
+  Scenario: Browse artists with an unknown sort order
+    When accessing "/api/v1/artists?sort=popularity"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Browse albums with a negative offset
+    When accessing "/api/v1/albums?offset=-1"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Browse the newest albums
+    Given the song "up.mp3" is in the library
+    And the song "pathetique.mp3" is in the library
+    When accessing "/api/v1/albums?sort=year&limit=1"
+    Then the albums listed are "Singles"
+
+  @serial
+  Scenario: Get the alphabetical index of the albums
+    When accessing "/api/v1/albums/index"
+    Then the HTTP status is 200
+
+  @serial
+  Scenario: Get an unknown artist
+    When accessing "/api/v1/artists/42"
+    Then the HTTP status is 404
+
+  @serial
+  Scenario: Get an unknown album
+    When accessing "/api/v1/albums/42"
+    Then the HTTP status is 404
+
+  @serial
+  Scenario: Search with fields, ranges and a sort order
+    Given the song "so_what.mp3" is in the library
+    And the song "dream.mp3" is in the library
+    When accessing "/api/v1/search?q=artist:%22miles%20davis%22%20year:%5B1955%20TO%201960%5D%20-genre:fusion&sort=-year"
+    Then search returns 1 songs out of 1
+    When accessing "/api/v1/search?q=genre:jazz%20year:%5B1955%20TO%201960%5D&sort=-year&limit=1"
+    Then search returns 1 songs out of 2
+
+  @serial
+  Scenario: Search with an invalid query
+    When accessing "/api/v1/search?q=artist:(miles"
+    Then the query error is "syntax"
+
+  @serial
+  Scenario: Search an unknown field
+    When accessing "/api/v1/search?q=label:blue"
+    Then the query error is "unknown_field"
+
+  @serial
+  Scenario: Search with an unknown sort order
+    When accessing "/api/v1/search?q=notturno&sort=popularity"
+    Then the query error is "unknown_sort"
+
+  @serial
+  Scenario: Search with a negative limit
+    When accessing "/api/v1/search?q=notturno&limit=-1"
+    Then the query error is "invalid_page"
+
+  @serial
+  Scenario: Search with a typo
+    Given the song "pathetique.mp3" is in the library
+    And the song "moonlight.mp3" is in the library
+    When accessing "/api/v1/search?q=bethoven"
+    Then search returns 2 songs out of 2
+
+  @serial
+  Scenario: Suggest one of the artists of a song
+    Given the song "so_what.mp3" is in the library
+    When accessing "/api/v1/suggest?q=miles%20da"
+    Then the suggested artists are "Miles Davis"
+
+  @serial
+  Scenario: Search a two letters title
+    Given the song "up.mp3" is in the library
+    When accessing "/api/v1/search?q=up"
+    Then search returns 1 songs out of 1
+    When accessing "/api/v1/search?q=artist:u2"
+    Then search returns 1 songs out of 1
+
+  @serial
+  Scenario: Search the whole value of a field
+    Given the song "so_what.mp3" is in the library
+    When accessing "/api/v1/search?q=album_keyword:%22Kind%20of%20Blue%22"
+    Then search returns 1 songs out of 1
+    When accessing "/api/v1/search?q=album_keyword:blue"
+    Then search returns 0 songs out of 0
+
+  @serial
+  Scenario: Suggest the values starting with the words typed
+    Given the song "moonlight.mp3" is in the library
+    And the song "up.mp3" is in the library
+    When accessing "/api/v1/suggest?q=moonlight%20so"
+    Then the suggested titles are "Moonlight Sonata"
+    When accessing "/api/v1/suggest?q=sonata%20moonl"
+    Then the suggested titles are "Moonlight Sonata"
+    When accessing "/api/v1/suggest?q=u"
+    Then the suggested artists are "U2"
+
+  @serial
+  Scenario: Search with facet filters
+    Given the song "so_what.mp3" is in the library
+    And the song "dream.mp3" is in the library
+    When accessing "/api/v1/search?q=genre:jazz&facet=decade:1950&facet=artist:Ella%20Fitzgerald"
+    Then search returns 1 songs out of 1
+
+  @serial
+  Scenario: Search with an unknown facet
+    When accessing "/api/v1/search?q=blue&facet=label:Blue%20Note"
+    Then the query error is "unknown_facet"
+
+  @serial
+  Scenario: Count the facets of a search
+    Given the song "pathetique.mp3" is in the library
+    And the song "moonlight.mp3" is in the library
+    When accessing "/api/v1/search?q=beethoven&limit=1&facets=true"
+    Then search returns 1 songs out of 2
+    And the genre facet counts are "Classical: 2"
+    And the decade facet counts are "1800: 2"
+    And the artist facet counts are "Beethoven: 2"
+    And the album facet counts are "Piano Sonatas: 2"
@@ -0,0 +1,40 @@
+# language: en
+
+Feature: Authentication
+
+  Background:
+    Given partition is running
+
+  @serial
+  Scenario: Access API without credentials
+    When accessing "/api/v1/"
+    Then the HTTP status is 401
+    And basic authentication is requested
+
+  @serial
+  Scenario: Access API with wrong credentials
+    Given authenticated as "test" with password "test"
+    And using password "wrong"
+    When accessing "/api/v1/"
+    Then the HTTP status is 401
+    And basic authentication is requested
+
+  @serial
+  Scenario: Access API with credentials
+    Given authenticated as "test" with password "test"
+    When accessing "/api/v1/"
+    Then the HTTP status is 200
+
+  @serial
+  Scenario: Access public metrics without credentials
+    When accessing "/metrics"
+    Then the HTTP status is 200
+
+  @serial
+  Scenario: Access API with a legacy MD5 password
+    Given authenticated as "legacy" with legacy password "legacy"
+    When accessing "/api/v1/"
+    Then the HTTP status is 200
+    And the password of "legacy" is hashed with Argon2id
+    When accessing "/api/v1/"
+    Then the HTTP status is 200
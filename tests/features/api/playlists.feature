@@ -0,0 +1,49 @@
+# language: en
+
+Feature: Playlists
+
+  Background:
+    Given partition is running
+    And authenticated as "test" with password "test"
+
+  @serial
+  Scenario: Create a playlist
+    When posting '{"name": "Night drive", "shared": true, "songs": []}' to "/api/v1/playlists"
+    Then the HTTP status is 201
+    And the playlist "Night drive" is returned
+
+  @serial
+  Scenario: Create a playlist without name
+    When posting '{"songs": []}' to "/api/v1/playlists"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Create a playlist with an unknown song
+    When posting '{"name": "Unknown", "songs": [{"id": 4242}]}' to "/api/v1/playlists"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Create a dynamic playlist
+    When posting '{"name": "Notturno", "query": "notturno", "songs": []}' to "/api/v1/playlists"
+    Then the HTTP status is 201
+    And the playlist "Notturno" is returned
+
+  @serial
+  Scenario: Create a dynamic playlist with an invalid query
+    When posting '{"name": "Invalid", "query": "title:(notturno", "songs": []}' to "/api/v1/playlists"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: List playlists
+    When accessing "/api/v1/playlists"
+    Then the HTTP status is 200
+
+  @serial
+  Scenario: Get an unknown playlist
+    When accessing "/api/v1/playlists/4242"
+    Then the HTTP status is 404
+
+  @serial
+  Scenario: Delete an unknown playlist
+    When deleting "/api/v1/playlists/4242"
+    Then the HTTP status is 404
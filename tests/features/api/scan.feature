@@ -0,0 +1,32 @@
+# language: en
+
+Feature: Scan music folders
+
+  Background:
+    Given partition is running
+
+  @serial
+  Scenario: Scan the library folder
+    Given authenticated as admin "root" with password "root"
+    When posting '' to "/api/v1/library/scan"
+    Then the HTTP status is 200
+
+  @serial
+  Scenario: Scan a folder that doesn't exist
+    Given authenticated as admin "root" with password "root"
+    When posting '' to "/api/v1/library/scan?path=does-not-exist"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Scan a folder outside of the library
+    Given authenticated as admin "root" with password "root"
+    When posting '' to "/api/v1/library/scan?path=.."
+    Then the HTTP status is 400
+    When posting '' to "/api/v1/library/scan?path=/tmp"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Scan without administrator role
+    Given authenticated as "test" with password "test"
+    When posting '' to "/api/v1/library/scan"
+    Then the HTTP status is 403
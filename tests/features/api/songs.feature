@@ -0,0 +1,39 @@
+# language: en
+
+Feature: Songs of the library
+
+  Background:
+    Given partition is running
+    And authenticated as "test" with password "test"
+
+  @serial
+  Scenario: Update the year of an album through one of its songs
+    Given the song "pathetique.mp3" is in the library
+    And the song "moonlight.mp3" is in the library
+    When putting '{"year": 1802}' to the song titled "Moonlight Sonata"
+    Then the HTTP status is 200
+    When accessing "/api/v1/search?q=year:1802"
+    Then search returns 2 songs out of 2
+
+  @serial
+  Scenario: Split the artists of a song on separators
+    Given the song "so_what.mp3" is in the library
+    Then the song titled "So What" has the artists "Miles Davis; John Coltrane"
+    When accessing "/api/v1/search?q=artist:coltrane"
+    Then search returns 1 songs out of 1
+
+  @serial
+  Scenario: Read the artists of a multi-value frame
+    Given the song "dream.mp3" is in the library
+    Then the song titled "Dream a Little Dream of Me" has the artists "Ella Fitzgerald; Louis Armstrong"
+    When accessing "/api/v1/search?q=artist:armstrong"
+    Then search returns 1 songs out of 1
+
+  @serial
+  Scenario: Update the artists of a song without duplicates
+    Given the song "so_what.mp3" is in the library
+    When putting '{"artists": ["John Coltrane", "Miles Davis & John Coltrane"]}' to the song titled "So What"
+    Then the HTTP status is 200
+    And the song titled "So What" has the artists "John Coltrane; Miles Davis"
+    When accessing "/api/v1/search?q=artist_keyword:%22miles%20davis%22"
+    Then search returns 1 songs out of 1
@@ -0,0 +1,72 @@
+# language: en
+
+Feature: Upload songs
+
+  Background:
+    Given partition is running
+    And authenticated as "test" with password "test"
+
+  @serial
+  Scenario: Upload a file too large
+    When uploading 2097152 bytes as "large.mp3"
+    Then the HTTP status is 413
+
+  @serial
+  Scenario: Upload without file name
+    When posting 'not a song' to "/api/v1/songs"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Upload an empty file
+    When uploading 0 bytes as "empty.mp3"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Upload outside of the temporary folder
+    When uploading 1024 bytes as "../../song.mp3"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Upload with a file name too long
+    When uploading 1024 bytes as a name of 256 characters
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Upload a file that isn't audio
+    When uploading 1024 bytes as "noise.mp3"
+    Then the HTTP status is 415
+    When uploading 1024 bytes as "noise"
+    Then the HTTP status is 415
+
+  @serial
+  Scenario: Upload a song
+    Given the song titled "Sonata Pathetique" isn't in the library
+    When uploading the song "pathetique.mp3"
+    Then the HTTP status is 201
+    And the song titled "Sonata Pathetique" has the original name "pathetique.mp3"
+
+  @serial
+  Scenario: Upload a song with an accented name
+    Given the song titled "Sonata Pathetique" isn't in the library
+    When uploading the song "pathetique.mp3" as "pathétique.mp3"
+    Then the HTTP status is 201
+    And the song titled "Sonata Pathetique" has the original name "pathétique.mp3"
+
+  @serial
+  Scenario: Upload a song encoded in base64
+    Given the song titled "Sonata Pathetique" isn't in the library
+    When uploading the song "pathetique.mp3" encoded in base64
+    Then the HTTP status is 201
+
+  @serial
+  Scenario: Upload a song in a form
+    Given the song titled "Sonata Pathetique" isn't in the library
+    When uploading the song "pathetique.mp3" in a form
+    Then the HTTP status is 201
+
+  @serial
+  Scenario: Upload a song already in the library
+    Given the song "moonlight.mp3" is in the library
+    When uploading the song "moonlight.mp3"
+    Then the HTTP status is 409
+    And the returned song is titled "Moonlight Sonata"
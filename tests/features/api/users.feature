@@ -0,0 +1,70 @@
+# language: en
+
+Feature: Users management
+
+  Background:
+    Given partition is running
+
+  @serial
+  Scenario: List users as administrator
+    Given authenticated as admin "root" with password "root"
+    When accessing "/api/v1/users"
+    Then the HTTP status is 200
+
+  @serial
+  Scenario: List users without administrator role
+    Given authenticated as "test" with password "test"
+    When accessing "/api/v1/users"
+    Then the HTTP status is 403
+
+  @serial
+  Scenario: Create a user
+    Given authenticated as admin "root" with password "root"
+    And user "bob" doesn't exist
+    When posting '{"name": "bob", "password": "secret"}' to "/api/v1/users"
+    Then the HTTP status is 201
+    When posting '{"name": "bob", "password": "secret"}' to "/api/v1/users"
+    Then the HTTP status is 409
+
+  @serial
+  Scenario: Create a user without password
+    Given authenticated as admin "root" with password "root"
+    When posting '{"name": "bob"}' to "/api/v1/users"
+    Then the HTTP status is 400
+
+  @serial
+  Scenario: Create a user without administrator role
+    Given authenticated as "test" with password "test"
+    When posting '{"name": "bob", "password": "secret"}' to "/api/v1/users"
+    Then the HTTP status is 403
+
+  @serial
+  Scenario: Update an unknown user
+    Given authenticated as admin "root" with password "root"
+    When putting '{"password": "secret"}' to "/api/v1/users/4242"
+    Then the HTTP status is 404
+
+  @serial
+  Scenario: Delete an unknown user
+    Given authenticated as admin "root" with password "root"
+    When deleting "/api/v1/users/4242"
+    Then the HTTP status is 404
+
+  @serial
+  Scenario: Keep the last administrator
+    Given authenticated as admin "root" with password "root"
+    And "root" is the only administrator
+    When putting '{"admin": false}' to user "root"
+    Then the HTTP status is 422
+    When deleting user "root"
+    Then the HTTP status is 422
+
+  @serial
+  Scenario: Delete a user and its playlists
+    Given authenticated as "bob" with password "secret"
+    When posting '{"name": "Bob mix", "songs": []}' to "/api/v1/playlists"
+    Then the HTTP status is 201
+    Given authenticated as admin "root" with password "root"
+    When deleting user "bob"
+    Then the HTTP status is 200
+    And the playlist "Bob mix" doesn't exist
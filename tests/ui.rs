@@ -1,7 +1,6 @@
 use crate::common::{PartitionWorld, CONFIGURATION_FILE};
 use cucumber::{then, World};
 use futures::FutureExt as _;
-use hyper::StatusCode;
 use std::future;
 use std::process::Command;
 use std::time::Duration;
@@ -9,11 +8,6 @@ use tokio::time::sleep;
 
 mod common;
 
-#[then(expr = "the HTTP status is {int}")]
-async fn check_status(world: &mut PartitionWorld, expected_status: u16) {
-    assert_eq!(world.status(), StatusCode::from_u16(expected_status).ok())
-}
-
 #[then(expr = "header {string} is {string}")]
 async fn check_header(world: &mut PartitionWorld, header: String, expected_location: String) {
     let location = world.header(&header);